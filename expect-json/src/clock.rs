@@ -0,0 +1,223 @@
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::thread;
+
+thread_local! {
+    static THREAD_CLOCK: RefCell<Option<SharedClock>> = const { RefCell::new(None) };
+
+    /// How many thread clocks are set, for checking guards are dropped in order.
+    static THREAD_CLOCK_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+///
+/// A source of the current time, for expectations relative to now,
+/// such as [`crate::expect::ops::ExpectIsoDateTime::within_past`].
+///
+/// By default the [`SystemClock`] is used. This can be changed for one assertion
+/// with [`crate::ExpectJsonOptions::clock`], or for the current thread with [`set_thread_clock`].
+///
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A clock reading the real time.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+///
+/// A clock which is frozen at the time given.
+///
+/// ```rust
+/// use chrono::TimeZone;
+/// use chrono::Utc;
+/// use expect_json::ExpectJsonOptions;
+/// use expect_json::FixedClock;
+/// use expect_json::expect;
+/// use expect_json::expect_json_eq_with;
+/// use serde_json::json;
+/// use std::time::Duration;
+///
+/// let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+/// let options = ExpectJsonOptions::new().clock(clock);
+///
+/// expect_json_eq_with(
+///     &json!({ "created_at": "2025-01-01T11:59:30Z" }),
+///     &json!({
+///         "created_at": expect::iso_date_time().within_past(Duration::from_secs(60)),
+///     }),
+///     options,
+/// ).unwrap();
+/// ```
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    pub fn new<Tz>(now: DateTime<Tz>) -> Self
+    where
+        Tz: TimeZone,
+    {
+        Self(now.with_timezone(&Utc))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+///
+/// Sets the clock used by assertions on the current thread,
+/// until the guard returned is dropped.
+///
+/// A clock given with [`crate::ExpectJsonOptions::clock`] takes precedence over this.
+///
+/// The clock is only seen on this thread. Async tasks can move between threads,
+/// and so can miss it, so use [`crate::ExpectJsonOptions::clock`] in async tests instead.
+///
+/// Guards must be dropped in the reverse order they were made,
+/// so each one restores the clock set before it. Dropping them out of order panics.
+///
+/// ```rust
+/// use chrono::TimeZone;
+/// use chrono::Utc;
+/// use expect_json::FixedClock;
+/// use expect_json::expect;
+/// use expect_json::expect_json_eq;
+/// use expect_json::set_thread_clock;
+/// use serde_json::json;
+/// use std::time::Duration;
+///
+/// let _clock_guard = set_thread_clock(FixedClock::new(
+///     Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+/// ));
+///
+/// expect_json_eq(
+///     &json!("2025-01-01T11:59:30Z"),
+///     &json!(expect::iso_date_time().within_past(Duration::from_secs(60))),
+/// ).unwrap();
+/// ```
+///
+#[must_use = "the clock is reset when the guard is dropped"]
+pub fn set_thread_clock<C>(clock: C) -> ThreadClockGuard
+where
+    C: Clock + 'static,
+{
+    let clock = SharedClock::new(clock);
+    let previous = THREAD_CLOCK.with(|thread_clock| thread_clock.replace(Some(clock)));
+    let depth = THREAD_CLOCK_DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    });
+
+    ThreadClockGuard {
+        previous,
+        depth,
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous thread clock when dropped. See [`set_thread_clock`].
+///
+/// This cannot be sent to other threads, as it restores the clock of the thread it was made on.
+#[derive(Debug)]
+pub struct ThreadClockGuard {
+    previous: Option<SharedClock>,
+    depth: usize,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for ThreadClockGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        THREAD_CLOCK.with(|thread_clock| thread_clock.replace(previous));
+
+        let current_depth = THREAD_CLOCK_DEPTH.with(|depth| depth.replace(self.depth - 1));
+        if current_depth != self.depth && !thread::panicking() {
+            panic!(
+                "ThreadClockGuard dropped out of order, guards must be dropped in the reverse order they were made"
+            );
+        }
+    }
+}
+
+/// A clock that can be shared across contexts, and held in the options.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedClock(Arc<dyn Clock>);
+
+impl SharedClock {
+    pub(crate) fn new<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        Self(Arc::new(clock))
+    }
+
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        self.0.now()
+    }
+}
+
+/// Clocks are only equal when they are the same clock.
+impl PartialEq for SharedClock {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Returns the time now, from the thread clock if one is set, or the system clock otherwise.
+pub(crate) fn thread_clock_now() -> DateTime<Utc> {
+    THREAD_CLOCK
+        .with(|thread_clock| thread_clock.borrow().as_ref().map(SharedClock::now))
+        .unwrap_or_else(|| SystemClock.now())
+}
+
+#[cfg(test)]
+mod test_set_thread_clock {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_use_the_thread_clock_until_the_guard_is_dropped() {
+        let first = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+
+        let first_guard = set_thread_clock(FixedClock::new(first));
+        assert_eq!(thread_clock_now(), first);
+
+        let second_guard = set_thread_clock(FixedClock::new(second));
+        assert_eq!(thread_clock_now(), second);
+
+        drop(second_guard);
+        assert_eq!(thread_clock_now(), first);
+
+        drop(first_guard);
+        assert!(thread_clock_now() > second);
+    }
+
+    #[test]
+    #[should_panic(expected = "ThreadClockGuard dropped out of order")]
+    fn it_should_panic_when_guards_are_dropped_out_of_order() {
+        let first = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        let second = Utc.with_ymd_and_hms(2010, 1, 1, 0, 0, 0).unwrap();
+
+        let first_guard = set_thread_clock(FixedClock::new(first));
+        let second_guard = set_thread_clock(FixedClock::new(second));
+
+        drop(first_guard);
+        drop(second_guard);
+    }
+}
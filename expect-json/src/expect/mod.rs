@@ -1,13 +1,30 @@
 pub mod ops;
 
+use crate::expect::ops::ExpectAbsent;
+use crate::expect::ops::ExpectAllOf;
+use crate::expect::ops::ExpectAnyOf;
 use crate::expect::ops::ExpectArray;
+use crate::expect::ops::ExpectAt;
+use crate::expect::ops::ExpectCapture;
+use crate::expect::ops::ExpectDate;
+use crate::expect::ops::ExpectDateTimeFormat;
 use crate::expect::ops::ExpectEmail;
 use crate::expect::ops::ExpectFloat;
 use crate::expect::ops::ExpectInteger;
 use crate::expect::ops::ExpectIsoDateTime;
+use crate::expect::ops::ExpectNot;
 use crate::expect::ops::ExpectObject;
+use crate::expect::ops::ExpectOptional;
+use crate::expect::ops::ExpectSameAs;
+use crate::expect::ops::ExpectSchema;
 use crate::expect::ops::ExpectString;
+use crate::expect::ops::ExpectTime;
+use crate::expect::ops::ExpectUnixTimestamp;
 use crate::expect::ops::ExpectUuid;
+use crate::expect::ops::FieldRef;
+use crate::expect::ops::compile_schema_patterns;
+use crate::internals::utils::parse_json_pointer;
+use serde_json::Value;
 
 ///
 /// Expect a JSON object. See [`ExpectObject`] for further methods to
@@ -207,6 +224,144 @@ pub fn iso_date_time() -> ExpectIsoDateTime {
     ExpectIsoDateTime::new()
 }
 
+///
+/// Expect a date on its own, such as `"2025-03-01"`.
+///
+/// Further methods are available on the returned [`ExpectDate`]
+/// to check the date is within a range, or relative to today.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user/barrington")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington",
+///         "birthday": expect_json::date(),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn date() -> ExpectDate {
+    ExpectDate::new()
+}
+
+///
+/// Expect a time of day on its own, such as `"14:30:00"`.
+///
+/// Further methods are available on the returned [`ExpectTime`]
+/// to check the time is within a range.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/shop")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington's Bakery",
+///         "opens_at": expect_json::time(),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn time() -> ExpectTime {
+    ExpectTime::new()
+}
+
+///
+/// Expect a date time string in the format given, using chrono's `strftime` syntax.
+///
+/// Further methods are available on the returned [`ExpectDateTimeFormat`]
+/// to check the date time is within a range, or relative to now.
+///
+/// This will panic if the format is invalid.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/legacy/user/barrington")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington",
+///         "joined": expect_json::date_time_format("%d/%m/%Y"),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn date_time_format(format: &str) -> ExpectDateTimeFormat {
+    ExpectDateTimeFormat::new(format.to_string())
+}
+
+///
+/// Expect an integer holding a Unix timestamp, in seconds by default.
+///
+/// Further methods are available on the returned [`ExpectUnixTimestamp`]
+/// to change the unit, and check the timestamp is within a range or relative to now.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/latest-event")
+///     .await
+///     .assert_json(&json!({
+///         "name": "user_created",
+///         "created_at": expect_json::unix_timestamp().millis(),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn unix_timestamp() -> ExpectUnixTimestamp {
+    ExpectUnixTimestamp::new()
+}
+
 ///
 /// Expect a valid UUID.
 ///
@@ -272,3 +427,402 @@ pub fn uuid() -> ExpectUuid {
 pub fn email() -> ExpectEmail {
     ExpectEmail::new()
 }
+
+///
+/// Expects the value matches at least one of the values given.
+/// Each can be an exact value, or another expectation.
+///
+/// When none match, the error lists why each of them failed.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Alice",
+///         "manager_id": expect_json::any_of([
+///             json!(expect_json::uuid()),
+///             json!(null),
+///         ]),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn any_of<I, V>(expected_values: I) -> ExpectAnyOf
+where
+    I: IntoIterator<Item = V>,
+    V: Into<Value>,
+{
+    ExpectAnyOf::new(expected_values.into_iter().map(Into::into).collect())
+}
+
+///
+/// Expects the value matches every one of the values given.
+/// Each can be an exact value, or another expectation.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Alice",
+///         "status": expect_json::all_of([
+///             json!(expect_json::integer().in_range(100..600)),
+///             json!(expect_json::not(404)),
+///         ]),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn all_of<I, V>(expected_values: I) -> ExpectAllOf
+where
+    I: IntoIterator<Item = V>,
+    V: Into<Value>,
+{
+    ExpectAllOf::new(expected_values.into_iter().map(Into::into).collect())
+}
+
+///
+/// Expects the value does not match the value given.
+/// This can be an exact value, or another expectation.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Alice",
+///         "age": expect_json::not(0),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn not<V>(expected: V) -> ExpectNot
+where
+    V: Into<Value>,
+{
+    ExpectNot::new(expected.into())
+}
+
+///
+/// Expects an object field is either missing, or matches the value given.
+/// This can be an exact value, or another expectation.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Alice",
+///         "email": expect_json::optional(expect_json::email()),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn optional<V>(expected: V) -> ExpectOptional
+where
+    V: Into<Value>,
+{
+    ExpectOptional::new(expected.into())
+}
+
+///
+/// Expects an object field is not present.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Alice",
+///         "password_hash": expect_json::absent(),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn absent() -> ExpectAbsent {
+    ExpectAbsent::new()
+}
+
+///
+/// Expects the value at the Json Pointer (RFC 6901) given, within this value,
+/// to match the value given. This can be an exact value, or another expectation.
+///
+/// Paths in errors are still given from the root of the document.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/orders")
+///     .await
+///     .assert_json(&expect_json::array().all(
+///         expect_json::at("/customer/address/country", "GB"),
+///     ));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn at<V>(pointer: &str, expected: V) -> ExpectAt
+where
+    V: Into<Value>,
+{
+    if parse_json_pointer(pointer).is_none() {
+        panic!(
+            "expect::at() expected a Json Pointer, which is empty or starts with '/'. Received: {pointer}"
+        );
+    }
+
+    ExpectAt::new(pointer.to_string(), expected.into())
+}
+
+///
+/// Expects the value is valid against the Json Schema given.
+///
+/// This supports draft 2020-12, with the core and validation vocabularies.
+/// References are only resolved within the schema given, such as `"#/$defs/user"`,
+/// and nothing is fetched over the network. The `format` keyword is treated as an annotation,
+/// and is not validated.
+///
+/// Each violation is reported at the path of the value that failed.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&expect_json::schema(json!({
+///         "type": "object",
+///         "required": ["id", "name"],
+///         "properties": {
+///             "id": { "type": "integer", "minimum": 1 },
+///             "name": { "type": "string" },
+///         },
+///     })));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn schema<V>(schema: V) -> ExpectSchema
+where
+    V: Into<Value>,
+{
+    let schema = schema.into();
+    if !schema.is_object() && !schema.is_boolean() {
+        panic!("expect::schema() expected a schema object or boolean. Received: {schema}");
+    }
+
+    let patterns = compile_schema_patterns(&schema);
+    for (pattern, compiled) in &patterns {
+        if let Err(error) = compiled {
+            panic!(
+                "expect::schema() expected valid regex patterns in the schema. Received: '{pattern}', {error}"
+            );
+        }
+    }
+
+    ExpectSchema::new(schema, patterns)
+}
+
+///
+/// Expects the value matches the value given, and captures it under the name given.
+/// This can be an exact value, or another expectation.
+///
+/// Any other [`capture`] or [`same_as`] using the same name must then hold an equal value.
+/// The values captured are returned from [`crate::expect_json_eq_captures`] when it succeeds.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "id": expect_json::capture("user_id", expect_json::uuid()),
+///         "links": {
+///             "self": expect_json::same_as("user_id"),
+///         },
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn capture<V>(name: &str, expected: V) -> ExpectCapture
+where
+    V: Into<Value>,
+{
+    ExpectCapture::new(name.to_string(), expected.into())
+}
+
+///
+/// Expects the value is equal to the value captured under the name given, using [`capture`].
+///
+/// It does not matter which comes first within the Json.
+/// However the name must be captured somewhere, otherwise the assertion fails.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user")
+///     .await
+///     .assert_json(&json!({
+///         "id": expect_json::capture("user_id", expect_json::uuid()),
+///         "links": {
+///             "self": expect_json::same_as("user_id"),
+///         },
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn same_as(name: &str) -> ExpectSameAs {
+    ExpectSameAs::new(name.to_string())
+}
+
+///
+/// A reference to another field within the received Json,
+/// for use as a bound in comparisons.
+///
+/// Paths starting with `/` are Json Pointers from the root, such as `"/created_at"`.
+/// Otherwise they are relative to the value being checked, such as `"../created_at"`.
+///
+/// Use [`FieldRef::sum`] to compare against the sum of many fields,
+/// such as `expect::field("/items/*/price").sum()`.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/booking")
+///     .await
+///     .assert_json(&json!({
+///         "start": expect_json::integer(),
+///         "end": expect_json::integer().greater_than(expect_json::field("../start")),
+///         "booked_at": expect_json::iso_date_time(),
+///         "updated_at": expect_json::iso_date_time()
+///             .greater_than_equal(expect_json::field("/booked_at")),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+pub fn field(path: &str) -> FieldRef {
+    FieldRef::new(path.to_string())
+}
@@ -0,0 +1,98 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use crate::internals::objects::ValueObject;
+use serde_json::Value;
+
+///
+/// Expects an object field is not present.
+///
+/// You can build these using the [`crate::expect::absent`] function.
+///
+#[expect_op(internal, name = "absent")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectAbsent;
+
+impl ExpectAbsent {
+    pub(crate) fn new() -> Self {
+        Self
+    }
+}
+
+impl ExpectOp for ExpectAbsent {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let error_message = format!(
+            r#"expected field to not be present,
+    received {}"#,
+            ValueObject::from(received.clone())
+        );
+        Err(ExpectOpError::custom(self, context, error_message))
+    }
+
+    fn allows_missing_key(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test_absent {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_missing_field() {
+        let left = json!({ "name": "Joe" });
+        let right = json!({
+            "name": "Joe",
+            "password_hash": expect::absent(),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_present_field() {
+        let left = json!({ "name": "Joe", "password_hash": "abc123" });
+        let right = json!({
+            "name": "Joe",
+            "password_hash": expect::absent(),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::absent() error at root.password_hash:
+    expected field to not be present,
+    received "abc123""#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_present_null_field() {
+        let left = json!({ "name": "Joe", "password_hash": null });
+        let right = json!({
+            "name": "Joe",
+            "password_hash": expect::absent(),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn it_should_accept_missing_field_within_array_of_objects() {
+        let left = json!([{ "name": "Joe" }, { "name": "Jane" }]);
+        let right = json!(expect::array().all(json!({
+            "name": expect::string(),
+            "password_hash": expect::absent(),
+        })));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+}
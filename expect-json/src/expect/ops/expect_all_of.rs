@@ -0,0 +1,96 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
+use serde_json::Value;
+
+///
+/// Expects the value matches every one of the values given.
+/// These can be exact values, or other expectations.
+///
+/// You can build these using the [`crate::expect::all_of`] function.
+///
+#[expect_op(internal, name = "all_of")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectAllOf {
+    expected_values: Vec<Value>,
+}
+
+impl ExpectAllOf {
+    pub(crate) fn new(expected_values: Vec<Value>) -> Self {
+        Self { expected_values }
+    }
+}
+
+impl ExpectOp for ExpectAllOf {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let checkpoint = context.captures_checkpoint();
+        let result = self.check_all(context, received);
+        checkpoint.restore_on_err(result)
+    }
+}
+
+impl ExpectAllOf {
+    fn check_all(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
+        for expected in &self.expected_values {
+            let result = context
+                .json_eq(received, expected)
+                .map_err(ExpectOpError::from);
+            errors.check(result)?;
+        }
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod test_all_of {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_value_matching_all_expectations() {
+        let left = json!(200);
+        let right = json!(expect::all_of([
+            json!(expect::integer().in_range(100..600)),
+            json!(expect::not(404)),
+        ]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_no_expectations() {
+        let left = json!(123);
+        let right = json!(expect::all_of(Vec::<serde_json::Value>::new()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_return_error_of_failing_expectation() {
+        let left = json!({ "status": 404 });
+        let right = json!({
+            "status": expect::all_of([
+                json!(expect::integer().in_range(100..600)),
+                json!(expect::not(404)),
+            ]),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::not() error at root.status, received value matched:
+    expected not 404
+    received 404"#
+        );
+    }
+}
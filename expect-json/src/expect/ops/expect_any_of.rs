@@ -0,0 +1,117 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use serde_json::Value;
+
+///
+/// Expects the value matches at least one of the values given.
+/// These can be exact values, or other expectations.
+///
+/// You can build these using the [`crate::expect::any_of`] function.
+///
+#[expect_op(internal, name = "any_of")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectAnyOf {
+    expected_values: Vec<Value>,
+}
+
+impl ExpectAnyOf {
+    pub(crate) fn new(expected_values: Vec<Value>) -> Self {
+        Self { expected_values }
+    }
+}
+
+impl ExpectOp for ExpectAnyOf {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let mut errors = Vec::with_capacity(self.expected_values.len());
+        for expected in &self.expected_values {
+            let checkpoint = context.captures_checkpoint();
+            match checkpoint.restore_on_err(context.json_eq(received, expected)) {
+                Ok(()) => return Ok(()),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        Err(ExpectOpError::AnyOfNoMatch {
+            context: context.to_static(),
+            errors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_any_of {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_value_matching_first_expectation() {
+        let left = json!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8");
+        let right = json!(expect::any_of([json!(expect::uuid()), json!(null)]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_value_matching_last_expectation() {
+        let left = json!(null);
+        let right = json!(expect::any_of([json!(expect::uuid()), json!(null)]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_object_matching_one_of_many_shapes() {
+        let left = json!({ "type": "dog", "barks": true });
+        let right = json!({
+            "pet": expect::any_of([
+                json!({ "type": "cat", "meows": true }),
+                json!({ "type": "dog", "barks": true }),
+            ])
+        });
+
+        let output = expect_json_eq(&json!({ "pet": left }), &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_list_why_each_expectation_failed() {
+        let left = json!({ "id": 123 });
+        let right = json!({ "id": expect::any_of([json!(expect::uuid()), json!(null)]) });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::any_of() error at root.id, none of the 2 expectations matched:
+
+    expectation 0 failed,
+        Json expect::uuid() at root.id, received wrong type:
+            expected string
+            received integer 123
+
+    expectation 1 failed,
+        Json is not null at root.id, expected null:
+            expected null
+            received integer 123"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_given_no_expectations() {
+        let left = json!(123);
+        let right = json!(expect::any_of(Vec::<serde_json::Value>::new()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::any_of() error at root, none of the 0 expectations matched:"#
+        );
+    }
+}
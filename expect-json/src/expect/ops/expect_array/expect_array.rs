@@ -5,11 +5,13 @@ use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
 use serde_json::Value;
 use std::fmt::Debug;
 
 #[expect_op(internal, name = "array")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectArray {
     sub_ops: Vec<ExpectArraySubOp>,
 }
@@ -58,6 +60,48 @@ impl ExpectArray {
         self
     }
 
+    /// Expects none of the values given are in the array.
+    /// These can be exact values, or an `ExpectOp`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.delete(&"/users/123").await;
+    ///
+    /// server.get(&"/users")
+    ///     .await
+    ///     .assert_json(&expect_json::array().not_contains([
+    ///         expect_json::object().contains(json!({ "id": 123 })),
+    ///     ]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn not_contains<I, V>(mut self, expected_values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        let inner_expected_values = expected_values
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        self.sub_ops
+            .push(ExpectArraySubOp::NotContains(inner_expected_values));
+        self
+    }
+
     /// Expects all values in the array match the expected values in some order.
     /// This can be an exact value, or an `ExpectOp`.
     /// The lengths of the arrays must be equal.
@@ -219,11 +263,12 @@ impl ExpectArray {
 
 impl ExpectOp for ExpectArray {
     fn on_array(&self, context: &mut Context, received: &[Value]) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for sub_op in &self.sub_ops {
-            sub_op.on_array(self, context, received)?;
+            errors.check(sub_op.on_array(self, context, received))?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 
     fn debug_supported_types(&self) -> &'static [JsonType] {
@@ -364,6 +409,107 @@ mod test_contains {
     }
 }
 
+#[cfg(test)]
+mod test_not_contains {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_be_ok_for_totally_different_values() {
+        let left = json!([0, 1, 2, 3]);
+        let right = json!(expect::array().not_contains([4, 5, 6]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_be_ok_for_empty_not_contains() {
+        let left = json!([0, 1, 2, 3]);
+        let right = json!(expect::array().not_contains([] as [u32; 0]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_error_for_identical_numeric_arrays() {
+        let left = json!([1, 2, 3]);
+        let right = json!(expect::array().not_contains([1, 2, 3]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json array at root contains value was expecting to not be there:
+    expected array to not contain 1, but it was found.
+    received [1, 2, 3]"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_reversed_identical_numeric_arrays() {
+        let left = json!([1, 2, 3]);
+        let right = json!(expect::array().not_contains([3, 2, 1]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json array at root contains value was expecting to not be there:
+    expected array to not contain 3, but it was found.
+    received [1, 2, 3]"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_partial_contains() {
+        let left = json!([0, 1, 2, 3, 4, 5]);
+        let right = json!(expect::array().not_contains([6, 3]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json array at root contains value was expecting to not be there:
+    expected array to not contain 3, but it was found.
+    received [0, 1, 2, 3, 4, 5]"#
+        );
+    }
+
+    #[test]
+    fn it_should_be_ok_when_no_values_match_expect_op() {
+        let left = json!([{ "id": 1 }, { "id": 2 }]);
+        let right =
+            json!(expect::array().not_contains([expect::object().contains(json!({ "id": 3 }))]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_error_when_a_value_matches_expect_op() {
+        let left = json!({ "users": [{ "id": 1 }, { "id": 2 }] });
+        let right = json!({
+            "users": expect::array().not_contains([expect::object().contains(json!({ "id": 2 }))]),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json array at root.users contains value was expecting to not be there:
+    expected array to not contain expect::object(), but it was found.
+    received [
+        {
+            "id": 1
+        },
+        {
+            "id": 2
+        }
+    ]"#
+        );
+    }
+}
+
 #[cfg(test)]
 mod test_empty {
     use crate::expect;
@@ -594,6 +740,24 @@ mod test_eq_unordered {
         );
     }
 
+    #[test]
+    fn it_should_give_the_index_of_items_failing_when_compared_again() {
+        let left = json!([1, 2]);
+        let right = json!(expect::array().eq_unordered([
+            json!(expect::capture("id", expect::integer())),
+            json!(expect::same_as("id")),
+        ]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::capture() error at root[1]:
+    expected value captured as "id",
+    expected 1
+    received 2"#
+        );
+    }
+
     #[test]
     fn it_should_pass_with_complex_matches() {
         let left = json!(["Alice", "Bob", "Charlie"]);
@@ -3,6 +3,7 @@ use crate::expect::ops::ExpectArray;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
+use crate::internals::ErrorCollector;
 use crate::internals::objects::ArrayObject;
 use crate::internals::utils::bipartite_match;
 use serde::Deserialize;
@@ -18,6 +19,7 @@ pub enum ExpectArraySubOp {
     Len(usize),
     MaxLen(usize),
     Contains(Vec<Value>),
+    NotContains(Vec<Value>),
     EqUnordered(Vec<Value>),
     AllUnique,
     AllEqual(Value),
@@ -39,6 +41,9 @@ impl ExpectArraySubOp {
             Self::Contains(expected_values) => {
                 Self::on_array_contains(expected_values, parent, context, received)
             }
+            Self::NotContains(expected_values) => {
+                Self::on_array_not_contains(expected_values, parent, context, received)
+            }
             Self::EqUnordered(expected_values) => {
                 Self::on_array_eq_unordered(expected_values, parent, context, received)
             }
@@ -152,9 +157,12 @@ impl ExpectArraySubOp {
         // TODO: This is brute force as we don't know if we are containing an inner ExpectOp.
         // Can this be done without a brute force approach?
         for expected in expected_values {
-            let is_found = received_values
-                .iter()
-                .any(|received| context.json_eq(received, expected).is_ok());
+            let is_found = received_values.iter().any(|received| {
+                let checkpoint = context.captures_checkpoint();
+                checkpoint
+                    .restore_on_err(context.json_eq(received, expected))
+                    .is_ok()
+            });
 
             if !is_found {
                 return Err(ExpectOpError::ContainsNotFound {
@@ -169,6 +177,34 @@ impl ExpectArraySubOp {
         Ok(())
     }
 
+    fn on_array_not_contains(
+        expected_values: &[Value],
+        _parent: &ExpectArray,
+        context: &mut Context<'_>,
+        received_values: &[Value],
+    ) -> ExpectOpResult<()> {
+        for expected in expected_values {
+            let is_found = received_values.iter().any(|received| {
+                let checkpoint = context.captures_checkpoint();
+                let is_matched = context.json_eq(received, expected).is_ok();
+                checkpoint.restore();
+
+                is_matched
+            });
+
+            if is_found {
+                return Err(ExpectOpError::ContainsFound {
+                    context: context.to_static(),
+                    json_type: JsonType::Array,
+                    expected: expected.clone().into(),
+                    received: ArrayObject::from(received_values.to_owned()).into(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     fn on_array_eq_unordered(
         expected_values: &[Value],
         _parent: &ExpectArray,
@@ -186,7 +222,15 @@ impl ExpectArraySubOp {
         let mut edges: Vec<(usize, usize)> = Vec::new();
         for (expected_index, expected_value) in expected_values.iter().enumerate() {
             for (received_index, received_value) in received_values.iter().enumerate() {
-                if context.json_eq(received_value, expected_value).is_ok() {
+                // Every pairing is only a candidate, so nothing captured here is kept.
+                let checkpoint = context.captures_checkpoint();
+                let is_matched = context
+                    .with_path(received_index)
+                    .json_eq(received_value, expected_value)
+                    .is_ok();
+                checkpoint.restore();
+
+                if is_matched {
                     edges.push((expected_index, received_index));
                 }
             }
@@ -199,6 +243,18 @@ impl ExpectArraySubOp {
                 received_array: ArrayObject::from(received_values.to_owned()),
             });
         }
+
+        // Compare the chosen pairs again, to capture their values.
+        for (received_index, (received_value, expected_index)) in
+            received_values.iter().zip(&matches).enumerate()
+        {
+            if let Some(expected_index) = expected_index {
+                context
+                    .with_path(received_index)
+                    .json_eq(received_value, &expected_values[*expected_index])?;
+            }
+        }
+
         Ok(())
     }
 
@@ -230,16 +286,18 @@ impl ExpectArraySubOp {
         context: &mut Context<'_>,
         received_values: &[Value],
     ) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for (index, value) in received_values.iter().enumerate() {
-            context
+            let result = context
                 .with_path(index)
                 .json_eq(value, expected_value)
                 .map_err(|error| ExpectOpError::ArrayAllEqual {
                     error: Box::new(error),
                     received_full_array: ArrayObject::from(received_values.to_owned()),
-                })?;
+                });
+            errors.check(result)?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 }
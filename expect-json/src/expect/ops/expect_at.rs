@@ -0,0 +1,108 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use crate::internals::json_pointer_find;
+use serde_json::Value;
+
+///
+/// Expects the value at the Json Pointer (RFC 6901) given, within the received value,
+/// to match the value given.
+///
+/// You can build these using the [`crate::expect::at`] function.
+///
+#[expect_op(internal, name = "at")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectAt {
+    pointer: String,
+    expected: Value,
+}
+
+impl ExpectAt {
+    pub(crate) fn new(pointer: String, expected: Value) -> Self {
+        Self { pointer, expected }
+    }
+}
+
+impl ExpectOp for ExpectAt {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        // The path is extended from a copy, so it stays anchored at the document root.
+        let mut context: Context<'_> = context.clone();
+        let received_at = json_pointer_find(&mut context, received, &self.pointer)?;
+        context
+            .json_eq(received_at, &self.expected)
+            .map_err(ExpectOpError::from)
+    }
+}
+
+#[cfg(test)]
+mod test_at {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_matching_value_at_pointer() {
+        let left = json!({
+            "user": { "profile": { "name": "Joe", "age": 30 } },
+        });
+        let right = json!({
+            "user": expect::at("/profile/name", "Joe"),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_work_within_arrays() {
+        let left = json!([{ "tags": ["a", "b"] }, { "tags": ["c"] }]);
+        let right = json!(expect::array().all(expect::at("/tags/0", expect::string())));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_give_errors_from_the_document_root() {
+        let left = json!({
+            "data": { "items": [{ "owner": { "name": "Joe" } }] },
+        });
+        let right = json!({
+            "data": expect::at("/items/0/owner/name", "Sam"),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json strings at root.data.items[0].owner.name are not equal:
+    expected "Sam"
+    received "Joe""#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_the_pointer_is_not_found() {
+        let left = json!({
+            "data": { "items": [{ "owner": "Joe" }] },
+        });
+        let right = json!({
+            "data": expect::at("/items/0/name", "Joe"),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root.data.items[0] has no value at 'name', for path '/items/0/name'"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_the_pointer_is_invalid() {
+        let _ = expect::at("items", "Joe");
+    }
+}
@@ -0,0 +1,179 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use crate::internals::objects::ValueObject;
+use serde_json::Value;
+
+///
+/// Expects the value matches the value given, and binds it to a name.
+/// Other uses of the same name must then hold an equal value.
+///
+/// You can build these using the [`crate::expect::capture`] function.
+///
+#[expect_op(internal, name = "capture")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectCapture {
+    name: String,
+    expected: Value,
+}
+
+impl ExpectCapture {
+    pub(crate) fn new(name: String, expected: Value) -> Self {
+        Self { name, expected }
+    }
+}
+
+impl ExpectOp for ExpectCapture {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        context
+            .json_eq(received, &self.expected)
+            .map_err(ExpectOpError::from)?;
+
+        let maybe_bound = context.get_or_bind_capture(&self.name, received);
+        check_bound_capture(self, context, &self.name, maybe_bound, received)
+    }
+}
+
+/// Passes when nothing was bound to the name before,
+/// otherwise checks it is equal to the value already bound.
+pub(crate) fn check_bound_capture<O>(
+    expect_op: &O,
+    context: &mut Context<'_>,
+    name: &str,
+    maybe_bound: Option<Value>,
+    received: &Value,
+) -> ExpectOpResult<()>
+where
+    O: ExpectOp,
+{
+    let Some(bound) = maybe_bound else {
+        return Ok(());
+    };
+
+    if bound != *received {
+        let error_message = format!(
+            r#"expected value captured as "{name}",
+    expected {}
+    received {}"#,
+            ValueObject::from(bound),
+            ValueObject::from(received.clone())
+        );
+        return Err(ExpectOpError::custom(expect_op, context, error_message));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_capture {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_captures;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_return_captured_values() {
+        let left = json!({ "id": 123, "name": "Joe" });
+        let right = json!({
+            "id": expect::capture("user_id", expect::integer()),
+            "name": expect::capture("user_name", expect::string()),
+        });
+
+        let captures = expect_json_eq_captures(&left, &right).unwrap();
+        assert_eq!(captures.get("user_id"), Some(&json!(123)));
+        assert_eq!(
+            captures.get_as::<String>("user_name"),
+            Some("Joe".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_inner_expectation_fails() {
+        let left = json!({ "id": "abc" });
+        let right = json!({
+            "id": expect::capture("user_id", expect::integer()),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::integer() at root.id, received wrong type:
+    expected integer
+    received string "abc""#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_captured_twice_with_different_values() {
+        let left = json!([1, 2]);
+        let right = json!([
+            expect::capture("n", expect::integer()),
+            expect::capture("n", expect::integer()),
+        ]);
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::capture() error at root[1]:
+    expected value captured as "n",
+    expected 1
+    received 2"#
+        );
+    }
+
+    #[test]
+    fn it_should_not_keep_captures_from_array_items_that_did_not_match_contains() {
+        let left = json!([{ "id": 1, "name": "Sam" }, { "id": 2, "name": "Joe" }]);
+        let right = json!(expect::array().contains([json!({
+            "id": expect::capture("id", expect::integer()),
+            "name": "Joe",
+        })]));
+
+        let captures = expect_json_eq_captures(&left, &right).unwrap();
+        assert_eq!(captures.get("id"), Some(&json!(2)));
+    }
+
+    #[test]
+    fn it_should_not_keep_captures_from_any_of_branches_that_failed() {
+        let left = json!([{ "id": 1, "kind": "b" }, 2]);
+        let right = json!([
+            expect::any_of([
+                json!({ "id": expect::capture("id", expect::integer()), "kind": "a" }),
+                json!({ "id": expect::integer(), "kind": "b" }),
+            ]),
+            expect::capture("id", expect::integer()),
+        ]);
+
+        let captures = expect_json_eq_captures(&left, &right).unwrap();
+        assert_eq!(captures.get("id"), Some(&json!(2)));
+    }
+
+    #[test]
+    fn it_should_not_keep_captures_from_within_not() {
+        let left = json!([{ "id": 1, "kind": "a" }, 2]);
+        let right = json!([
+            expect::not(json!({ "id": expect::capture("id", expect::integer()), "kind": "b" })),
+            expect::capture("id", expect::integer()),
+        ]);
+
+        let captures = expect_json_eq_captures(&left, &right).unwrap();
+        assert_eq!(captures.get("id"), Some(&json!(2)));
+    }
+
+    #[test]
+    fn it_should_keep_captures_from_the_chosen_pairs_in_unordered_arrays() {
+        let left = json!([{ "id": 1, "kind": "b" }, { "id": 2, "kind": "a" }]);
+        let right = json!(expect::array().eq_unordered([
+            json!({ "id": expect::capture("a_id", expect::integer()), "kind": "a" }),
+            json!({ "id": expect::capture("b_id", expect::integer()), "kind": "b" }),
+        ]));
+
+        let captures = expect_json_eq_captures(&left, &right).unwrap();
+        assert_eq!(captures.get("a_id"), Some(&json!(2)));
+        assert_eq!(captures.get("b_id"), Some(&json!(1)));
+    }
+}
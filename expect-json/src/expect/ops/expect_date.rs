@@ -0,0 +1,292 @@
+use crate::JsonType;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::into_temporal_bound;
+use crate::expect::ops::utils::into_temporal_range;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use std::time::Duration as StdDuration;
+
+///
+/// Expects a date on its own, such as `"2025-03-01"`.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use std::time::Duration;
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/user/barrington")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington",
+///         "birthday": expect_json::date().less_than("2010-01-01"),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+///
+#[expect_op(internal, name = "date")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectDate {
+    checks: TemporalChecks,
+}
+
+impl ExpectDate {
+    pub(crate) fn new() -> Self {
+        Self {
+            checks: TemporalChecks::default(),
+        }
+    }
+
+    ///
+    /// Expects the date to be within a past duration, up to now.
+    ///
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks.within_past(duration);
+        self
+    }
+
+    ///
+    /// Expects the date to be from now, and up to a future duration.
+    ///
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks.within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the date to be after the bound given.
+    ///
+    /// The bound can also be a reference to another field, using [`crate::expect::field`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user/barrington")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "value": expect_json::date().greater_than("2025-01-01"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn greater_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than", bound);
+        self.checks.push(TemporalComparison::GreaterThan(bound));
+        self
+    }
+
+    /// Expects the date to be at or after the bound given.
+    pub fn greater_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than_equal", bound);
+        self.checks
+            .push(TemporalComparison::GreaterThanEqual(bound));
+        self
+    }
+
+    /// Expects the date to be before the bound given.
+    pub fn less_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than", bound);
+        self.checks.push(TemporalComparison::LessThan(bound));
+        self
+    }
+
+    /// Expects the date to be at or before the bound given.
+    pub fn less_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than_equal", bound);
+        self.checks.push(TemporalComparison::LessThanEqual(bound));
+        self
+    }
+
+    /// Expects the date to be between the two bounds given, inclusive.
+    pub fn between<B1, B2>(mut self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<String>>,
+        B2: Into<ValueOrFieldRef<String>>,
+    {
+        let (min, max) = into_temporal_range(&self, "between", min, max);
+        self.checks.push(TemporalComparison::Between { min, max });
+        self
+    }
+}
+
+impl ExpectOp for ExpectDate {
+    fn on_string(&self, context: &mut Context, received: &str) -> ExpectOpResult<()> {
+        let date = NaiveDate::parse_from_str(received, DATE_FORMAT).map_err(|error| {
+            let error_message = format!("failed to parse string '{received}' as date");
+            ExpectOpError::custom_error(self, context, error_message, error)
+        })?;
+
+        self.checks.check(self, context, received, date)
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::String]
+    }
+}
+
+impl ExpectTemporal for ExpectDate {
+    type Value = NaiveDate;
+
+    const FUNCTION_NAME: &'static str = "date";
+    const LABEL: &'static str = "date";
+    const TYPE_NAME: &'static str = "date";
+
+    fn bound_description(&self) -> String {
+        "a date".to_string()
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        NaiveDate::parse_from_str(raw, DATE_FORMAT)
+            .ok()
+            .or_else(|| {
+                DateTime::parse_from_rfc3339(raw)
+                    .ok()
+                    .map(|date_time| date_time.date_naive())
+            })
+    }
+}
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[cfg(test)]
+mod test_date {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_a_date() {
+        let left = json!("2025-03-01");
+        let right = json!(expect::date());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_a_date_time() {
+        let left = json!("2025-03-01T10:00:00Z");
+        let right = json!(expect::date());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::date() error at root:
+    failed to parse string '2025-03-01T10:00:00Z' as date,
+    trailing input"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_greater_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_fail_with_the_number_of_days_out() {
+        let left = json!("2024-12-29");
+        let right = json!(expect::date().greater_than_equal("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::date() error at root:
+    date is out of bounds,
+    expected greater than equal 2025-01-01
+    received 2024-12-29
+    difference 3 days"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_invalid_bounds() {
+        let _ = expect::date().greater_than("01/01/2025");
+    }
+}
+
+#[cfg(test)]
+mod test_within_past {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use chrono::Days;
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_accept_today() {
+        let today = Utc::now().date_naive().to_string();
+        let right = json!(expect::date().within_past(Duration::from_secs(60 * 60 * 24 * 7)));
+
+        let output = expect_json_eq(&json!(today), &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_dates_too_far_in_the_past() {
+        let date = (Utc::now().date_naive() - Days::new(8)).to_string();
+        let right = json!(expect::date().within_past(Duration::from_secs(60 * 60 * 24 * 7)));
+
+        let output = expect_json_eq(&json!(date), &right)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            format!(
+                r#"Json expect::date() error at root:
+    date '{date}' is too far from the past, expected between '7 days' ago and now"#
+            )
+        );
+    }
+}
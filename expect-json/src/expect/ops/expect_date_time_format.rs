@@ -0,0 +1,306 @@
+use crate::JsonType;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::into_temporal_bound;
+use crate::expect::ops::utils::into_temporal_range;
+use crate::expect::ops::utils::parse_date_time_bound;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::ParseResult;
+use chrono::format::Item;
+use chrono::format::ParseErrorKind;
+use chrono::format::StrftimeItems;
+use std::time::Duration as StdDuration;
+
+///
+/// Expects a date time string in a custom format, using chrono's `strftime` syntax.
+///
+/// Formats without an offset are taken as UTC,
+/// and formats with only a date are taken as midnight UTC.
+///
+/// For example `"%d/%m/%Y"` matches `"01/03/2025"`,
+/// and `"%a, %d %b %Y %H:%M:%S %z"` matches RFC 2822 strings such as `"Sat, 01 Mar 2025 14:30:00 +0000"`.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use std::time::Duration;
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/legacy/user/barrington")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington",
+///         "joined": expect_json::date_time_format("%d/%m/%Y")
+///             .within_past(Duration::from_secs(60 * 60 * 24 * 365)),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+///
+#[expect_op(internal, name = "date_time_format")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectDateTimeFormat {
+    format: String,
+    checks: TemporalChecks,
+}
+
+impl ExpectDateTimeFormat {
+    pub(crate) fn new(format: String) -> Self {
+        let is_invalid_format = StrftimeItems::new(&format).any(|item| item == Item::Error);
+        if is_invalid_format {
+            panic!("date_time_format() expected a valid date time format. Received: '{format}'");
+        }
+
+        Self {
+            format,
+            checks: TemporalChecks::default(),
+        }
+    }
+
+    ///
+    /// Expects the date time to be within a past duration, up to now.
+    ///
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks.within_past(duration);
+        self
+    }
+
+    ///
+    /// Expects the date time to be from now, and up to a future duration.
+    ///
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks.within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the date time to be after the bound given.
+    ///
+    /// The bound can also be a reference to another field, using [`crate::expect::field`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/legacy/user/barrington")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "value": expect_json::date_time_format("%d/%m/%Y").greater_than("01/01/2025"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn greater_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than", bound);
+        self.checks.push(TemporalComparison::GreaterThan(bound));
+        self
+    }
+
+    /// Expects the date time to be at or after the bound given.
+    pub fn greater_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than_equal", bound);
+        self.checks
+            .push(TemporalComparison::GreaterThanEqual(bound));
+        self
+    }
+
+    /// Expects the date time to be before the bound given.
+    pub fn less_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than", bound);
+        self.checks.push(TemporalComparison::LessThan(bound));
+        self
+    }
+
+    /// Expects the date time to be at or before the bound given.
+    pub fn less_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than_equal", bound);
+        self.checks.push(TemporalComparison::LessThanEqual(bound));
+        self
+    }
+
+    /// Expects the date time to be between the two bounds given, inclusive.
+    pub fn between<B1, B2>(mut self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<String>>,
+        B2: Into<ValueOrFieldRef<String>>,
+    {
+        let (min, max) = into_temporal_range(&self, "between", min, max);
+        self.checks.push(TemporalComparison::Between { min, max });
+        self
+    }
+}
+
+impl ExpectOp for ExpectDateTimeFormat {
+    fn on_string(&self, context: &mut Context, received: &str) -> ExpectOpResult<()> {
+        let date_time = parse_with_format(received, &self.format).map_err(|error| {
+            let error_message = format!(
+                "failed to parse string '{received}' with format '{}'",
+                self.format
+            );
+            ExpectOpError::custom_error(self, context, error_message, error)
+        })?;
+
+        self.checks.check(self, context, received, date_time)
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::String]
+    }
+}
+
+impl ExpectTemporal for ExpectDateTimeFormat {
+    type Value = DateTime<FixedOffset>;
+
+    const FUNCTION_NAME: &'static str = "date_time_format";
+    const LABEL: &'static str = "date time";
+    const TYPE_NAME: &'static str = "date time";
+
+    fn bound_description(&self) -> String {
+        format!(
+            "a date time in the format '{}', or an iso date time",
+            self.format
+        )
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        parse_with_format(raw, &self.format)
+            .ok()
+            .or_else(|| parse_date_time_bound(raw))
+    }
+}
+
+/// Parses with the format, falling back to UTC when the format has no offset,
+/// and to midnight when it has no time.
+fn parse_with_format(raw: &str, format: &str) -> ParseResult<DateTime<FixedOffset>> {
+    match DateTime::parse_from_str(raw, format) {
+        Err(error) if error.kind() == ParseErrorKind::NotEnough => {}
+        result => return result,
+    }
+
+    match NaiveDateTime::parse_from_str(raw, format) {
+        Err(error) if error.kind() == ParseErrorKind::NotEnough => {}
+        result => return result.map(|date_time| date_time.and_utc().fixed_offset()),
+    }
+
+    NaiveDate::parse_from_str(raw, format)
+        .map(|date| date.and_time(NaiveTime::MIN).and_utc().fixed_offset())
+}
+
+#[cfg(test)]
+mod test_date_time_format {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_dates_in_the_format() {
+        let left = json!("01/03/2025");
+        let right = json!(expect::date_time_format("%d/%m/%Y"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_rfc_2822_strings() {
+        let left = json!("Sat, 01 Mar 2025 14:30:00 +0100");
+        let right = json!(
+            expect::date_time_format("%a, %d %b %Y %H:%M:%S %z")
+                .greater_than("2025-03-01T13:00:00Z")
+        );
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_strings_not_in_the_format() {
+        let left = json!("2025-03-01");
+        let right = json!(expect::date_time_format("%d/%m/%Y"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::date_time_format() error at root:
+    failed to parse string '2025-03-01' with format '%d/%m/%Y',
+    input contains invalid characters"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_invalid_formats() {
+        let _ = expect::date_time_format("%Q");
+    }
+}
+
+#[cfg(test)]
+mod test_less_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_compare_bounds_given_in_the_same_format() {
+        let left = json!("03/01/2025");
+        let right = json!(expect::date_time_format("%d/%m/%Y").less_than("01/01/2025"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::date_time_format() error at root:
+    date time is out of bounds,
+    expected less than 01/01/2025
+    received 03/01/2025
+    difference 2 days"#
+        );
+    }
+}
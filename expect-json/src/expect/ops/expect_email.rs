@@ -4,8 +4,10 @@ use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::utils::RegexPattern;
 use core::str::FromStr;
 use email_address::EmailAddress;
+use std::borrow::Cow;
 
 ///
 /// Expects a valid email address string.
@@ -14,9 +16,16 @@ use email_address::EmailAddress;
 ///
 #[expect_op(internal, name = "email")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectEmail {
     expected_domain: Option<String>,
     expected_local_part: Option<String>,
+    maybe_domains_in: Option<Vec<String>>,
+    maybe_domain_suffix: Option<String>,
+    maybe_local_part_regex: Option<RegexPattern>,
+    is_case_insensitive: bool,
+    is_plus_addressing_forbidden: bool,
+    is_internationalised_forbidden: bool,
 }
 
 impl ExpectEmail {
@@ -24,6 +33,12 @@ impl ExpectEmail {
         Self {
             expected_domain: None,
             expected_local_part: None,
+            maybe_domains_in: None,
+            maybe_domain_suffix: None,
+            maybe_local_part_regex: None,
+            is_case_insensitive: false,
+            is_plus_addressing_forbidden: false,
+            is_internationalised_forbidden: false,
         }
     }
 
@@ -101,6 +116,135 @@ impl ExpectEmail {
         self.expected_domain = Some(domain.into());
         self
     }
+
+    ///
+    /// Expects the domain part of the email address to be one of those given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": "Joe",
+    ///         "email": expect_json::email().domain_in(["example.com", "example.org"]),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn domain_in<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.maybe_domains_in = Some(domains.into_iter().map(Into::into).collect());
+        self
+    }
+
+    ///
+    /// Expects the domain part of the email address to end with the suffix given.
+    /// i.e. `".example.com"` matches 'joe@tenant.example.com'.
+    ///
+    pub fn domain_ends_with<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.maybe_domain_suffix = Some(suffix.into());
+        self
+    }
+
+    ///
+    /// Expects the local part of the email address to match the regex given.
+    ///
+    pub fn local_part_matches_regex<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let pattern = pattern.into();
+        let regex_pattern = RegexPattern::new(pattern.clone()).unwrap_or_else(|error| {
+            panic!(
+                "email().local_part_matches_regex() expected a valid regex pattern. Received: '{pattern}', {error}"
+            )
+        });
+
+        self.maybe_local_part_regex = Some(regex_pattern);
+        self
+    }
+
+    ///
+    /// Compares the local part and domain ignoring case,
+    /// for [`Self::local_part`], [`Self::domain`], [`Self::domain_in`], and [`Self::domain_ends_with`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": "Joe",
+    ///         // Accepts 'joe@Example.com'
+    ///         "email": expect_json::email().domain("example.com").ignore_case(),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn ignore_case(mut self) -> Self {
+        self.is_case_insensitive = true;
+        self
+    }
+
+    ///
+    /// Expects the local part to not use plus addressing,
+    /// i.e. rejects 'joe+newsletter@example.com'.
+    ///
+    pub fn no_plus_addressing(mut self) -> Self {
+        self.is_plus_addressing_forbidden = true;
+        self
+    }
+
+    ///
+    /// Expects the email address to only use ASCII characters,
+    /// rejecting internationalised (EAI) addresses such as 'jöe@example.com'.
+    ///
+    /// Internationalised addresses are accepted by default.
+    ///
+    pub fn no_internationalised(mut self) -> Self {
+        self.is_internationalised_forbidden = true;
+        self
+    }
+
+    fn normalise_case<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match self.is_case_insensitive {
+            true => Cow::Owned(value.to_lowercase()),
+            false => Cow::Borrowed(value),
+        }
+    }
 }
 
 impl ExpectOp for ExpectEmail {
@@ -109,25 +253,102 @@ impl ExpectOp for ExpectEmail {
             let error_message = format!("Invalid email address, received '{received}'");
             ExpectOpError::custom_error(self, context, error_message, e)
         })?;
+        let local_part = email.local_part();
+        let domain = email.domain();
+
+        if self.is_internationalised_forbidden && !received.is_ascii() {
+            return Err(ExpectOpError::custom(
+                self,
+                context,
+                format!(
+                    "Internationalised address not allowed, received local part '{local_part}' and domain '{domain}', in '{received}'"
+                ),
+            ));
+        }
 
         if let Some(expected_local_part) = &self.expected_local_part {
-            if email.local_part() != expected_local_part {
+            if self.normalise_case(local_part) != self.normalise_case(expected_local_part) {
+                return Err(ExpectOpError::custom(
+                    self,
+                    context,
+                    format!(
+                        "Local part mismatch, expected '{expected_local_part}', received '{local_part}', in '{received}'"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(pattern) = &self.maybe_local_part_regex {
+            let regex = pattern.regex().map_err(|e| {
+                let error_message = format!("invalid regex pattern '{pattern}'");
+                ExpectOpError::custom_error(self, context, error_message, e.clone())
+            })?;
+
+            if !regex.is_match(local_part) {
                 return Err(ExpectOpError::custom(
                     self,
                     context,
                     format!(
-                        "Local part mismatch, expected '{expected_local_part}', received '{received}'"
+                        "Local part mismatch, expected to match regex '{pattern}', received '{local_part}', in '{received}'"
                     ),
                 ));
             }
         }
 
+        if self.is_plus_addressing_forbidden && local_part.contains('+') {
+            return Err(ExpectOpError::custom(
+                self,
+                context,
+                format!(
+                    "Plus addressing not allowed, received local part '{local_part}', in '{received}'"
+                ),
+            ));
+        }
+
         if let Some(expected_domain) = &self.expected_domain {
-            if email.domain() != expected_domain {
+            if self.normalise_case(domain) != self.normalise_case(expected_domain) {
                 return Err(ExpectOpError::custom(
                     self,
                     context,
-                    format!("Domain mismatch, expected '{expected_domain}', received '{received}'"),
+                    format!(
+                        "Domain mismatch, expected '{expected_domain}', received '{domain}', in '{received}'"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(expected_domains) = &self.maybe_domains_in {
+            let received_domain = self.normalise_case(domain);
+            let is_domain_in = expected_domains
+                .iter()
+                .any(|expected_domain| self.normalise_case(expected_domain) == received_domain);
+            if !is_domain_in {
+                let expected_domains_list = expected_domains
+                    .iter()
+                    .map(|expected_domain| format!("'{expected_domain}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(ExpectOpError::custom(
+                    self,
+                    context,
+                    format!(
+                        "Domain mismatch, expected one of [{expected_domains_list}], received '{domain}', in '{received}'"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(expected_suffix) = &self.maybe_domain_suffix {
+            if !self
+                .normalise_case(domain)
+                .ends_with(&*self.normalise_case(expected_suffix))
+            {
+                return Err(ExpectOpError::custom(
+                    self,
+                    context,
+                    format!(
+                        "Domain mismatch, expected ending with '{expected_suffix}', received '{domain}', in '{received}'"
+                    ),
                 ));
             }
         }
@@ -217,7 +438,7 @@ mod test_local_part {
         assert_eq!(
             output,
             r#"Json expect::email() error at root:
-    Local part mismatch, expected '🦊', received 'test@example.com'"#
+    Local part mismatch, expected '🦊', received 'test', in 'test@example.com'"#
         );
     }
 }
@@ -247,7 +468,199 @@ mod test_domain {
         assert_eq!(
             output,
             r#"Json expect::email() error at root:
-    Domain mismatch, expected '🦊.fox', received 'test@example.com'"#
+    Domain mismatch, expected '🦊.fox', received 'example.com', in 'test@example.com'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_domain_in {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_a_domain_in_the_list() {
+        let left = json!("test@example.org");
+        let right = json!(expect::email().domain_in(["example.com", "example.org"]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_a_domain_not_in_the_list() {
+        let left = json!("test@example.net");
+        let right = json!(expect::email().domain_in(["example.com", "example.org"]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Domain mismatch, expected one of ['example.com', 'example.org'], received 'example.net', in 'test@example.net'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_domain_ends_with {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_a_domain_with_the_suffix() {
+        let left = json!("test@tenant.example.com");
+        let right = json!(expect::email().domain_ends_with(".example.com"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_a_domain_without_the_suffix() {
+        let left = json!("test@example.com.evil.com");
+        let right = json!(expect::email().domain_ends_with(".example.com"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Domain mismatch, expected ending with '.example.com', received 'example.com.evil.com', in 'test@example.com.evil.com'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_ignore_case {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_parts_in_a_different_case() {
+        let left = json!("Test@Tenant.Example.COM");
+        let right = json!(
+            expect::email()
+                .local_part("test")
+                .domain_in(["tenant.example.com"])
+                .domain_ends_with(".example.com")
+                .ignore_case()
+        );
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_be_case_sensitive_by_default() {
+        let left = json!("test@Example.com");
+        let right = json!(expect::email().domain("example.com"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Domain mismatch, expected 'example.com', received 'Example.com', in 'test@Example.com'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_no_plus_addressing {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_addresses_without_plus() {
+        let left = json!("test@example.com");
+        let right = json!(expect::email().no_plus_addressing());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_plus_addressing() {
+        let left = json!("test+spam@example.com");
+        let right = json!(expect::email().no_plus_addressing());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Plus addressing not allowed, received local part 'test+spam', in 'test+spam@example.com'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_local_part_matches_regex {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_a_matching_local_part() {
+        let left = json!("joe.bloggs@example.com");
+        let right = json!(expect::email().local_part_matches_regex(r"^[a-z]+\.[a-z]+$"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_regex_is_invalid() {
+        let _ = expect::email().local_part_matches_regex("([a-z]+");
+    }
+
+    #[test]
+    fn it_should_reject_a_local_part_not_matching() {
+        let left = json!("joe@example.com");
+        let right = json!(expect::email().local_part_matches_regex(r"^[a-z]+\.[a-z]+$"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Local part mismatch, expected to match regex '^[a-z]+\.[a-z]+$', received 'joe', in 'joe@example.com'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_no_internationalised {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_internationalised_addresses_by_default() {
+        let left = json!("jöe@exämple.com");
+        let right = json!(expect::email());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_internationalised_addresses_when_set() {
+        let left = json!("jöe@example.com");
+        let right = json!(expect::email().no_internationalised());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::email() error at root:
+    Internationalised address not allowed, received local part 'jöe' and domain 'example.com', in 'jöe@example.com'"#
         );
     }
 }
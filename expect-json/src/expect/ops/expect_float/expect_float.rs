@@ -1,13 +1,17 @@
 use crate::JsonType;
+use crate::expect::ops::ValueOrFieldRef;
 use crate::expect::ops::expect_float::ExpectFloatSubOp;
+use crate::expect::ops::expect_float::FloatTolerance;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
 use core::ops::RangeBounds;
 
 #[expect_op(internal, name = "float")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectFloat {
     sub_ops: Vec<ExpectFloatSubOp>,
 }
@@ -17,26 +21,43 @@ impl ExpectFloat {
         Self { sub_ops: vec![] }
     }
 
-    pub fn greater_than(mut self, expected: f64) -> Self {
-        self.sub_ops
-            .push(ExpectFloatSubOp::GreaterThan { expected });
+    pub fn greater_than<E>(mut self, expected: E) -> Self
+    where
+        E: Into<ValueOrFieldRef<f64>>,
+    {
+        self.sub_ops.push(ExpectFloatSubOp::GreaterThan {
+            expected: expected.into(),
+        });
         self
     }
 
-    pub fn greater_than_equal(mut self, expected: f64) -> Self {
-        self.sub_ops
-            .push(ExpectFloatSubOp::GreaterThanEqual { expected });
+    pub fn greater_than_equal<E>(mut self, expected: E) -> Self
+    where
+        E: Into<ValueOrFieldRef<f64>>,
+    {
+        self.sub_ops.push(ExpectFloatSubOp::GreaterThanEqual {
+            expected: expected.into(),
+        });
         self
     }
 
-    pub fn less_than(mut self, expected: f64) -> Self {
-        self.sub_ops.push(ExpectFloatSubOp::LessThan { expected });
+    pub fn less_than<E>(mut self, expected: E) -> Self
+    where
+        E: Into<ValueOrFieldRef<f64>>,
+    {
+        self.sub_ops.push(ExpectFloatSubOp::LessThan {
+            expected: expected.into(),
+        });
         self
     }
 
-    pub fn less_than_equal(mut self, expected: f64) -> Self {
-        self.sub_ops
-            .push(ExpectFloatSubOp::LessThanEqual { expected });
+    pub fn less_than_equal<E>(mut self, expected: E) -> Self
+    where
+        E: Into<ValueOrFieldRef<f64>>,
+    {
+        self.sub_ops.push(ExpectFloatSubOp::LessThanEqual {
+            expected: expected.into(),
+        });
         self
     }
 
@@ -115,15 +136,89 @@ impl ExpectFloat {
         self.sub_ops.push(ExpectFloatSubOp::Negative);
         self
     }
+
+    /// Expects a float approximately equal to the value given.
+    ///
+    /// By default the floats may be 4 ULPs apart, which allows for rounding errors
+    /// in computed values. Use [`ExpectFloat::abs`], [`ExpectFloat::rel`], or
+    /// [`ExpectFloat::ulps`] after this to change the tolerance.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/basket")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "total": expect_json::float().approx(0.3),
+    ///         "tax": expect_json::float().approx(0.06).abs(0.005),
+    ///         "distance_in_km": expect_json::float().approx(12.5).rel(0.01),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn approx<E>(mut self, expected: E) -> Self
+    where
+        E: Into<ValueOrFieldRef<f64>>,
+    {
+        self.sub_ops.push(ExpectFloatSubOp::Approx {
+            expected: expected.into(),
+            tolerance: FloatTolerance::default(),
+        });
+        self
+    }
+
+    /// Allows the previous [`ExpectFloat::approx`] to differ by at most `epsilon`.
+    pub fn abs(self, epsilon: f64) -> Self {
+        self.map_approx_tolerance("abs", |tolerance| tolerance.abs(epsilon))
+    }
+
+    /// Allows the previous [`ExpectFloat::approx`] to differ by at most `ratio`
+    /// of the larger value. i.e. `0.01` allows a 1% difference.
+    pub fn rel(self, ratio: f64) -> Self {
+        self.map_approx_tolerance("rel", |tolerance| tolerance.rel(ratio))
+    }
+
+    /// Allows the previous [`ExpectFloat::approx`] to be at most `ulps`
+    /// representable floats apart.
+    pub fn ulps(self, ulps: u64) -> Self {
+        self.map_approx_tolerance("ulps", |tolerance| tolerance.ulps(ulps))
+    }
+
+    fn map_approx_tolerance<F>(mut self, method_name: &str, map: F) -> Self
+    where
+        F: FnOnce(FloatTolerance) -> FloatTolerance,
+    {
+        match self.sub_ops.last_mut() {
+            Some(ExpectFloatSubOp::Approx { tolerance, .. }) => {
+                *tolerance = map(*tolerance);
+            }
+            _ => panic!("float().{method_name}() must be called after float().approx()"),
+        }
+
+        self
+    }
 }
 
 impl ExpectOp for ExpectFloat {
     fn on_f64(&self, context: &mut Context, received: f64) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for sub_op in &self.sub_ops {
-            sub_op.on_f64(self, context, received)?;
+            errors.check(sub_op.on_f64(self, context, received))?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 
     fn debug_supported_types(&self) -> &'static [JsonType] {
@@ -681,3 +776,143 @@ mod test_greater_than_equal {
         assert!(output.is_ok(), "{output:#?}");
     }
 }
+
+#[cfg(test)]
+mod test_approx {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_computed_value_with_default_tolerance() {
+        let left = json!(0.1 + 0.2);
+        let right = json!(expect::float().approx(0.3));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_outside_default_tolerance() {
+        let left = json!(0.31);
+        let right = json!(expect::float().approx(0.3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::float() error at root:
+    float is not approximately equal,
+    expected 0.3, within 4 ulps
+    received 0.31
+    difference 0.010000000000000009 (180143985094820 ulps)"#
+        );
+    }
+
+    #[test]
+    fn it_should_pass_within_abs_tolerance() {
+        let left = json!(10.04);
+        let right = json!(expect::float().approx(10.0).abs(0.05));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_outside_abs_tolerance() {
+        let left = json!(10.5);
+        let right = json!(expect::float().approx(10.0).abs(0.25));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::float() error at root:
+    float is not approximately equal,
+    expected 10.0, within abs 0.25
+    received 10.5
+    difference 0.5"#
+        );
+    }
+
+    #[test]
+    fn it_should_pass_within_rel_tolerance() {
+        let left = json!(1010.0);
+        let right = json!(expect::float().approx(1000.0).rel(0.01));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_outside_rel_tolerance() {
+        let left = json!(1100.0);
+        let right = json!(expect::float().approx(1000.0).rel(0.05));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::float() error at root:
+    float is not approximately equal,
+    expected 1000.0, within rel 0.05
+    received 1100.0
+    difference 100.0"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_outside_ulps_tolerance() {
+        let left = json!(1.0000000000000004);
+        let right = json!(expect::float().approx(1.0).ulps(1));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::float() error at root:
+    float is not approximately equal,
+    expected 1.0, within 1 ulps
+    received 1.0000000000000004
+    difference 0.0000000000000004440892098500626 (2 ulps)"#
+        );
+    }
+
+    #[test]
+    fn it_should_pass_when_within_any_tolerance_given() {
+        let left = json!(10.5);
+        let right = json!(expect::float().approx(10.0).abs(0.1).rel(0.1));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_if_tolerance_is_set_without_approx() {
+        let _ = expect::float().abs(0.1);
+    }
+}
+
+#[cfg(test)]
+mod test_field_ref_bounds {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_compare_against_the_field_referenced() {
+        let left = json!({ "min_price": 4.5, "price": 4.0 });
+        let right = json!({
+            "min_price": expect::float(),
+            "price": expect::float().greater_than_equal(expect::field("../min_price")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::float() error at root.price:
+    float is out of bounds,
+    expected greater than equal 4.5 (from root.min_price)
+    received 4.0"#
+        );
+    }
+}
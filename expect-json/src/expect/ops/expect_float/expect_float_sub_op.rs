@@ -1,4 +1,7 @@
+use crate::expect::ops::ValueOrFieldRef;
 use crate::expect::ops::expect_float::ExpectFloat;
+use crate::expect::ops::expect_float::FloatTolerance;
+use crate::expect::ops::expect_float::ulps_difference;
 use crate::expect::ops::utils::SerializableBound;
 use crate::expect::ops::utils::SerializableBoundContains;
 use crate::expect_core::Context;
@@ -7,6 +10,7 @@ use crate::expect_core::ExpectOpResult;
 use crate::internals::objects::FloatObject;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExpectFloatSubOp {
@@ -25,16 +29,21 @@ pub enum ExpectFloatSubOp {
     Negative,
 
     GreaterThan {
-        expected: f64,
+        expected: ValueOrFieldRef<f64>,
     },
     GreaterThanEqual {
-        expected: f64,
+        expected: ValueOrFieldRef<f64>,
     },
     LessThan {
-        expected: f64,
+        expected: ValueOrFieldRef<f64>,
     },
     LessThanEqual {
-        expected: f64,
+        expected: ValueOrFieldRef<f64>,
+    },
+
+    Approx {
+        expected: ValueOrFieldRef<f64>,
+        tolerance: FloatTolerance,
     },
 }
 
@@ -53,9 +62,9 @@ impl ExpectFloatSubOp {
             ));
         }
 
-        match *self {
+        match self {
             Self::InRange { min, max } => {
-                if !SerializableBound::contains(min, max, received) {
+                if !SerializableBound::contains(*min, *max, received) {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -71,7 +80,7 @@ impl ExpectFloatSubOp {
                 }
             }
             Self::OutsideRange { min, max } => {
-                if SerializableBound::contains(min, max, received) {
+                if SerializableBound::contains(*min, *max, received) {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -130,7 +139,8 @@ impl ExpectFloatSubOp {
             }
 
             Self::GreaterThan { expected } => {
-                if received <= expected {
+                let expected = expected.resolve(parent, context, "a number", Value::as_f64)?;
+                if received <= expected.value {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -138,14 +148,15 @@ impl ExpectFloatSubOp {
                             "float is out of bounds,
     expected greater than {expected}
     received {received}",
-                            expected = FloatObject::from(expected),
+                            expected = expected.map(FloatObject::from),
                             received = FloatObject::from(received)
                         ),
                     ));
                 }
             }
             Self::GreaterThanEqual { expected } => {
-                if received < expected {
+                let expected = expected.resolve(parent, context, "a number", Value::as_f64)?;
+                if received < expected.value {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -153,14 +164,15 @@ impl ExpectFloatSubOp {
                             "float is out of bounds,
     expected greater than equal {expected}
     received {received}",
-                            expected = FloatObject::from(expected),
+                            expected = expected.map(FloatObject::from),
                             received = FloatObject::from(received)
                         ),
                     ));
                 }
             }
             Self::LessThan { expected } => {
-                if received >= expected {
+                let expected = expected.resolve(parent, context, "a number", Value::as_f64)?;
+                if received >= expected.value {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -168,14 +180,15 @@ impl ExpectFloatSubOp {
                             "float is out of bounds,
     expected less than {expected}
     received {received}",
-                            expected = FloatObject::from(expected),
+                            expected = expected.map(FloatObject::from),
                             received = FloatObject::from(received)
                         ),
                     ));
                 }
             }
             Self::LessThanEqual { expected } => {
-                if received > expected {
+                let expected = expected.resolve(parent, context, "a number", Value::as_f64)?;
+                if received > expected.value {
                     return Err(ExpectOpError::custom(
                         parent,
                         context,
@@ -183,7 +196,44 @@ impl ExpectFloatSubOp {
                             "float is out of bounds,
     expected less than equal {expected}
     received {received}",
-                            expected = FloatObject::from(expected),
+                            expected = expected.map(FloatObject::from),
+                            received = FloatObject::from(received)
+                        ),
+                    ));
+                }
+            }
+
+            Self::Approx {
+                expected,
+                tolerance,
+            } => {
+                let expected = expected.resolve(parent, context, "a number", Value::as_f64)?;
+                let tolerance = *tolerance;
+
+                // A tolerance set on the op takes precedence over the assertion options.
+                let tolerance = if tolerance == FloatTolerance::default() {
+                    context.options().get_float_tolerance().unwrap_or(tolerance)
+                } else {
+                    tolerance
+                };
+
+                if !tolerance.is_within(expected.value, received) {
+                    let mut difference =
+                        FloatObject::from((expected.value - received).abs()).to_string();
+                    if tolerance.is_ulps_used() {
+                        let ulps = ulps_difference(expected.value, received);
+                        difference = format!("{difference} ({ulps} ulps)");
+                    }
+
+                    return Err(ExpectOpError::custom(
+                        parent,
+                        context,
+                        format!(
+                            "float is not approximately equal,
+    expected {expected}, within {tolerance}
+    received {received}
+    difference {difference}",
+                            expected = expected.map(FloatObject::from),
                             received = FloatObject::from(received)
                         ),
                     ));
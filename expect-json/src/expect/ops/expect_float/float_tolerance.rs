@@ -0,0 +1,131 @@
+use crate::internals::objects::FloatObject;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// The number of ULPs allowed when no tolerance is given.
+const DEFAULT_ULPS: u64 = 4;
+
+///
+/// How far apart two floats may be, and still be treated as equal.
+///
+/// When more than one tolerance is given, the floats are equal if
+/// they are within _any_ of them. When none are given, floats are
+/// allowed to be 4 ULPs apart.
+///
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FloatTolerance {
+    abs: Option<f64>,
+    rel: Option<f64>,
+    ulps: Option<u64>,
+}
+
+impl FloatTolerance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the floats to differ by at most `epsilon`.
+    pub fn abs(mut self, epsilon: f64) -> Self {
+        self.abs = Some(epsilon);
+        self
+    }
+
+    /// Allows the floats to differ by at most `ratio` of the larger of the two.
+    /// i.e. `0.01` allows a 1% difference.
+    pub fn rel(mut self, ratio: f64) -> Self {
+        self.rel = Some(ratio);
+        self
+    }
+
+    /// Allows the floats to be at most `ulps` representable floats apart.
+    pub fn ulps(mut self, ulps: u64) -> Self {
+        self.ulps = Some(ulps);
+        self
+    }
+
+    pub(crate) fn is_within(&self, expected: f64, received: f64) -> bool {
+        if expected == received {
+            return true;
+        }
+
+        let difference = (expected - received).abs();
+        let is_within_abs = self.abs.is_some_and(|epsilon| difference <= epsilon);
+        let is_within_rel = self
+            .rel
+            .is_some_and(|ratio| difference <= ratio * expected.abs().max(received.abs()));
+        let is_within_ulps = ulps_difference(expected, received) <= self.ulps_or_default();
+
+        is_within_abs || is_within_rel || (self.is_ulps_used() && is_within_ulps)
+    }
+
+    pub(crate) fn is_ulps_used(&self) -> bool {
+        self.ulps.is_some() || (self.abs.is_none() && self.rel.is_none())
+    }
+
+    fn ulps_or_default(&self) -> u64 {
+        self.ulps.unwrap_or(DEFAULT_ULPS)
+    }
+}
+
+impl Display for FloatTolerance {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let mut tolerances = vec![];
+        if let Some(epsilon) = self.abs {
+            tolerances.push(format!("abs {}", FloatObject::from(epsilon)));
+        }
+        if let Some(ratio) = self.rel {
+            tolerances.push(format!("rel {}", FloatObject::from(ratio)));
+        }
+        if self.is_ulps_used() {
+            tolerances.push(format!("{} ulps", self.ulps_or_default()));
+        }
+
+        write!(formatter, "{}", tolerances.join(" or "))
+    }
+}
+
+/// Returns how many representable floats lie between the two given.
+pub(crate) fn ulps_difference(a: f64, b: f64) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+
+    let a = i128::from(to_ordered_bits(a));
+    let b = i128::from(to_ordered_bits(b));
+    u64::try_from((a - b).unsigned_abs()).unwrap_or(u64::MAX)
+}
+
+/// Maps the float onto an integer, where the order of the integers matches the floats.
+fn to_ordered_bits(n: f64) -> i64 {
+    let bits = n.to_bits() as i64;
+    if bits < 0 { i64::MIN - bits } else { bits }
+}
+
+#[cfg(test)]
+mod test_ulps_difference {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_be_zero_for_positive_and_negative_zero() {
+        assert_eq!(ulps_difference(0.0, -0.0), 0);
+    }
+
+    #[test]
+    fn it_should_be_one_for_adjacent_floats() {
+        assert_eq!(
+            ulps_difference(1.0, f64::from_bits(1.0_f64.to_bits() + 1)),
+            1
+        );
+    }
+
+    #[test]
+    fn it_should_count_across_zero() {
+        let smallest = f64::from_bits(1);
+        assert_eq!(ulps_difference(smallest, -smallest), 2);
+    }
+}
@@ -3,3 +3,6 @@ pub use expect_float::*;
 
 mod expect_float_sub_op;
 use expect_float_sub_op::*;
+
+mod float_tolerance;
+pub use float_tolerance::*;
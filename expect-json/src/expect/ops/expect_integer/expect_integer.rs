@@ -1,14 +1,17 @@
 use crate::JsonInteger;
 use crate::JsonType;
+use crate::expect::ops::ValueOrFieldRef;
 use crate::expect::ops::expect_integer::ExpectIntegerSubOp;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
 use core::ops::RangeBounds;
 
 #[expect_op(internal, name = "integer")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectInteger {
     sub_ops: Vec<ExpectIntegerSubOp>,
 }
@@ -18,9 +21,19 @@ impl ExpectInteger {
         Self { sub_ops: vec![] }
     }
 
+    pub fn equal_to<N>(mut self, expected: N) -> Self
+    where
+        N: Into<ValueOrFieldRef<JsonInteger>>,
+    {
+        self.sub_ops.push(ExpectIntegerSubOp::EqualTo {
+            expected: expected.into(),
+        });
+        self
+    }
+
     pub fn greater_than<N>(mut self, expected: N) -> Self
     where
-        N: Into<JsonInteger>,
+        N: Into<ValueOrFieldRef<JsonInteger>>,
     {
         self.sub_ops.push(ExpectIntegerSubOp::GreaterThan {
             expected: expected.into(),
@@ -30,7 +43,7 @@ impl ExpectInteger {
 
     pub fn greater_than_equal<N>(mut self, expected: N) -> Self
     where
-        N: Into<JsonInteger>,
+        N: Into<ValueOrFieldRef<JsonInteger>>,
     {
         self.sub_ops.push(ExpectIntegerSubOp::GreaterThanEqual {
             expected: expected.into(),
@@ -40,7 +53,7 @@ impl ExpectInteger {
 
     pub fn less_than<N>(mut self, expected: N) -> Self
     where
-        N: Into<JsonInteger>,
+        N: Into<ValueOrFieldRef<JsonInteger>>,
     {
         self.sub_ops.push(ExpectIntegerSubOp::LessThan {
             expected: expected.into(),
@@ -50,7 +63,7 @@ impl ExpectInteger {
 
     pub fn less_than_equal<N>(mut self, expected: N) -> Self
     where
-        N: Into<JsonInteger>,
+        N: Into<ValueOrFieldRef<JsonInteger>>,
     {
         self.sub_ops.push(ExpectIntegerSubOp::LessThanEqual {
             expected: expected.into(),
@@ -138,19 +151,21 @@ impl ExpectInteger {
 
 impl ExpectOp for ExpectInteger {
     fn on_i64(&self, context: &mut Context, received: i64) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for sub_op in &self.sub_ops {
-            sub_op.on_i64(self, context, received)?;
+            errors.check(sub_op.on_i64(self, context, received))?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 
     fn on_u64(&self, context: &mut Context, received: u64) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for sub_op in &self.sub_ops {
-            sub_op.on_u64(self, context, received)?;
+            errors.check(sub_op.on_u64(self, context, received))?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 
     fn debug_supported_types(&self) -> &'static [JsonType] {
@@ -1,5 +1,6 @@
 use crate::JsonInteger;
 use crate::expect::ops::ExpectInteger;
+use crate::expect::ops::ValueOrFieldRef;
 use crate::expect::ops::utils::SerializableBound;
 use crate::expect::ops::utils::SerializableBoundContains;
 use crate::expect_core::Context;
@@ -27,17 +28,20 @@ pub enum ExpectIntegerSubOp {
     Positive,
     Negative,
 
+    EqualTo {
+        expected: ValueOrFieldRef<JsonInteger>,
+    },
     GreaterThan {
-        expected: JsonInteger,
+        expected: ValueOrFieldRef<JsonInteger>,
     },
     GreaterThanEqual {
-        expected: JsonInteger,
+        expected: ValueOrFieldRef<JsonInteger>,
     },
     LessThan {
-        expected: JsonInteger,
+        expected: ValueOrFieldRef<JsonInteger>,
     },
     LessThanEqual {
-        expected: JsonInteger,
+        expected: ValueOrFieldRef<JsonInteger>,
     },
 }
 
@@ -48,10 +52,10 @@ impl ExpectIntegerSubOp {
         context: &mut Context<'_>,
         received: i64,
     ) -> ExpectOpResult<()> {
-        match *self {
-            Self::InRange { min, max } => on_i64_in_range(parent, context, received, min, max),
+        match self {
+            Self::InRange { min, max } => on_i64_in_range(parent, context, received, *min, *max),
             Self::OutsideRange { min, max } => {
-                on_i64_outside_range(parent, context, received, min, max)
+                on_i64_outside_range(parent, context, received, *min, *max)
             }
 
             Self::Zero => on_zero(context, received),
@@ -60,6 +64,14 @@ impl ExpectIntegerSubOp {
             Self::Positive => on_positive(parent, context, received),
             Self::Negative => on_negative(parent, context, received),
 
+            Self::EqualTo { expected: num } => on_comparison(
+                parent,
+                context,
+                received.into(),
+                num,
+                JsonInteger::eq_value,
+                "equal to",
+            ),
             Self::GreaterThan { expected: num } => on_comparison(
                 parent,
                 context,
@@ -101,10 +113,10 @@ impl ExpectIntegerSubOp {
         context: &mut Context<'_>,
         received: u64,
     ) -> ExpectOpResult<()> {
-        match *self {
-            Self::InRange { min, max } => on_u64_in_range(parent, context, received, min, max),
+        match self {
+            Self::InRange { min, max } => on_u64_in_range(parent, context, received, *min, *max),
             Self::OutsideRange { min, max } => {
-                on_u64_outside_range(parent, context, received, min, max)
+                on_u64_outside_range(parent, context, received, *min, *max)
             }
 
             Self::Zero => on_zero(context, received),
@@ -113,6 +125,14 @@ impl ExpectIntegerSubOp {
             Self::Positive => on_positive(parent, context, received),
             Self::Negative => on_negative(parent, context, received),
 
+            Self::EqualTo { expected: num } => on_comparison(
+                parent,
+                context,
+                received.into(),
+                num,
+                JsonInteger::eq_value,
+                "equal to",
+            ),
             Self::GreaterThan { expected: num } => on_comparison(
                 parent,
                 context,
@@ -321,14 +341,15 @@ fn on_comparison<F>(
     parent: &ExpectInteger,
     context: &mut Context<'_>,
     received: JsonInteger,
-    expected: JsonInteger,
+    expected: &ValueOrFieldRef<JsonInteger>,
     comparison: F,
     comparison_name: &'static str,
 ) -> ExpectOpResult<()>
 where
     F: Fn(JsonInteger, JsonInteger) -> bool,
 {
-    if !comparison(received, expected) {
+    let expected = expected.resolve(parent, context, "an integer", JsonInteger::from_json_value)?;
+    if !comparison(received, expected.value) {
         return Err(ExpectOpError::custom(
             parent,
             context,
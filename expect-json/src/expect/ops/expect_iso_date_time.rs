@@ -1,15 +1,26 @@
 use crate::JsonType;
-use crate::expect::ops::utils::DurationFormatter;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::into_temporal_bound;
+use crate::expect::ops::utils::into_temporal_range;
+use crate::expect::ops::utils::parse_date_time_bound;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
 use chrono::DateTime;
-use chrono::Duration as ChronoDuration;
 use chrono::FixedOffset;
 use chrono::Offset;
-use chrono::Utc;
+#[cfg(feature = "timezone")]
+use chrono_tz::Tz;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use std::time::Duration as StdDuration;
 
 ///
@@ -45,16 +56,74 @@ use std::time::Duration as StdDuration;
 ///
 #[expect_op(internal, name = "iso_date_time")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectIsoDateTime {
     is_utc_only: bool,
+    is_z_suffix_required: bool,
+    maybe_offset: Option<i32>,
+    #[cfg(feature = "timezone")]
+    maybe_timezone: Option<Tz>,
+    maybe_precision: Option<IsoPrecision>,
+    checks: TemporalChecks,
+
+    /// Where `within_past` and `within_future` were held, before they moved into the checks.
+    /// These are only read, so ops serialised before then keep their checks.
+    #[serde(skip_serializing)]
     maybe_past_duration: Option<StdDuration>,
+    #[serde(skip_serializing)]
     maybe_future_duration: Option<StdDuration>,
 }
 
+///
+/// The number of fractional second digits expected,
+/// used with [`ExpectIsoDateTime::precision`].
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IsoPrecision {
+    /// No fractional digits, i.e. `2025-01-01T12:00:00Z`.
+    Seconds,
+    /// Three fractional digits, i.e. `2025-01-01T12:00:00.123Z`.
+    Millis,
+    /// Six fractional digits, i.e. `2025-01-01T12:00:00.123456Z`.
+    Micros,
+    /// Nine fractional digits, i.e. `2025-01-01T12:00:00.123456789Z`.
+    Nanos,
+}
+
+impl IsoPrecision {
+    fn digits(self) -> usize {
+        match self {
+            Self::Seconds => 0,
+            Self::Millis => 3,
+            Self::Micros => 6,
+            Self::Nanos => 9,
+        }
+    }
+}
+
+impl Display for IsoPrecision {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Seconds => "seconds",
+            Self::Millis => "milliseconds",
+            Self::Micros => "microseconds",
+            Self::Nanos => "nanoseconds",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
+
 impl ExpectIsoDateTime {
     pub(crate) fn new() -> Self {
         Self {
             is_utc_only: false,
+            is_z_suffix_required: false,
+            maybe_offset: None,
+            #[cfg(feature = "timezone")]
+            maybe_timezone: None,
+            maybe_precision: None,
+            checks: TemporalChecks::default(),
             maybe_past_duration: None,
             maybe_future_duration: None,
         }
@@ -101,6 +170,175 @@ impl ExpectIsoDateTime {
         }
     }
 
+    ///
+    /// Expects the date time to be in UTC, and written with a `Z` suffix.
+    ///
+    /// This rejects UTC written as `+00:00`, which [`Self::utc`] allows.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time().requires_z_suffix(),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn requires_z_suffix(self) -> Self {
+        Self {
+            is_z_suffix_required: true,
+            ..self
+        }
+    }
+
+    ///
+    /// Expects the date time to be written with the offset given, such as `"+02:00"`.
+    ///
+    /// `"Z"` is accepted as the same as `"+00:00"`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time().offset("+02:00"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn offset(self, offset: &str) -> Self {
+        let parsed = DateTime::<FixedOffset>::parse_from_rfc3339(&format!(
+            "2000-01-01T00:00:00{offset}"
+        ))
+        .unwrap_or_else(|_| {
+            panic!(
+                "iso_date_time().offset() expected an offset such as '+02:00'. Received: '{offset}'"
+            )
+        });
+
+        Self {
+            maybe_offset: Some(parsed.offset().local_minus_utc()),
+            ..self
+        }
+    }
+
+    ///
+    /// Expects the offset of the date time to be the one used by the IANA timezone given,
+    /// such as `"Europe/London"`, at the instant received.
+    ///
+    /// For example in `"Europe/London"` a summer date time must use `+01:00`,
+    /// and a winter date time must use `+00:00`.
+    ///
+    /// Timezones come from the IANA database built into `chrono-tz`,
+    /// so they do not depend on the timezones installed on the machine.
+    /// This panics if the timezone cannot be found.
+    ///
+    /// This requires the `timezone` feature.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time().timezone("Europe/London"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    #[cfg(feature = "timezone")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "timezone")))]
+    pub fn timezone(self, timezone: &str) -> Self {
+        let Ok(timezone) = timezone.parse::<Tz>() else {
+            panic!(
+                "iso_date_time().timezone() expected a known IANA timezone. Received: '{timezone}'"
+            );
+        };
+
+        Self {
+            maybe_timezone: Some(timezone),
+            ..self
+        }
+    }
+
+    ///
+    /// Expects the date time to be written with exactly the number of fractional second digits given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    /// use axum_test::expect_json::ops::IsoPrecision;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         // i.e. "2025-01-01T12:00:00.123Z"
+    ///         "created_at": expect_json::iso_date_time().precision(IsoPrecision::Millis),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn precision(self, precision: IsoPrecision) -> Self {
+        Self {
+            maybe_precision: Some(precision),
+            ..self
+        }
+    }
+
     ///
     /// Expects the date time to be within a past duration,
     /// up to the current time.
@@ -138,11 +376,9 @@ impl ExpectIsoDateTime {
     /// # Ok(()) }
     /// ```
     ///
-    pub fn within_past(self, duration: StdDuration) -> Self {
-        Self {
-            maybe_past_duration: Some(duration),
-            ..self
-        }
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks.within_past(duration);
+        self
     }
 
     ///
@@ -181,11 +417,201 @@ impl ExpectIsoDateTime {
     /// # Ok(()) }
     /// ```
     ///
-    pub fn within_future(self, duration: StdDuration) -> Self {
-        Self {
-            maybe_future_duration: Some(duration),
-            ..self
-        }
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks.within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the date time to be after the bound given.
+    ///
+    /// The bound can be an RFC 3339 string, a date such as `"2025-01-01"`
+    /// (taken as midnight UTC), a chrono `DateTime`,
+    /// or a reference to another field using [`crate::expect::field`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time().greater_than("2025-01-01"),
+    ///         "updated_at": expect_json::iso_date_time()
+    ///             .greater_than(expect_json::field("../created_at")),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn greater_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than", bound);
+        self.checks.push(TemporalComparison::GreaterThan(bound));
+        self
+    }
+
+    /// Expects the date time to be at or after the bound given.
+    pub fn greater_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than_equal", bound);
+        self.checks
+            .push(TemporalComparison::GreaterThanEqual(bound));
+        self
+    }
+
+    /// Expects the date time to be before the bound given.
+    pub fn less_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than", bound);
+        self.checks.push(TemporalComparison::LessThan(bound));
+        self
+    }
+
+    /// Expects the date time to be at or before the bound given.
+    pub fn less_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than_equal", bound);
+        self.checks.push(TemporalComparison::LessThanEqual(bound));
+        self
+    }
+
+    ///
+    /// Expects the date time to be between the two bounds given, inclusive.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time()
+    ///             .between("2025-01-01", "2025-12-31T23:59:59Z"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn between<B1, B2>(mut self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<String>>,
+        B2: Into<ValueOrFieldRef<String>>,
+    {
+        let (min, max) = into_temporal_range(&self, "between", min, max);
+        self.checks.push(TemporalComparison::Between { min, max });
+        self
+    }
+
+    ///
+    /// Expects the date time to be the same instant as the one given,
+    /// regardless of the offset either is written with.
+    ///
+    /// For example `"2025-01-01T13:00:00+01:00"` is the same instant as `"2025-01-01T12:00:00Z"`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time().eq_instant("2025-01-01T12:00:00Z"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn eq_instant<B>(mut self, instant: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let instant = into_temporal_bound(&self, "eq_instant", instant);
+        self.checks.push(TemporalComparison::EqInstant(instant));
+        self
+    }
+
+    ///
+    /// Expects the date time to be within the tolerance given of an instant,
+    /// either before or after it.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use std::time::Duration;
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-comment")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::iso_date_time()
+    ///             .around("2025-01-01T12:00:00Z", Duration::from_secs(60)),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn around<B>(mut self, instant: B, tolerance: StdDuration) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let expected = into_temporal_bound(&self, "around", instant);
+        self.checks.push(TemporalComparison::Around {
+            expected,
+            tolerance,
+        });
+        self
     }
 }
 
@@ -196,157 +622,378 @@ impl ExpectOp for ExpectIsoDateTime {
             ExpectOpError::custom_error(self, context, error_message, error)
         })?;
 
-        if self.is_utc_only {
-            let is_date_time_utc = date_time.offset().fix().utc_minus_local() != 0;
-            if is_date_time_utc {
-                let error_message = format!(
-                    "ISO datetime '{received}' is using a non-UTC timezone, expected UTC only"
-                );
-                return Err(ExpectOpError::custom(self, context, error_message));
-            }
-        }
+        if self.is_utc_only {
+            let is_date_time_utc = date_time.offset().fix().utc_minus_local() != 0;
+            if is_date_time_utc {
+                let error_message = format!(
+                    "ISO datetime '{received}' is using a non-UTC timezone, expected UTC only"
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        if self.is_z_suffix_required && !received.ends_with('Z') {
+            let error_message = format!(
+                "ISO datetime '{received}' is not using a 'Z' suffix, expected UTC written as 'Z'"
+            );
+            return Err(ExpectOpError::custom(self, context, error_message));
+        }
+
+        if let Some(expected_offset) = self.maybe_offset {
+            let received_offset = date_time.offset().local_minus_utc();
+            if received_offset != expected_offset {
+                let error_message = format!(
+                    "ISO datetime '{received}' is using offset '{}', expected '{}'",
+                    date_time.offset(),
+                    format_offset(expected_offset),
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        #[cfg(feature = "timezone")]
+        if let Some(timezone) = self.maybe_timezone {
+            let received_offset = date_time.offset().local_minus_utc();
+            let expected_offset = date_time
+                .with_timezone(&timezone)
+                .offset()
+                .fix()
+                .local_minus_utc();
+            if received_offset != expected_offset {
+                let error_message = format!(
+                    "ISO datetime '{received}' is using offset '{}', which is not valid for timezone '{timezone}' at that time, expected '{}'",
+                    date_time.offset(),
+                    format_offset(expected_offset),
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        if let Some(precision) = self.maybe_precision {
+            let received_digits = count_fractional_digits(received);
+            if received_digits != precision.digits() {
+                let error_message = format!(
+                    "ISO datetime '{received}' has {received_digits} fractional second digits, expected exactly {} ({precision})",
+                    precision.digits(),
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        self.checks
+            .with_fallback_window(self.maybe_past_duration, self.maybe_future_duration)
+            .check(self, context, received, date_time)
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::String]
+    }
+}
+
+fn format_offset(offset_seconds: i32) -> String {
+    FixedOffset::east_opt(offset_seconds)
+        .map(|offset| offset.to_string())
+        .unwrap_or_else(|| format!("{offset_seconds} seconds"))
+}
+
+/// Counts the digits after the seconds, where an RFC 3339 string always starts `YYYY-MM-DDTHH:MM:SS`.
+fn count_fractional_digits(received: &str) -> usize {
+    received
+        .get(19..)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .map(|fraction| fraction.bytes().take_while(u8::is_ascii_digit).count())
+        .unwrap_or_default()
+}
+
+impl ExpectTemporal for ExpectIsoDateTime {
+    type Value = DateTime<FixedOffset>;
+
+    const FUNCTION_NAME: &'static str = "iso_date_time";
+    const LABEL: &'static str = "ISO datetime";
+    const TYPE_NAME: &'static str = "date time";
+
+    fn bound_description(&self) -> String {
+        "an iso date time".to_string()
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        parse_date_time_bound(raw)
+    }
+}
+
+#[cfg(test)]
+mod test_iso_date_time {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_parse_iso_datetime_with_utc_timezone() {
+        let left = json!("2024-01-15T13:45:30Z");
+        let right = json!(expect::iso_date_time());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_parse_iso_datetime_with_non_utc_timezone_by_default() {
+        let left = json!("2024-01-15T13:45:30+01:00");
+        let right = json!(expect::iso_date_time());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_to_parse_iso_datetime_without_timezone() {
+        let left = json!("2024-01-15T13:45:30");
+        let right = json!(expect::iso_date_time());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    failed to parse string '2024-01-15T13:45:30' as iso date time,
+    premature end of input"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_utc {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_parse_iso_datetime_with_utc_timezone_when_set() {
+        let left = json!("2024-01-15T13:45:30Z");
+        let right = json!(expect::iso_date_time().utc());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_not_parse_iso_datetime_with_non_utc_timezone_when_set() {
+        let left = json!("2024-01-15T13:45:30+01:00");
+        let right = json!(expect::iso_date_time().utc());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2024-01-15T13:45:30+01:00' is using a non-UTC timezone, expected UTC only"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_requires_z_suffix {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_with_a_z_suffix() {
+        let left = json!("2024-01-15T13:45:30Z");
+        let right = json!(expect::iso_date_time().requires_z_suffix());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_with_a_zero_offset() {
+        let left = json!("2024-01-15T13:45:30+00:00");
+        let right = json!(expect::iso_date_time().requires_z_suffix());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2024-01-15T13:45:30+00:00' is not using a 'Z' suffix, expected UTC written as 'Z'"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_offset {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_with_the_same_offset() {
+        let left = json!("2024-01-15T13:45:30+02:00");
+        let right = json!(expect::iso_date_time().offset("+02:00"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_treat_z_as_a_zero_offset() {
+        let left = json!("2024-01-15T13:45:30+00:00");
+        let right = json!(expect::iso_date_time().offset("Z"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
 
-        match (self.maybe_past_duration, self.maybe_future_duration) {
-            (None, None) => {}
-            (Some(past_duration), None) => {
-                let is_date_time_outside_past = date_time < Utc::now() - past_duration;
-                if is_date_time_outside_past {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(past_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is too far from the past, expected between '{duration}' ago and now"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-
-                let is_date_time_ahead_of_now = date_time > Utc::now();
-                if is_date_time_ahead_of_now {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(past_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is in the future of now, expected between '{duration}' ago and now"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-            }
-            (None, Some(future_duration)) => {
-                let is_date_time_outside_future = date_time > Utc::now() + future_duration;
-                if is_date_time_outside_future {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(future_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is too far in the future, expected between now and '{duration}' in the future"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-
-                let is_date_time_behind_of_now = date_time < Utc::now();
-                if is_date_time_behind_of_now {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(future_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is in the past of now, expected between now and '{duration}' in the future"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-            }
-            (Some(past_duration), Some(future_duration)) => {
-                let is_date_time_outside_past = date_time < Utc::now() - past_duration;
-                if is_date_time_outside_past {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(past_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is too far from the past, expected between '{duration}' ago and now"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-
-                let is_date_time_outside_future = date_time > Utc::now() + future_duration;
-                if is_date_time_outside_future {
-                    let duration =
-                        DurationFormatter::new(ChronoDuration::from_std(future_duration).unwrap());
-                    let error_message = format!(
-                        "ISO datetime '{received}' is too far in the future, expected between now and '{duration}' in the future"
-                    );
-                    return Err(ExpectOpError::custom(self, context, error_message));
-                }
-            }
-        }
+    #[test]
+    fn it_should_fail_with_a_different_offset() {
+        let left = json!("2024-01-15T13:45:30+01:00");
+        let right = json!(expect::iso_date_time().offset("+02:00"));
 
-        Ok(())
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2024-01-15T13:45:30+01:00' is using offset '+01:00', expected '+02:00'"
+        );
     }
 
-    fn debug_supported_types(&self) -> &'static [JsonType] {
-        &[JsonType::String]
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_invalid_offsets() {
+        let _ = expect::iso_date_time().offset("2 hours");
     }
 }
 
-#[cfg(test)]
-mod test_iso_date_time {
+#[cfg(all(test, feature = "timezone"))]
+mod test_timezone {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_parse_iso_datetime_with_utc_timezone() {
-        let left = json!("2024-01-15T13:45:30Z");
-        let right = json!(expect::iso_date_time());
+    fn it_should_pass_with_the_summer_offset_in_summer() {
+        let left = json!("2025-07-01T12:00:00+01:00");
+        let right = json!(expect::iso_date_time().timezone("Europe/London"));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_parse_iso_datetime_with_non_utc_timezone_by_default() {
-        let left = json!("2024-01-15T13:45:30+01:00");
-        let right = json!(expect::iso_date_time());
+    fn it_should_pass_with_the_winter_offset_in_winter() {
+        let left = json!("2025-01-01T12:00:00Z");
+        let right = json!(expect::iso_date_time().timezone("Europe/London"));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_to_parse_iso_datetime_without_timezone() {
-        let left = json!("2024-01-15T13:45:30");
-        let right = json!(expect::iso_date_time());
+    fn it_should_use_historic_offsets() {
+        // The UK stayed on British Summer Time all year between 1968 and 1971.
+        let left = json!("1970-01-01T12:00:00+01:00");
+        let right = json!(expect::iso_date_time().timezone("Europe/London"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_use_daylight_time_spanning_the_new_year() {
+        let left = json!("2025-01-01T12:00:00+11:00");
+        let right = json!(expect::iso_date_time().timezone("Australia/Sydney"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_with_the_winter_offset_in_summer() {
+        let left = json!("2025-07-01T12:00:00Z");
+        let right = json!(expect::iso_date_time().timezone("Europe/London"));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
-            r#"Json expect::iso_date_time() error at root:
-    failed to parse string '2024-01-15T13:45:30' as iso date time,
-    premature end of input"#
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2025-07-01T12:00:00Z' is using offset '+00:00', which is not valid for timezone 'Europe/London' at that time, expected '+01:00'"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_with_an_offset_from_another_zone() {
+        let left = json!("2025-01-01T12:00:00-05:00");
+        let right = json!(expect::iso_date_time().timezone("Europe/London"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2025-01-01T12:00:00-05:00' is using offset '-05:00', which is not valid for timezone 'Europe/London' at that time, expected '+00:00'"
         );
     }
+
+    #[test]
+    #[should_panic(
+        expected = "iso_date_time().timezone() expected a known IANA timezone. Received: 'Europe/Atlantis'"
+    )]
+    fn it_should_panic_for_unknown_timezones() {
+        let _ = expect::iso_date_time().timezone("Europe/Atlantis");
+    }
 }
 
 #[cfg(test)]
-mod test_utc {
+mod test_precision {
     use crate::expect;
+    use crate::expect::ops::IsoPrecision;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_parse_iso_datetime_with_utc_timezone_when_set() {
-        let left = json!("2024-01-15T13:45:30Z");
-        let right = json!(expect::iso_date_time().utc());
+    fn it_should_pass_with_exactly_the_digits_expected() {
+        let left = json!("2024-01-15T13:45:30.123Z");
+        let right = json!(expect::iso_date_time().precision(IsoPrecision::Millis));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_not_parse_iso_datetime_with_non_utc_timezone_when_set() {
+    fn it_should_pass_seconds_precision_without_a_fraction() {
         let left = json!("2024-01-15T13:45:30+01:00");
-        let right = json!(expect::iso_date_time().utc());
+        let right = json!(expect::iso_date_time().precision(IsoPrecision::Seconds));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_with_fewer_digits() {
+        let left = json!("2024-01-15T13:45:30.1Z");
+        let right = json!(expect::iso_date_time().precision(IsoPrecision::Millis));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             "Json expect::iso_date_time() error at root:
-    ISO datetime '2024-01-15T13:45:30+01:00' is using a non-UTC timezone, expected UTC only"
+    ISO datetime '2024-01-15T13:45:30.1Z' has 1 fractional second digits, expected exactly 3 (milliseconds)"
+        );
+    }
+
+    #[test]
+    fn it_should_fail_with_more_digits() {
+        let left = json!("2024-01-15T13:45:30.123456Z");
+        let right = json!(expect::iso_date_time().precision(IsoPrecision::Millis));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            "Json expect::iso_date_time() error at root:
+    ISO datetime '2024-01-15T13:45:30.123456Z' has 6 fractional second digits, expected exactly 3 (milliseconds)"
         );
     }
 }
@@ -356,6 +1003,8 @@ mod test_within_past {
     use super::*;
     use crate::expect;
     use crate::expect_json_eq;
+    use chrono::Duration as ChronoDuration;
+    use chrono::Utc;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
@@ -403,6 +1052,30 @@ mod test_within_past {
             )
         );
     }
+
+    #[test]
+    fn it_should_keep_within_past_from_ops_serialised_before_the_shared_checks() {
+        let now_str = (Utc::now() - ChronoDuration::seconds(90)).to_rfc3339();
+        let left = json!(now_str);
+        let right = json!({
+            "magic_id": "__ExpectJson_MarkerId_0ABDBD14_93D1_4D73_8E26_0177D8A280A4__",
+            "inner": {
+                "type": "ExpectIsoDateTime",
+                "is_utc_only": false,
+                "maybe_past_duration": { "secs": 60, "nanos": 0 },
+                "maybe_future_duration": null,
+            },
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            format!(
+                r#"Json expect::iso_date_time() error at root:
+    ISO datetime '{now_str}' is too far from the past, expected between '1 minute' ago and now"#
+            )
+        );
+    }
 }
 
 #[cfg(test)]
@@ -410,6 +1083,8 @@ mod test_within_future {
     use super::*;
     use crate::expect;
     use crate::expect_json_eq;
+    use chrono::Duration as ChronoDuration;
+    use chrono::Utc;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
@@ -515,3 +1190,275 @@ mod test_within_future {
         );
     }
 }
+
+#[cfg(test)]
+mod test_comparisons {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_after_the_field_referenced() {
+        let left = json!({
+            "created_at": "2024-01-15T13:45:30Z",
+            "updated_at": "2024-01-15T14:45:30+01:00",
+        });
+        let right = json!({
+            "created_at": expect::iso_date_time(),
+            "updated_at": expect::iso_date_time().greater_than_equal(expect::field("../created_at")),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_before_the_field_referenced() {
+        let left = json!({
+            "created_at": "2024-01-15T13:45:30Z",
+            "updated_at": "2024-01-15T13:00:00Z",
+        });
+        let right = json!({
+            "created_at": expect::iso_date_time(),
+            "updated_at": expect::iso_date_time().greater_than(expect::field("/created_at")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root.updated_at:
+    date time is out of bounds,
+    expected greater than 2024-01-15T13:45:30Z (from root.created_at)
+    received 2024-01-15T13:00:00Z
+    difference 45 minutes, 30 seconds"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_field_referenced_is_not_a_date_time() {
+        let left = json!({
+            "created_at": "yesterday",
+            "updated_at": "2024-01-15T13:00:00Z",
+        });
+        let right = json!({
+            "created_at": expect::string(),
+            "updated_at": expect::iso_date_time().less_than(expect::field("../created_at")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root.updated_at:
+    field '../created_at' at root.created_at is not an iso date time,
+    received string "yesterday""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_greater_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_after_a_date_bound() {
+        let left = json!("2025-03-01T10:00:00Z");
+        let right = json!(expect::iso_date_time().greater_than("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_after_a_chrono_bound() {
+        let bound = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let left = json!("2025-03-01T10:00:00+05:00");
+        let right = json!(expect::iso_date_time().greater_than(bound));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_before_the_bound() {
+        let left = json!("2024-12-31T22:30:00Z");
+        let right = json!(expect::iso_date_time().greater_than("2025-01-01T00:00:00+01:00"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is out of bounds,
+    expected greater than 2025-01-01T00:00:00+01:00
+    received 2024-12-31T22:30:00Z
+    difference 30 minutes"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_the_same_instant() {
+        let left = json!("2025-01-01T00:00:00Z");
+        let right = json!(expect::iso_date_time().greater_than("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is out of bounds,
+    expected greater than 2025-01-01
+    received 2025-01-01T00:00:00Z"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_invalid_bounds() {
+        let _ = expect::iso_date_time().greater_than("next tuesday");
+    }
+}
+
+#[cfg(test)]
+mod test_less_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_before_the_bound() {
+        let left = json!("2024-12-31T23:59:59Z");
+        let right = json!(expect::iso_date_time().less_than("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_after_the_bound() {
+        let left = json!("2025-01-03T02:00:00Z");
+        let right = json!(expect::iso_date_time().less_than_equal("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is out of bounds,
+    expected less than equal 2025-01-01
+    received 2025-01-03T02:00:00Z
+    difference 2 days, 2 hours"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_between {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_within_range_inclusive() {
+        let right = json!(expect::iso_date_time().between("2025-01-01", "2025-02-01"));
+
+        for received in [
+            "2025-01-01T00:00:00Z",
+            "2025-01-15T12:00:00+02:00",
+            "2025-02-01T00:00:00Z",
+        ] {
+            let output = expect_json_eq(&json!(received), &right);
+            assert!(output.is_ok(), "assertion error: {output:#?}");
+        }
+    }
+
+    #[test]
+    fn it_should_fail_when_outside_range() {
+        let left = json!("2025-02-01T00:00:01Z");
+        let right = json!(expect::iso_date_time().between("2025-01-01", "2025-02-01"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is not in range,
+    expected 2025-01-01 to 2025-02-01
+    received 2025-02-01T00:00:01Z
+    difference 1 second"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_eq_instant {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_the_same_instant_with_a_different_offset() {
+        let left = json!("2025-01-01T13:00:00+01:00");
+        let right = json!(expect::iso_date_time().eq_instant("2025-01-01T12:00:00Z"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_for_a_different_instant() {
+        let left = json!("2025-01-01T12:00:00.500Z");
+        let right = json!(expect::iso_date_time().eq_instant("2025-01-01T12:00:00Z"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is not the same instant,
+    expected 2025-01-01T12:00:00Z
+    received 2025-01-01T12:00:00.500Z
+    difference 500 milliseconds"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_around {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_pass_when_within_tolerance() {
+        let right =
+            json!(expect::iso_date_time().around("2025-01-01T12:00:00Z", Duration::from_secs(60)));
+
+        for received in ["2025-01-01T11:59:00Z", "2025-01-01T12:01:00Z"] {
+            let output = expect_json_eq(&json!(received), &right);
+            assert!(output.is_ok(), "assertion error: {output:#?}");
+        }
+    }
+
+    #[test]
+    fn it_should_fail_when_outside_tolerance() {
+        let left = json!("2025-01-01T11:55:30Z");
+        let right =
+            json!(expect::iso_date_time().around("2025-01-01T12:00:00Z", Duration::from_secs(60)));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::iso_date_time() error at root:
+    date time is not around the instant expected,
+    expected 2025-01-01T12:00:00Z, within 1 minute
+    received 2025-01-01T11:55:30Z
+    difference 4 minutes, 30 seconds"#
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use serde_json::Value;
+
+///
+/// Expects the value does not match the value given.
+/// This can be an exact value, or another expectation.
+///
+/// You can build these using the [`crate::expect::not`] function.
+///
+#[expect_op(internal, name = "not")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectNot {
+    expected: Value,
+}
+
+impl ExpectNot {
+    pub(crate) fn new(expected: Value) -> Self {
+        Self { expected }
+    }
+}
+
+impl ExpectOp for ExpectNot {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        // Nothing captured within a `not` is kept, as it only passes when the inside fails.
+        let checkpoint = context.captures_checkpoint();
+        let is_matched = context.json_eq(received, &self.expected).is_ok();
+        checkpoint.restore();
+
+        if !is_matched {
+            return Ok(());
+        }
+
+        Err(ExpectOpError::NotMatched {
+            context: context.to_static(),
+            expected: self.expected.clone().into(),
+            received: received.clone().into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_not {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_different_value() {
+        let left = json!(200);
+        let right = json!(expect::not(0));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_value_failing_inner_expectation() {
+        let left = json!(123);
+        let right = json!(expect::not(expect::string()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_equal_value() {
+        let left = json!({ "code": 0 });
+        let right = json!({ "code": expect::not(0) });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::not() error at root.code, received value matched:
+    expected not 0
+    received 0"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_value_passing_inner_expectation() {
+        let left = json!("abc");
+        let right = json!(expect::not(expect::string()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::not() error at root, received value matched:
+    expected not expect::string()
+    received "abc""#
+        );
+    }
+}
@@ -4,11 +4,13 @@ use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
 use serde_json::Map;
 use serde_json::Value;
 
 #[expect_op(internal, name = "object")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectObject {
     sub_ops: Vec<ExpectObjectSubOp>,
 }
@@ -28,6 +30,132 @@ impl ExpectObject {
         self
     }
 
+    pub fn len(mut self, len: usize) -> Self {
+        self.sub_ops.push(ExpectObjectSubOp::Len(len));
+        self
+    }
+
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.sub_ops.push(ExpectObjectSubOp::MinLen(min_len));
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.sub_ops.push(ExpectObjectSubOp::MaxLen(max_len));
+        self
+    }
+
+    /// Expects all keys of the object to match the expectation given.
+    /// Each key is checked as a Json string.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/users")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         // expect an object keyed by user ids
+    ///         "users": expect_json::object().all_keys(expect_json::uuid()),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn all_keys<V>(mut self, expected: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.sub_ops
+            .push(ExpectObjectSubOp::AllKeys(expected.into()));
+        self
+    }
+
+    /// Expects all values of the object to match the expectation given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/users")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "users": expect_json::object().all_values(json!({
+    ///             "name": expect_json::string().not_empty(),
+    ///             "email": expect_json::email(),
+    ///         })),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn all_values<V>(mut self, expected: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.sub_ops
+            .push(ExpectObjectSubOp::AllValues(expected.into()));
+        self
+    }
+
+    /// Expects at least one value of the object to match the expectation given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/users")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "users": expect_json::object().any_value(
+    ///             expect_json::object().contains(json!({
+    ///                 "is_admin": true,
+    ///             }))
+    ///         ),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn any_value<V>(mut self, expected: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.sub_ops
+            .push(ExpectObjectSubOp::AnyValue(expected.into()));
+        self
+    }
+
     /// Expect an object containing a subset of the JSON object given.
     ///
     /// ```rust
@@ -75,6 +203,154 @@ impl ExpectObject {
         self
     }
 
+    /// Expect an object where none of the fields given are present with the values given.
+    /// Fields which are missing, or have a different value, are accepted.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user/barrington")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": "Barrington",
+    ///         "metadata": expect_json::object().not_contains(json!({
+    ///             "is_deleted": true,
+    ///             "password": expect_json::string(),
+    ///         })),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn not_contains<V>(mut self, expected_values: V) -> Self
+    where
+        V: Into<Value>,
+    {
+        let value = Into::<Value>::into(expected_values);
+        let sub_op = match value {
+            Value::Object(values_object) => ExpectObjectSubOp::NotContains(values_object),
+            _ => {
+                let value_type = JsonType::from(&value);
+                panic!("object().not_contains() expected to take object. Received: {value_type}");
+            }
+        };
+
+        self.sub_ops.push(sub_op);
+        self
+    }
+
+    /// Expects the object to have exactly the keys given, with any values.
+    /// Missing keys, and keys not in the list, are both errors.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user/barrington")
+    ///     .await
+    ///     .assert_json(&expect_json::object().only_keys(["id", "name", "email"]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn only_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.sub_ops.push(ExpectObjectSubOp::OnlyKeys(keys));
+        self
+    }
+
+    /// Expects the object to have all of the keys given, with any values.
+    /// Other keys are allowed.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user/barrington")
+    ///     .await
+    ///     .assert_json(&expect_json::object().has_keys(["id", "name"]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn has_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.sub_ops.push(ExpectObjectSubOp::HasKeys(keys));
+        self
+    }
+
+    /// Expects the object to have none of the keys given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user/barrington")
+    ///     .await
+    ///     .assert_json(&expect_json::object().forbids_keys(["password", "password_hash"]));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn forbids_keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let keys = keys.into_iter().map(Into::into).collect::<Vec<_>>();
+        self.sub_ops.push(ExpectObjectSubOp::ForbidsKeys(keys));
+        self
+    }
+
     #[doc(hidden)]
     pub fn propagated_contains<V>(mut self, expected_values: V) -> Self
     where
@@ -102,11 +378,12 @@ impl ExpectOp for ExpectObject {
         context: &mut Context,
         received: &Map<String, Value>,
     ) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
         for sub_op in &self.sub_ops {
-            sub_op.on_object(self, context, received)?;
+            errors.check(sub_op.on_object(self, context, received))?;
         }
 
-        Ok(())
+        errors.into_result()
     }
 
     fn debug_supported_types(&self) -> &'static [JsonType] {
@@ -256,11 +533,12 @@ mod test_contains {
         assert_eq!(
             output,
             r#"Json object at root.meta has many extra fields over expected:
-    expected {}
-    received {
-        "location": "uk",
-        "previous_login": "sometime last thursday"
-    }
+    - expected
+    + received
+      {
+    +     "location": "uk",
+    +     "previous_login": "sometime last thursday",
+      }
 
     extra fields in received:
         location,
@@ -522,11 +800,12 @@ mod test_propagated_contains {
         assert_eq!(
             output,
             r#"Json object at root.meta.inner has many extra fields over expected:
-    expected {}
-    received {
-        "location": "uk",
-        "previous_login": "sometime last thursday"
-    }
+    - expected
+    + received
+      {
+    +     "location": "uk",
+    +     "previous_login": "sometime last thursday",
+      }
 
     extra fields in received:
         location,
@@ -658,65 +937,567 @@ mod test_propagated_contains {
 }
 
 #[cfg(test)]
-mod test_empty {
+mod test_not_contains {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_object_is_empty() {
-        let left = json!({});
-        let right = json!(expect::object().empty());
+    fn it_should_be_ok_for_missing_fields() {
+        let left = json!({ "name": "John", "age": 30 });
+        let right = json!(expect::object().not_contains(json!({ "password": "abc123" })));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_when_object_is_not_empty() {
-        let left = json!({ "foo": "bar" });
-        let right = json!(expect::object().empty());
+    fn it_should_be_ok_for_fields_with_different_values() {
+        let left = json!({ "name": "John", "age": 30, "scores": [1, 2, 3] });
+        let right = json!(expect::object().not_contains(json!({
+            "name": "Joe",
+            "age": 31,
+            "scores": [4, 5, 6],
+        })));
 
-        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
-        assert_eq!(
-            output,
-            r#"Json expect::object() error at root:
-    expected empty object
-    received {
-        "foo": "bar"
-    }"#
-        );
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
     }
-}
-
-#[cfg(test)]
-mod test_not_empty {
-    use crate::expect;
-    use crate::expect_json_eq;
-    use pretty_assertions::assert_eq;
-    use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_object_is_not_empty() {
-        let left = json!({ "foo": "bar" });
-        let right = json!(expect::object().not_empty());
+    fn it_should_be_ok_for_empty_not_contains() {
+        let left = json!({ "name": "John" });
+        let right = json!(expect::object().not_contains(json!({})));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_when_object_is_empty() {
-        let left = json!({});
-        let right = json!(expect::object().not_empty());
+    fn it_should_error_for_field_with_equal_value() {
+        let left = json!({ "name": "John", "is_deleted": true });
+        let right = json!({
+            "user": expect::object().not_contains(json!({ "is_deleted": true })),
+        });
 
-        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        let output = expect_json_eq(&json!({ "user": left }), &right)
+            .unwrap_err()
+            .to_string();
         assert_eq!(
             output,
-            r#"Json expect::object() error at root:
-    expected non-empty object
-    received {}"#
+            r#"Json at root.user.is_deleted has key with value, expecting either key not present or different value.
+    received true"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_field_matching_expect_op() {
+        let left = json!({ "name": "John", "password": "abc123" });
+        let right = json!(expect::object().not_contains(json!({
+            "password": expect::string(),
+        })));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json at root.password has key with value, expecting either key not present or different value.
+    received "abc123""#
+        );
+    }
+
+    #[test]
+    fn it_should_error_if_used_against_the_wrong_type() {
+        let left = json!("🦊");
+        let right = json!(expect::object().not_contains(json!({})));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() at root, received wrong type:
+    expected object
+    received string "🦊""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_empty {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().empty());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_is_not_empty() {
+        let left = json!({ "foo": "bar" });
+        let right = json!(expect::object().empty());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected empty object
+    received {
+        "foo": "bar"
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_not_empty {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_is_not_empty() {
+        let left = json!({ "foo": "bar" });
+        let right = json!(expect::object().not_empty());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().not_empty());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected non-empty object
+    received {}"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_min_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_has_exactly_enough_fields() {
+        let left = json!({ "a": 1, "b": 2 });
+        let right = json!(expect::object().min_len(2));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_has_too_few_fields() {
+        let left = json!({ "a": 1, "b": 2 });
+        let right = json!(expect::object().min_len(3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected object to have at least 3 fields, but it has 2.
+    received {
+        "a": 1,
+        "b": 2
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_has_same_number_of_fields() {
+        let left = json!({ "a": 1, "b": 2 });
+        let right = json!(expect::object().len(2));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_has_different_number_of_fields() {
+        let left = json!({ "a": 1 });
+        let right = json!(expect::object().len(2));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected object to have 2 fields, but it has 1.
+    received {
+        "a": 1
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_max_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().max_len(1));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_has_too_many_fields() {
+        let left = json!({ "a": 1, "b": 2 });
+        let right = json!(expect::object().max_len(1));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected object to have at most 1 fields, but it has 2.
+    received {
+        "a": 1,
+        "b": 2
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_all_keys {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_all;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().all_keys(expect::uuid()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_all_keys_match() {
+        let left = json!({
+            "123e4567-e89b-12d3-a456-426614174000": "Alice",
+            "123e4567-e89b-12d3-a456-426614174001": "Bob",
+        });
+        let right = json!(expect::object().all_keys(expect::uuid()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_with_failing_key_in_path() {
+        let left = json!({
+            "123e4567-e89b-12d3-a456-426614174000": "Alice",
+            "00000000-0000-0000-0000-000000000000": "Bob",
+        });
+        let right = json!(expect::object().all_keys(expect::uuid().not_nil()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root."00000000-0000-0000-0000-000000000000":
+    expected uuid to be not nil, but it is, received '00000000-0000-0000-0000-000000000000'
+    received object key "00000000-0000-0000-0000-000000000000""#
+        );
+    }
+
+    #[test]
+    fn it_should_report_every_failing_key_when_collecting_all_errors() {
+        let left = json!({ "a": 1, "B": 2, "C": 3 });
+        let right = json!(expect::object().all_keys(expect::string().max_len(0)));
+
+        let output = expect_json_eq_all(&left, &right).unwrap_err().into_errors();
+        assert_eq!(output.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod test_all_values {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().all_values(expect::string()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_all_values_match() {
+        let left = json!({
+            "alice": { "age": 30 },
+            "bob": { "age": 40 },
+        });
+        let right = json!(expect::object().all_values(json!({
+            "age": expect::integer().positive(),
+        })));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_with_failing_key_in_path() {
+        let left = json!({
+            "alice": "Alice",
+            "bob": 123,
+        });
+        let right = json!(expect::object().all_values(expect::string()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() at root.bob, received wrong type:
+    expected string
+    received integer 123
+    received full object {
+        "alice": "Alice",
+        "bob": 123
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_any_value {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_one_value_matches() {
+        let left = json!({
+            "alice": "Alice",
+            "bob": 123,
+        });
+        let right = json!(expect::object().any_value(expect::integer()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_object_is_empty() {
+        let left = json!({});
+        let right = json!(expect::object().any_value("Alice"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected any value to match "Alice",
+    but none did
+    received {}"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_no_values_match() {
+        let left = json!({
+            "alice": "Alice",
+            "bob": "Bob",
+        });
+        let right = json!(expect::object().any_value(expect::integer()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::object() error at root:
+    expected any value to match expect::integer(),
+    but none did
+    received {
+        "alice": "Alice",
+        "bob": "Bob"
+    }"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_only_keys {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_all;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_keys_are_the_same() {
+        let left = json!({ "id": 1, "name": "Joe" });
+        let right = json!(expect::object().only_keys(["name", "id"]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_received_has_extra_key() {
+        let left = json!({ "id": 1, "name": "Joe", "password": "hunter2" });
+        let right = json!(expect::object().only_keys(["id", "name"]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root has extra field "password":
+    - expected
+    + received
+      {
+          "id": 1,
+          "name": "Joe",
+    +     "password": "hunter2",
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_received_has_many_extra_keys() {
+        let left = json!({ "id": 1, "is-admin": true, "password": "hunter2" });
+        let right = json!(expect::object().only_keys(["id"]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root has many extra fields over expected:
+    - expected
+    + received
+      {
+          "id": 1,
+    +     "is-admin": true,
+    +     "password": "hunter2",
+      }
+
+    extra fields in received:
+        "is-admin",
+        password,
+"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_received_is_missing_key() {
+        let left = json!({ "id": 1 });
+        let right = json!(expect::object().only_keys(["id", "name"]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root is missing key for object:
+    expected field 'name',
+    but it was not found"#
+        );
+    }
+
+    #[test]
+    fn it_should_report_extra_and_missing_keys_when_collecting_all_errors() {
+        let left = json!({ "id": 1, "password": "hunter2" });
+        let right = json!(expect::object().only_keys(["id", "name"]));
+
+        let output = expect_json_eq_all(&left, &right).unwrap_err().into_errors();
+        assert_eq!(output.len(), 2);
+    }
+}
+
+#[cfg(test)]
+mod test_has_keys {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_keys_are_present_with_others() {
+        let left = json!({ "id": 1, "name": "Joe", "age": 30 });
+        let right = json!(expect::object().has_keys(["id", "name"]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_key_is_missing() {
+        let left = json!({ "id": 1, "age": 30 });
+        let right = json!(expect::object().has_keys(["id", "name"]));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root is missing key for object:
+    expected field 'name',
+    but it was not found"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_forbids_keys {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_keys_are_not_present() {
+        let left = json!({ "id": 1, "name": "Joe" });
+        let right = json!(expect::object().forbids_keys(["password"]));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_forbidden_key_is_present() {
+        let left = json!({ "user": { "id": 1, "password": "hunter2" } });
+        let right = json!({
+            "user": expect::object().forbids_keys(["password", "password_hash"]),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root.user has extra field "password":
+    - expected
+    + received
+      {
+          "id": 1,
+    +     "password": "hunter2",
+      }"#
         );
     }
 }
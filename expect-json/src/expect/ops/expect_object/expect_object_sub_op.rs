@@ -3,9 +3,11 @@ use crate::expect::ops::ExpectObject;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
+use crate::internals::ErrorCollector;
 use crate::internals::ExpectOpMeta;
 use crate::internals::json_value_eq::json_value_eq_object_contains;
 use crate::internals::objects::ObjectObject;
+use crate::internals::objects::ValueObject;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Map;
@@ -15,7 +17,17 @@ use serde_json::Value;
 pub enum ExpectObjectSubOp {
     Empty,
     NotEmpty,
+    MinLen(usize),
+    Len(usize),
+    MaxLen(usize),
+    AllKeys(Value),
+    AllValues(Value),
+    AnyValue(Value),
+    OnlyKeys(Vec<String>),
+    HasKeys(Vec<String>),
+    ForbidsKeys(Vec<String>),
     Contains(Map<String, Value>),
+    NotContains(Map<String, Value>),
     PartialContains(Map<String, Value>),
 }
 
@@ -29,9 +41,21 @@ impl ExpectObjectSubOp {
         match self {
             Self::Empty => on_object_empty(parent, context, received),
             Self::NotEmpty => on_object_not_empty(parent, context, received),
+            Self::MinLen(min_len) => on_object_min_len(*min_len, parent, context, received),
+            Self::Len(len) => on_object_len(*len, parent, context, received),
+            Self::MaxLen(max_len) => on_object_max_len(*max_len, parent, context, received),
+            Self::AllKeys(expected) => on_object_all_keys(expected, context, received),
+            Self::AllValues(expected) => on_object_all_values(expected, context, received),
+            Self::AnyValue(expected) => on_object_any_value(expected, parent, context, received),
+            Self::OnlyKeys(keys) => on_object_only_keys(keys, parent, context, received),
+            Self::HasKeys(keys) => on_object_has_keys(keys, parent, context, received),
+            Self::ForbidsKeys(keys) => on_object_forbids_keys(keys, context, received),
             Self::Contains(expected_values) => {
                 on_object_contains(parent, context, expected_values, received)
             }
+            Self::NotContains(expected_values) => {
+                on_object_not_contains(parent, context, expected_values, received)
+            }
             Self::PartialContains(expected_values) => {
                 on_object_propagated_contains(parent, context, expected_values, received)
             }
@@ -73,24 +97,280 @@ fn on_object_not_empty(
     Ok(())
 }
 
+fn on_object_min_len(
+    min_len: usize,
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    if received.len() < min_len {
+        let error_message = format!(
+            r#"expected object to have at least {} fields, but it has {}.
+    received {}"#,
+            min_len,
+            received.len(),
+            ObjectObject::from(received.clone())
+        );
+        return Err(ExpectOpError::custom(parent, context, error_message));
+    }
+
+    Ok(())
+}
+
+fn on_object_len(
+    len: usize,
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    if received.len() != len {
+        let error_message = format!(
+            r#"expected object to have {} fields, but it has {}.
+    received {}"#,
+            len,
+            received.len(),
+            ObjectObject::from(received.clone())
+        );
+        return Err(ExpectOpError::custom(parent, context, error_message));
+    }
+
+    Ok(())
+}
+
+fn on_object_max_len(
+    max_len: usize,
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    if received.len() > max_len {
+        let error_message = format!(
+            r#"expected object to have at most {} fields, but it has {}.
+    received {}"#,
+            max_len,
+            received.len(),
+            ObjectObject::from(received.clone())
+        );
+        return Err(ExpectOpError::custom(parent, context, error_message));
+    }
+
+    Ok(())
+}
+
+fn on_object_all_keys(
+    expected: &Value,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let mut errors = ErrorCollector::new(context);
+    for key in received.keys() {
+        let received_key = Value::String(key.clone());
+        let result = context
+            .with_path(key.clone())
+            .json_eq(&received_key, expected)
+            .map_err(|error| ExpectOpError::ObjectAllKeys {
+                error: Box::new(error),
+                received_key: key.clone(),
+            });
+        errors.check(result)?;
+    }
+
+    errors.into_result()
+}
+
+fn on_object_all_values(
+    expected: &Value,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let mut errors = ErrorCollector::new(context);
+    for (key, value) in received {
+        let result = context
+            .with_path(key.clone())
+            .json_eq(value, expected)
+            .map_err(|error| ExpectOpError::ObjectAllValues {
+                error: Box::new(error),
+                received_full_object: ObjectObject::from(received.clone()),
+            });
+        errors.check(result)?;
+    }
+
+    errors.into_result()
+}
+
+fn on_object_any_value(
+    expected: &Value,
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let is_found = received.iter().any(|(key, value)| {
+        let checkpoint = context.captures_checkpoint();
+        let result = context.with_path(key.clone()).json_eq(value, expected);
+        checkpoint.restore_on_err(result).is_ok()
+    });
+
+    if !is_found {
+        let error_message = format!(
+            r#"expected any value to match {},
+    but none did
+    received {}"#,
+            ValueObject::from(expected.clone()),
+            ObjectObject::from(received.clone())
+        );
+        return Err(ExpectOpError::custom(parent, context, error_message));
+    }
+
+    Ok(())
+}
+
+fn on_object_only_keys(
+    keys: &[String],
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let extra_keys = received
+        .keys()
+        .filter(|key| !keys.contains(key))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let mut errors = ErrorCollector::new(context);
+    if !extra_keys.is_empty() {
+        errors.check(Err(extra_keys_error(context, extra_keys, received)))?;
+    }
+    errors.check(on_object_has_keys(keys, parent, context, received))?;
+
+    errors.into_result()
+}
+
+fn on_object_has_keys(
+    keys: &[String],
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let mut errors = ErrorCollector::new(context);
+    for key in keys {
+        if !received.contains_key(key) {
+            errors.check(Err(ExpectOpError::ObjectKeyMissingForExpectOp {
+                context: context.to_static(),
+                expected_key: key.clone(),
+                expected_operation: ExpectOpMeta::new(parent),
+            }))?;
+        }
+    }
+
+    errors.into_result()
+}
+
+fn on_object_forbids_keys(
+    keys: &[String],
+    context: &mut Context<'_>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    let forbidden_keys = received
+        .keys()
+        .filter(|key| keys.contains(key))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if !forbidden_keys.is_empty() {
+        return Err(extra_keys_error(context, forbidden_keys, received));
+    }
+
+    Ok(())
+}
+
+/// Reports the keys given as extra fields on the received object.
+/// The expected object shown is the received object without those keys.
+fn extra_keys_error(
+    context: &Context<'_>,
+    mut extra_keys: Vec<String>,
+    received: &Map<String, Value>,
+) -> ExpectOpError {
+    let expected = received
+        .iter()
+        .filter(|(key, _)| !extra_keys.contains(key))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Map<_, _>>();
+
+    let error = if extra_keys.len() == 1 {
+        ExpectJsonError::ObjectReceivedHasExtraKey {
+            context: context.to_static(),
+            received_extra_field: extra_keys.remove(0),
+            received_obj: Box::new(ObjectObject::from(received.clone()).into()),
+            expected_obj: Box::new(ObjectObject::from(expected).into()),
+        }
+    } else {
+        ExpectJsonError::ObjectReceivedHasExtraKeys {
+            context: context.to_static(),
+            received_extra_fields: extra_keys,
+            received_obj: Box::new(ObjectObject::from(received.clone()).into()),
+            expected_obj: Box::new(ObjectObject::from(expected).into()),
+        }
+    };
+
+    error.into()
+}
+
 fn on_object_contains(
     parent: &ExpectObject,
     context: &mut Context<'_>,
     expected: &Map<String, Value>,
     received: &Map<String, Value>,
 ) -> ExpectOpResult<()> {
-    json_value_eq_object_contains(&mut context.clone(), received, expected).map_err(|err| match err
-    {
-        ExpectJsonError::ObjectKeyMissing {
-            context,
-            expected_key,
-        } => ExpectOpError::ObjectKeyMissingForExpectOp {
-            context,
-            expected_key,
-            expected_operation: ExpectOpMeta::new(parent),
-        },
-        err => err.into(),
-    })
+    let Err(error) = json_value_eq_object_contains(&mut context.clone(), received, expected) else {
+        return Ok(());
+    };
+
+    let mut errors = ErrorCollector::new(context);
+    for error in error.into_errors() {
+        let error = match error {
+            ExpectJsonError::ObjectKeyMissing {
+                context,
+                expected_key,
+            } => ExpectOpError::ObjectKeyMissingForExpectOp {
+                context,
+                expected_key,
+                expected_operation: ExpectOpMeta::new(parent),
+            },
+            err => err.into(),
+        };
+        errors.check(Err(error))?;
+    }
+
+    errors.into_result()
+}
+
+fn on_object_not_contains(
+    parent: &ExpectObject,
+    context: &mut Context<'_>,
+    expected: &Map<String, Value>,
+    received: &Map<String, Value>,
+) -> ExpectOpResult<()> {
+    for (key, expected_value) in expected {
+        let Some(received_value) = received.get(key) else {
+            continue;
+        };
+
+        let mut key_context = context.clone();
+        key_context.push(key.clone());
+        let checkpoint = context.captures_checkpoint();
+        let is_matched = key_context.json_eq(received_value, expected_value).is_ok();
+        checkpoint.restore();
+
+        if is_matched {
+            return Err(ExpectOpError::ObjectKeyValueIsEqual {
+                context: key_context.to_static(),
+                received: ValueObject::from(received_value.clone()),
+                expected_operation: ExpectOpMeta::new(parent),
+            });
+        }
+    }
+
+    Ok(())
 }
 
 fn on_object_propagated_contains(
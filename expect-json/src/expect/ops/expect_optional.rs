@@ -0,0 +1,136 @@
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use serde_json::Value;
+
+///
+/// Expects an object field is either missing, or matches the value given.
+///
+/// You can build these using the [`crate::expect::optional`] function.
+///
+#[expect_op(internal, name = "optional")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectOptional {
+    expected: Value,
+}
+
+impl ExpectOptional {
+    pub(crate) fn new(expected: Value) -> Self {
+        Self { expected }
+    }
+}
+
+impl ExpectOp for ExpectOptional {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        context
+            .json_eq(received, &self.expected)
+            .map_err(ExpectOpError::from)
+    }
+
+    fn allows_missing_key(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test_optional {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_all;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_missing_field() {
+        let left = json!({ "name": "Joe" });
+        let right = json!({
+            "name": "Joe",
+            "email": expect::optional(expect::email()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_matching_field() {
+        let left = json!({ "name": "Joe", "email": "joe@example.com" });
+        let right = json!({
+            "name": "Joe",
+            "email": expect::optional(expect::email()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_field_not_matching() {
+        let left = json!({ "name": "Joe", "age": 30 });
+        let right = json!({
+            "name": "Joe",
+            "age": expect::optional(31),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json integers at root.age are not equal:
+    expected 31
+    received 30"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_extra_field_when_optional_field_is_missing() {
+        let left = json!({ "name": "Joe", "extra": true });
+        let right = json!({
+            "name": "Joe",
+            "age": expect::optional(expect::integer()),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root has extra field "extra":
+    - expected
+    + received
+      {
+    +     "extra": true,
+          "name": "Joe",
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_missing_required_field_alongside_optional_field() {
+        let left = json!({ "age": 30 });
+        let right = json!({
+            "name": "Joe",
+            "age": expect::optional(expect::integer()),
+        });
+
+        let output = expect_json_eq_all(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json objects at root are not equal:
+    expected field 'name',
+    but it was not found"#
+        );
+    }
+
+    #[test]
+    fn it_should_accept_missing_field_within_object_contains() {
+        let left = json!({ "name": "Joe", "age": 30 });
+        let right = json!(expect::object().contains(json!({
+            "name": "Joe",
+            "email": expect::optional(expect::email()),
+        })));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::expect::ops::expect_capture::check_bound_capture;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use serde_json::Value;
+
+///
+/// Expects the value is equal to the value bound to the name given,
+/// using [`crate::expect::capture`].
+///
+/// You can build these using the [`crate::expect::same_as`] function.
+///
+#[expect_op(internal, name = "same_as")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectSameAs {
+    name: String,
+}
+
+impl ExpectSameAs {
+    pub(crate) fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl ExpectOp for ExpectSameAs {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let maybe_bound = context.get_or_bind_same_as(&self.name, received);
+        check_bound_capture(self, context, &self.name, maybe_bound, received)
+    }
+}
+
+#[cfg(test)]
+mod test_same_as {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_all;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_same_value_as_captured() {
+        let left = json!({
+            "id": "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+            "links": { "self": "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8" },
+        });
+        let right = json!({
+            "id": expect::capture("user_id", expect::uuid()),
+            "links": { "self": expect::same_as("user_id") },
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_accept_same_as_coming_before_capture() {
+        let left = json!({
+            "a_parent_id": 123,
+            "z_id": 123,
+        });
+        let right = json!({
+            "a_parent_id": expect::same_as("id"),
+            "z_id": expect::capture("id", expect::integer()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_different_value_to_captured() {
+        let left = json!({
+            "id": 123,
+            "links": { "self": 456 },
+        });
+        let right = json!({
+            "id": expect::capture("user_id", expect::integer()),
+            "links": { "self": expect::same_as("user_id") },
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::same_as() error at root.links.self:
+    expected value captured as "user_id",
+    expected 123
+    received 456"#
+        );
+    }
+
+    #[test]
+    fn it_should_accept_every_item_in_array_being_the_same_as_captured() {
+        let left = json!({
+            "owner": "joe",
+            "items": [{ "owner": "joe" }, { "owner": "joe" }],
+        });
+        let right = json!({
+            "owner": expect::capture("owner", expect::string()),
+            "items": expect::array().all(json!({ "owner": expect::same_as("owner") })),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_each_item_in_array_different_to_captured() {
+        let left = json!({
+            "owner": "joe",
+            "items": [{ "owner": "joe" }, { "owner": "sam" }, { "owner": "ann" }],
+        });
+        let right = json!({
+            "owner": expect::capture("owner", expect::string()),
+            "items": expect::array().all(json!({ "owner": expect::same_as("owner") })),
+        });
+
+        let output = expect_json_eq_all(&left, &right).unwrap_err().into_errors();
+        assert_eq!(output.len(), 2);
+    }
+
+    #[test]
+    fn it_should_reject_same_as_when_nothing_is_captured() {
+        let left = json!({ "id": 123, "links": { "self": 123 } });
+        let right = json!({
+            "id": expect::integer(),
+            "links": { "self": expect::same_as("user_id") },
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::same_as() error at root.links.self:
+    no value was captured as "user_id",
+    expected an expect::capture() using the same name"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_same_as_when_the_capture_failed_to_match() {
+        let left = json!([1, { "id": 1, "kind": "b" }]);
+        let right = json!([
+            expect::same_as("id"),
+            expect::any_of([
+                json!({ "id": expect::capture("id", expect::integer()), "kind": "a" }),
+                json!({ "id": expect::integer(), "kind": "b" }),
+            ]),
+        ]);
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::same_as() error at root[0]:
+    no value was captured as "id",
+    expected an expect::capture() using the same name"#
+        );
+    }
+}
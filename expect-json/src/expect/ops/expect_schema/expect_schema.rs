@@ -0,0 +1,649 @@
+use crate::expect::ops::expect_schema::SchemaPatterns;
+use crate::expect::ops::expect_schema::SchemaValidator;
+use crate::expect::ops::expect_schema::compile_schema_patterns;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+///
+/// Expects the value is valid against a Json Schema (draft 2020-12).
+///
+/// You can build these using the [`crate::expect::schema`] function.
+///
+#[expect_op(internal, name = "schema")]
+#[derive(Debug, Clone, Default)]
+#[serde(default)]
+pub struct ExpectSchema {
+    schema: Value,
+
+    /// Compiled when the schema is built, or when first used after being deserialised.
+    #[serde(skip)]
+    patterns: OnceLock<SchemaPatterns>,
+}
+
+impl ExpectSchema {
+    pub(crate) fn new(schema: Value, patterns: SchemaPatterns) -> Self {
+        Self {
+            schema,
+            patterns: OnceLock::from(patterns),
+        }
+    }
+}
+
+/// Schemas are equal when they are written the same, as the patterns come from the schema.
+impl PartialEq for ExpectSchema {
+    fn eq(&self, other: &Self) -> bool {
+        self.schema == other.schema
+    }
+}
+
+impl ExpectOp for ExpectSchema {
+    fn on_any(&self, context: &mut Context<'_>, received: &Value) -> ExpectOpResult<()> {
+        let patterns = self
+            .patterns
+            .get_or_init(|| compile_schema_patterns(&self.schema));
+        let violations = SchemaValidator::new(&self.schema, patterns)
+            .validate(&mut context.to_static(), received);
+
+        let mut errors = ErrorCollector::new(context);
+        for violation in violations {
+            errors.check(Err(ExpectOpError::custom(
+                self,
+                &violation.context,
+                violation.message,
+            )))?;
+        }
+
+        errors.into_result()
+    }
+}
+
+#[cfg(test)]
+mod test_schema {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_all;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn user_schema() -> serde_json::Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["id", "name", "roles"],
+            "additionalProperties": false,
+            "properties": {
+                "id": { "type": "integer", "minimum": 1 },
+                "name": { "type": "string", "minLength": 1 },
+                "email": { "type": "string", "pattern": "@" },
+                "roles": {
+                    "type": "array",
+                    "items": { "$ref": "#/$defs/role" },
+                    "uniqueItems": true,
+                },
+            },
+            "$defs": {
+                "role": { "enum": ["admin", "staff", "guest"] },
+            },
+        })
+    }
+
+    #[test]
+    fn it_should_accept_valid_value() {
+        let left = json!({
+            "id": 123,
+            "name": "Joe",
+            "email": "joe@example.com",
+            "roles": ["admin", "staff"],
+        });
+        let right = json!(expect::schema(user_schema()));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_with_path_of_failing_value() {
+        let left = json!({
+            "user": {
+                "id": 123,
+                "name": "Joe",
+                "roles": ["admin", "owner"],
+            },
+        });
+        let right = json!({
+            "user": expect::schema(user_schema()),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.user.roles[1]:
+    expected one of ["admin", "staff", "guest"],
+    received "owner",
+    schema path '#/$defs/role/enum'"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_missing_required_field() {
+        let left = json!({ "id": 123, "roles": [] });
+        let right = json!(expect::schema(user_schema()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected field 'name',
+    but it was not found,
+    schema path '#/required'"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_additional_properties() {
+        let left = json!({ "id": 123, "name": "Joe", "roles": [], "password": "hunter2" });
+        let right = json!(expect::schema(user_schema()));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.password:
+    unexpected field, as additionalProperties is false,
+    schema path '#/additionalProperties'"#
+        );
+    }
+
+    #[test]
+    fn it_should_report_every_violation_when_collecting_all_errors() {
+        let left = json!({
+            "id": 0,
+            "name": "",
+            "roles": ["admin", "admin"],
+        });
+        let right = json!(expect::schema(user_schema()));
+
+        let output = expect_json_eq_all(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json has 3 errors:
+
+Json expect::schema() error at root.id:
+    expected number at least 1,
+    received 0,
+    schema path '#/properties/id/minimum'
+
+Json expect::schema() error at root.name:
+    expected string with at least 1 characters,
+    received 0 characters "",
+    schema path '#/properties/name/minLength'
+
+Json expect::schema() error at root.roles[1]:
+    expected unique items, this is a duplicate of index 0,
+    received "admin",
+    schema path '#/properties/roles/uniqueItems'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_types_with_integers_as_numbers() {
+        let schema = json!({ "type": "array", "items": { "type": "number" } });
+
+        let output = expect_json_eq(&json!([1, 2.5]), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!([1, "2"]), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root[1]:
+    expected type number,
+    received string "2",
+    schema path '#/items/type'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_one_of() {
+        let schema = json!({
+            "oneOf": [
+                { "type": "integer" },
+                { "type": "number", "minimum": 10 },
+            ],
+        });
+
+        let output = expect_json_eq(&json!(5), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(20), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected value to match exactly one of the 2 oneOf schemas,
+    but it matched 2,
+    schema path '#/oneOf'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_if_then_else() {
+        let schema = json!({
+            "if": { "properties": { "kind": { "const": "card" } } },
+            "then": { "required": ["card_number"] },
+            "else": { "required": ["iban"] },
+        });
+
+        let output = expect_json_eq(
+            &json!({ "kind": "bank", "iban": "GB00" }),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!({ "kind": "card" }), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected field 'card_number',
+    but it was not found,
+    schema path '#/then/required'"#
+        );
+    }
+
+    #[test]
+    fn it_should_resolve_recursive_refs() {
+        let schema = json!({
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "value": { "type": "integer" },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } },
+                    },
+                },
+            },
+            "$ref": "#/$defs/node",
+        });
+        let left = json!({
+            "value": 1,
+            "children": [{ "value": 2, "children": [{ "value": "3" }] }],
+        });
+
+        let output = expect_json_eq(&left, &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.children[0].children[0].value:
+    expected type integer,
+    received string "3",
+    schema path '#/$defs/node/properties/value/type'"#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_remote_refs() {
+        let schema = json!({ "$ref": "https://example.com/schema.json" });
+
+        let output = expect_json_eq(&json!(1), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    $ref 'https://example.com/schema.json' is not supported, only local references starting with '#' are,
+    schema path '#/$ref'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_contains_and_multiple_of() {
+        let schema = json!({
+            "contains": { "type": "number", "multipleOf": 0.1 },
+            "minContains": 2,
+        });
+
+        let output = expect_json_eq(
+            &json!([0.3, "a", 1.2]),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!([0.35, "a", 1.2]), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected array to contain at least 2 matching items,
+    found 1,
+    schema path '#/minContains'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_prefix_items() {
+        let schema = json!({
+            "prefixItems": [{ "type": "string" }, { "type": "integer" }],
+            "items": { "type": "boolean" },
+        });
+
+        let output = expect_json_eq(
+            &json!(["a", 1, true]),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(["a", "b", true]), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root[1]:
+    expected type integer,
+    received string "b",
+    schema path '#/prefixItems/1/type'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_pattern_properties() {
+        let schema = json!({
+            "patternProperties": { "^x-": { "type": "string" } },
+            "additionalProperties": false,
+        });
+
+        let output = expect_json_eq(
+            &json!({ "x-id": "abc" }),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!({ "x-id": 123 }), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root."x-id":
+    expected type string,
+    received integer 123,
+    schema path '#/patternProperties/^x-/type'"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_on_invalid_pattern_properties() {
+        let _ = expect::schema(json!({ "patternProperties": { "[": {} } }));
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_on_invalid_pattern() {
+        let _ = expect::schema(json!({ "properties": { "id": { "pattern": "[" } } }));
+    }
+
+    #[test]
+    fn it_should_not_treat_patterns_in_const_as_regexes() {
+        let schema = json!({ "const": { "pattern": "[" } });
+
+        let output = expect_json_eq(&json!({ "pattern": "[" }), &json!(expect::schema(schema)));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_validate_property_names() {
+        let schema = json!({ "propertyNames": { "maxLength": 3 } });
+
+        let output = expect_json_eq(&json!({ "abc": 1 }), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!({ "abcd": 1 }), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.abcd:
+    expected string with at most 3 characters,
+    received 4 characters "abcd",
+    schema path '#/propertyNames/maxLength'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_dependent_required() {
+        let schema = json!({ "dependentRequired": { "card": ["expiry"] } });
+
+        let output = expect_json_eq(
+            &json!({ "iban": "GB00" }),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!({ "card": "1234" }), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected field 'expiry', as field 'card' is present,
+    but it was not found,
+    schema path '#/dependentRequired'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_dependent_schemas() {
+        let schema = json!({
+            "dependentSchemas": {
+                "card": { "properties": { "expiry": { "type": "string" } } },
+            },
+        });
+
+        let output = expect_json_eq(
+            &json!({ "expiry": 1 }),
+            &json!(expect::schema(schema.clone())),
+        );
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(
+            &json!({ "card": "1234", "expiry": 1 }),
+            &json!(expect::schema(schema)),
+        )
+        .unwrap_err()
+        .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.expiry:
+    expected type string,
+    received integer 1,
+    schema path '#/dependentSchemas/card/properties/expiry/type'"#
+        );
+    }
+
+    #[test]
+    fn it_should_resolve_anchor_refs() {
+        let schema = json!({
+            "$defs": { "id": { "$anchor": "id", "type": "integer" } },
+            "properties": { "id": { "$ref": "#id" } },
+        });
+
+        let output = expect_json_eq(&json!({ "id": 1 }), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!({ "id": "1" }), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root.id:
+    expected type integer,
+    received string "1",
+    schema path '#id/type'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_max_contains() {
+        let schema = json!({
+            "contains": { "type": "string" },
+            "minContains": 0,
+            "maxContains": 1,
+        });
+
+        let output = expect_json_eq(&json!([1, 2]), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(["a", 1, "b"]), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected array to contain at most 1 matching items,
+    found 2,
+    schema path '#/maxContains'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_const_with_numbers_equal_by_value() {
+        let schema = json!({ "const": 1.0 });
+
+        let output = expect_json_eq(&json!(1), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(2), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected 1.0,
+    received 2,
+    schema path '#/const'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_all_of() {
+        let schema = json!({
+            "allOf": [{ "type": "string" }, { "minLength": 2 }],
+        });
+
+        let output = expect_json_eq(&json!("ab"), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!("a"), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected string with at least 2 characters,
+    received 1 characters "a",
+    schema path '#/allOf/1/minLength'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_not() {
+        let schema = json!({ "not": { "type": "null" } });
+
+        let output = expect_json_eq(&json!(1), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(null), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected value to not match the schema,
+    received null,
+    schema path '#/not'"#
+        );
+    }
+
+    #[test]
+    fn it_should_validate_exclusive_bounds() {
+        let schema = json!({ "exclusiveMinimum": 0, "exclusiveMaximum": 10 });
+
+        let output = expect_json_eq(&json!(5), &json!(expect::schema(schema.clone())));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = expect_json_eq(&json!(0), &json!(expect::schema(schema.clone())))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected number greater than 0,
+    received 0,
+    schema path '#/exclusiveMinimum'"#
+        );
+
+        let output = expect_json_eq(&json!(10), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected number less than 10,
+    received 10,
+    schema path '#/exclusiveMaximum'"#
+        );
+    }
+
+    #[test]
+    fn it_should_follow_recursive_refs_through_deeply_nested_values() {
+        let schema = json!({ "properties": { "next": { "$ref": "#" } } });
+        let mut left = json!({ "value": 300 });
+        for value in (0..300).rev() {
+            left = json!({ "value": value, "next": left });
+        }
+
+        let output = expect_json_eq(&left, &json!(expect::schema(schema)));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_refs_looping_without_moving_into_the_value() {
+        let schema = json!({ "anyOf": [{ "$ref": "#" }, { "$ref": "#" }] });
+
+        let output = expect_json_eq(&json!(1), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    expected value to match any of the 2 anyOf schemas,
+    but none did,
+    schema path '#/anyOf'"#
+        );
+    }
+
+    #[test]
+    fn it_should_report_refs_looping_back_to_the_same_schema() {
+        let schema = json!({ "$defs": { "a": { "$ref": "#/$defs/a" } }, "$ref": "#/$defs/a" });
+
+        let output = expect_json_eq(&json!(1), &json!(expect::schema(schema)))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::schema() error at root:
+    $ref '#/$defs/a' loops back to a schema already checking this value, it is an infinite loop,
+    schema path '#/$defs/a/$ref'"#
+        );
+    }
+}
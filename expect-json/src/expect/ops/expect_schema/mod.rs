@@ -0,0 +1,5 @@
+mod expect_schema;
+pub use expect_schema::*;
+
+mod schema_validator;
+pub(crate) use schema_validator::*;
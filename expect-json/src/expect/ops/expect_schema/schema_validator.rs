@@ -0,0 +1,849 @@
+use crate::expect_core::Context;
+use crate::expect_core::ContextPathPart;
+use crate::internals::objects::ArrayObject;
+use crate::internals::objects::ValueObject;
+use crate::internals::objects::ValueTypeObject;
+use crate::internals::utils::parse_json_pointer;
+use crate::internals::utils::parse_json_pointer_index;
+use regex::Error as RegexError;
+use regex::Regex;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// The compiled regexes for every `pattern` and `patternProperties` in a schema.
+pub type SchemaPatterns = BTreeMap<String, Result<Regex, RegexError>>;
+
+/// Keywords holding Json data, rather than schemas, so they are skipped when finding patterns.
+const DATA_KEYWORDS: [&str; 4] = ["const", "enum", "default", "examples"];
+
+/// A numeric keyword, the description used in errors, and the check it performs.
+type NumberBound = (&'static str, &'static str, fn(f64, f64) -> bool);
+
+/// A single place where the received value does not match the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    pub context: Context<'static>,
+    pub message: String,
+}
+
+///
+/// Validates values against a Json Schema, using draft 2020-12.
+///
+/// This supports the core and validation vocabularies, and the applicators.
+/// Only local references are supported (`#`, `#/$defs/name`, and `#anchor`),
+/// nothing is fetched.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaValidator<'s> {
+    root: &'s Value,
+    patterns: &'s SchemaPatterns,
+}
+
+impl<'s> SchemaValidator<'s> {
+    /// The patterns should come from [`compile_schema_patterns`] on the same schema.
+    pub fn new(root: &'s Value, patterns: &'s SchemaPatterns) -> Self {
+        Self { root, patterns }
+    }
+
+    /// Returns every violation found, with the context being the path to the failing value.
+    pub fn validate(
+        &self,
+        context: &mut Context<'static>,
+        received: &Value,
+    ) -> Vec<SchemaViolation> {
+        let mut violations = vec![];
+        self.validate_schema(
+            self.root,
+            "#",
+            &[self.root],
+            context,
+            received,
+            &mut violations,
+        );
+        violations
+    }
+
+    fn is_valid(
+        &self,
+        schema: &'s Value,
+        schema_path: &str,
+        refs: &[&'s Value],
+        context: &mut Context<'static>,
+        received: &Value,
+    ) -> bool {
+        let mut violations = vec![];
+        self.validate_schema(
+            schema,
+            schema_path,
+            refs,
+            context,
+            received,
+            &mut violations,
+        );
+        violations.is_empty()
+    }
+
+    fn validate_schema(
+        &self,
+        schema: &'s Value,
+        schema_path: &str,
+        refs: &[&'s Value],
+        context: &mut Context<'static>,
+        received: &Value,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let mut validation = Validation {
+            validator: *self,
+            schema_path,
+            refs,
+            context,
+            violations,
+        };
+
+        match schema {
+            Value::Bool(true) => {}
+            Value::Bool(false) => validation.fail("", "expected no value, as the schema is false"),
+            Value::Object(schema) => validation.validate_object_schema(schema, received),
+            _ => validation.fail("", "invalid schema, expected an object or boolean"),
+        }
+    }
+
+    /// Finds the schema a local `$ref` points to, and the path to it.
+    fn resolve_ref(&self, reference: &str) -> Result<(&'s Value, String), String> {
+        let Some(fragment) = reference.strip_prefix('#') else {
+            return Err(format!(
+                "$ref '{reference}' is not supported, only local references starting with '#' are"
+            ));
+        };
+
+        if fragment.is_empty() || fragment.starts_with('/') {
+            let segments = parse_json_pointer(fragment)
+                .ok_or_else(|| format!("$ref '{reference}' is not a valid Json Pointer"))?;
+
+            let mut current = self.root;
+            for segment in &segments {
+                let found = match current {
+                    Value::Object(object) => object.get(segment),
+                    Value::Array(array) => {
+                        parse_json_pointer_index(segment).and_then(|index| array.get(index))
+                    }
+                    _ => None,
+                };
+                current = found.ok_or_else(|| format!("$ref '{reference}' was not found"))?;
+            }
+
+            return Ok((current, reference.to_string()));
+        }
+
+        find_anchor(self.root, fragment)
+            .map(|schema| (schema, reference.to_string()))
+            .ok_or_else(|| format!("$ref '{reference}' was not found"))
+    }
+
+    /// Returns the regex compiled up front, falling back to compiling it now.
+    fn pattern_regex(&self, pattern: &str) -> Result<Regex, RegexError> {
+        self.patterns
+            .get(pattern)
+            .cloned()
+            .unwrap_or_else(|| Regex::new(pattern))
+    }
+}
+
+struct Validation<'v, 's> {
+    validator: SchemaValidator<'s>,
+    schema_path: &'v str,
+
+    /// The schemas being checked against the current value, through `$ref`s.
+    /// This is reset when moving into a child value.
+    refs: &'v [&'s Value],
+    context: &'v mut Context<'static>,
+    violations: &'v mut Vec<SchemaViolation>,
+}
+
+impl<'s> Validation<'_, 's> {
+    fn fail<S>(&mut self, keyword: &str, message: S)
+    where
+        S: AsRef<str>,
+    {
+        let schema_path = if keyword.is_empty() {
+            self.schema_path.to_string()
+        } else {
+            format!("{}/{keyword}", self.schema_path)
+        };
+
+        self.violations.push(SchemaViolation {
+            context: self.context.clone(),
+            message: format!("{},\n    schema path '{schema_path}'", message.as_ref()),
+        });
+    }
+
+    fn validate_sub_schema(&mut self, schema: &'s Value, schema_path: &str, received: &Value) {
+        self.validator.validate_schema(
+            schema,
+            schema_path,
+            self.refs,
+            self.context,
+            received,
+            self.violations,
+        );
+    }
+
+    fn validate_child<P>(&mut self, path: P, schema: &'s Value, schema_path: &str, received: &Value)
+    where
+        P: Into<ContextPathPart<'static>>,
+    {
+        self.context.push(path);
+        self.validator.validate_schema(
+            schema,
+            schema_path,
+            &[schema],
+            self.context,
+            received,
+            self.violations,
+        );
+        self.context.pop();
+    }
+
+    fn is_valid(&mut self, schema: &'s Value, schema_path: &str, received: &Value) -> bool {
+        self.validator
+            .is_valid(schema, schema_path, self.refs, self.context, received)
+    }
+
+    fn is_child_valid<P>(
+        &mut self,
+        path: P,
+        schema: &'s Value,
+        schema_path: &str,
+        received: &Value,
+    ) -> bool
+    where
+        P: Into<ContextPathPart<'static>>,
+    {
+        self.context.push(path);
+        let is_valid =
+            self.validator
+                .is_valid(schema, schema_path, &[schema], self.context, received);
+        self.context.pop();
+        is_valid
+    }
+
+    fn validate_object_schema(&mut self, schema: &'s Map<String, Value>, received: &Value) {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            self.validate_ref(reference, received);
+        }
+
+        self.validate_type(schema, received);
+        self.validate_enum_and_const(schema, received);
+
+        match received {
+            Value::Number(_) => self.validate_number(schema, received),
+            Value::String(received_string) => self.validate_string(schema, received_string),
+            Value::Array(received_array) => self.validate_array(schema, received_array),
+            Value::Object(received_object) => self.validate_object(schema, received_object),
+            _ => {}
+        }
+
+        self.validate_applicators(schema, received);
+    }
+
+    fn validate_ref(&mut self, reference: &str, received: &Value) {
+        let (schema, schema_path) = match self.validator.resolve_ref(reference) {
+            Ok(resolved) => resolved,
+            Err(message) => {
+                self.fail("$ref", message);
+                return;
+            }
+        };
+
+        // Following a schema already being checked against this same value can never finish.
+        if self.refs.iter().any(|other| std::ptr::eq(*other, schema)) {
+            self.fail(
+                "$ref",
+                format!("$ref '{reference}' loops back to a schema already checking this value, it is an infinite loop"),
+            );
+            return;
+        }
+
+        let refs = [self.refs, &[schema]].concat();
+        self.validator.validate_schema(
+            schema,
+            &schema_path,
+            &refs,
+            self.context,
+            received,
+            self.violations,
+        );
+    }
+
+    fn validate_type(&mut self, schema: &Map<String, Value>, received: &Value) {
+        let expected_types = match schema.get("type") {
+            Some(Value::String(expected_type)) => vec![expected_type.as_str()],
+            Some(Value::Array(expected_types)) => {
+                expected_types.iter().filter_map(Value::as_str).collect()
+            }
+            _ => return,
+        };
+
+        let is_matching = expected_types
+            .iter()
+            .any(|expected_type| is_type(expected_type, received));
+        if !is_matching {
+            self.fail(
+                "type",
+                format!(
+                    "expected type {},\n    received {}",
+                    expected_types.join(" or "),
+                    ValueTypeObject::from(received.clone())
+                ),
+            );
+        }
+    }
+
+    fn validate_enum_and_const(&mut self, schema: &Map<String, Value>, received: &Value) {
+        if let Some(Value::Array(expected_values)) = schema.get("enum") {
+            let is_found = expected_values
+                .iter()
+                .any(|expected| is_json_equal(expected, received));
+            if !is_found {
+                self.fail(
+                    "enum",
+                    format!(
+                        "expected one of {},\n    received {}",
+                        ArrayObject::from(expected_values.clone()),
+                        ValueObject::from(received.clone())
+                    ),
+                );
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if !is_json_equal(expected, received) {
+                self.fail(
+                    "const",
+                    format!(
+                        "expected {},\n    received {}",
+                        ValueObject::from(expected.clone()),
+                        ValueObject::from(received.clone())
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_number(&mut self, schema: &Map<String, Value>, received: &Value) {
+        let Some(received_number) = received.as_f64() else {
+            return;
+        };
+        let received_display = ValueObject::from(received.clone());
+
+        let bounds: [NumberBound; 4] = [
+            ("minimum", "at least", |received, bound| received >= bound),
+            ("exclusiveMinimum", "greater than", |received, bound| {
+                received > bound
+            }),
+            ("maximum", "at most", |received, bound| received <= bound),
+            ("exclusiveMaximum", "less than", |received, bound| {
+                received < bound
+            }),
+        ];
+        for (keyword, description, is_within) in bounds {
+            let Some(bound) = schema.get(keyword).filter(|bound| bound.is_number()) else {
+                continue;
+            };
+
+            if !is_within(received_number, bound.as_f64().unwrap_or_default()) {
+                self.fail(
+                    keyword,
+                    format!(
+                        "expected number {description} {},\n    received {received_display}",
+                        ValueObject::from(bound.clone())
+                    ),
+                );
+            }
+        }
+
+        if let Some(multiple_of) = schema.get("multipleOf").filter(|n| n.is_number()) {
+            if !is_multiple_of(received_number, multiple_of.as_f64().unwrap_or_default()) {
+                self.fail(
+                    "multipleOf",
+                    format!(
+                        "expected a multiple of {},\n    received {received_display}",
+                        ValueObject::from(multiple_of.clone())
+                    ),
+                );
+            }
+        }
+    }
+
+    fn validate_string(&mut self, schema: &Map<String, Value>, received: &str) {
+        let len = received.chars().count();
+
+        if let Some(min_len) = schema.get("minLength").and_then(Value::as_u64) {
+            if (len as u64) < min_len {
+                self.fail(
+                    "minLength",
+                    format!("expected string with at least {min_len} characters,\n    received {len} characters {received:?}"),
+                );
+            }
+        }
+
+        if let Some(max_len) = schema.get("maxLength").and_then(Value::as_u64) {
+            if (len as u64) > max_len {
+                self.fail(
+                    "maxLength",
+                    format!("expected string with at most {max_len} characters,\n    received {len} characters {received:?}"),
+                );
+            }
+        }
+
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            match self.validator.pattern_regex(pattern) {
+                Ok(regex) if !regex.is_match(received) => {
+                    self.fail(
+                        "pattern",
+                        format!("expected string matching pattern '{pattern}',\n    received {received:?}"),
+                    );
+                }
+                Ok(_) => {}
+                Err(error) => self.fail(
+                    "pattern",
+                    format!("invalid pattern '{pattern}' in schema, {error}"),
+                ),
+            }
+        }
+    }
+
+    fn validate_array(&mut self, schema: &'s Map<String, Value>, received: &[Value]) {
+        let len = received.len() as u64;
+
+        if let Some(min_items) = schema.get("minItems").and_then(Value::as_u64) {
+            if len < min_items {
+                self.fail(
+                    "minItems",
+                    format!(
+                        "expected array with at least {min_items} items,\n    received {len} items"
+                    ),
+                );
+            }
+        }
+
+        if let Some(max_items) = schema.get("maxItems").and_then(Value::as_u64) {
+            if len > max_items {
+                self.fail(
+                    "maxItems",
+                    format!(
+                        "expected array with at most {max_items} items,\n    received {len} items"
+                    ),
+                );
+            }
+        }
+
+        if schema.get("uniqueItems") == Some(&Value::Bool(true)) {
+            self.validate_unique_items(received);
+        }
+
+        let prefix_items = match schema.get("prefixItems") {
+            Some(Value::Array(prefix_items)) => prefix_items.as_slice(),
+            _ => &[],
+        };
+        for (index, (item_schema, item)) in prefix_items.iter().zip(received).enumerate() {
+            let schema_path = format!("{}/prefixItems/{index}", self.schema_path);
+            self.validate_child(index, item_schema, &schema_path, item);
+        }
+
+        if let Some(items_schema) = schema.get("items") {
+            let schema_path = format!("{}/items", self.schema_path);
+            for (index, item) in received.iter().enumerate().skip(prefix_items.len()) {
+                self.validate_child(index, items_schema, &schema_path, item);
+            }
+        }
+
+        if let Some(contains_schema) = schema.get("contains") {
+            self.validate_contains(schema, contains_schema, received);
+        }
+    }
+
+    fn validate_unique_items(&mut self, received: &[Value]) {
+        for (index, item) in received.iter().enumerate() {
+            let duplicate_index = received[..index]
+                .iter()
+                .position(|other| is_json_equal(other, item));
+
+            if let Some(duplicate_index) = duplicate_index {
+                self.context.push(index);
+                self.fail(
+                    "uniqueItems",
+                    format!("expected unique items, this is a duplicate of index {duplicate_index},\n    received {}", ValueObject::from(item.clone())),
+                );
+                self.context.pop();
+            }
+        }
+    }
+
+    fn validate_contains(
+        &mut self,
+        schema: &Map<String, Value>,
+        contains_schema: &'s Value,
+        received: &[Value],
+    ) {
+        let schema_path = format!("{}/contains", self.schema_path);
+        let mut count = 0;
+        for (index, item) in received.iter().enumerate() {
+            if self.is_child_valid(index, contains_schema, &schema_path, item) {
+                count += 1;
+            }
+        }
+
+        let min_contains = schema
+            .get("minContains")
+            .and_then(Value::as_u64)
+            .unwrap_or(1);
+        if count < min_contains {
+            let keyword = if schema.contains_key("minContains") {
+                "minContains"
+            } else {
+                "contains"
+            };
+            self.fail(
+                keyword,
+                format!("expected array to contain at least {min_contains} matching items,\n    found {count}"),
+            );
+        }
+
+        if let Some(max_contains) = schema.get("maxContains").and_then(Value::as_u64) {
+            if count > max_contains {
+                self.fail(
+                    "maxContains",
+                    format!("expected array to contain at most {max_contains} matching items,\n    found {count}"),
+                );
+            }
+        }
+    }
+
+    fn validate_object(&mut self, schema: &'s Map<String, Value>, received: &Map<String, Value>) {
+        let len = received.len() as u64;
+
+        if let Some(min_properties) = schema.get("minProperties").and_then(Value::as_u64) {
+            if len < min_properties {
+                self.fail(
+                    "minProperties",
+                    format!("expected object with at least {min_properties} fields,\n    received {len} fields"),
+                );
+            }
+        }
+
+        if let Some(max_properties) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if len > max_properties {
+                self.fail(
+                    "maxProperties",
+                    format!("expected object with at most {max_properties} fields,\n    received {len} fields"),
+                );
+            }
+        }
+
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for key in required.iter().filter_map(Value::as_str) {
+                if !received.contains_key(key) {
+                    self.fail(
+                        "required",
+                        format!("expected field '{key}',\n    but it was not found"),
+                    );
+                }
+            }
+        }
+
+        if let Some(Value::Object(dependent_required)) = schema.get("dependentRequired") {
+            for (present_key, required) in dependent_required {
+                if !received.contains_key(present_key) {
+                    continue;
+                }
+
+                for key in required
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                {
+                    if !received.contains_key(key) {
+                        self.fail(
+                            "dependentRequired",
+                            format!("expected field '{key}', as field '{present_key}' is present,\n    but it was not found"),
+                        );
+                    }
+                }
+            }
+        }
+
+        self.validate_object_fields(schema, received);
+
+        if let Some(property_names_schema) = schema.get("propertyNames") {
+            let schema_path = format!("{}/propertyNames", self.schema_path);
+            for key in received.keys() {
+                let key_value = Value::String(key.clone());
+                self.validate_child(key.clone(), property_names_schema, &schema_path, &key_value);
+            }
+        }
+
+        if let Some(Value::Object(dependent_schemas)) = schema.get("dependentSchemas") {
+            for (present_key, dependent_schema) in dependent_schemas {
+                if received.contains_key(present_key) {
+                    let schema_path =
+                        format!("{}/dependentSchemas/{present_key}", self.schema_path);
+                    let received_value = Value::Object(received.clone());
+                    self.validate_sub_schema(dependent_schema, &schema_path, &received_value);
+                }
+            }
+        }
+    }
+
+    /// Validates `properties`, `patternProperties`, and `additionalProperties`.
+    fn validate_object_fields(
+        &mut self,
+        schema: &'s Map<String, Value>,
+        received: &Map<String, Value>,
+    ) {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let mut pattern_properties = vec![];
+        if let Some(Value::Object(patterns)) = schema.get("patternProperties") {
+            for (pattern, pattern_schema) in patterns {
+                match self.validator.pattern_regex(pattern) {
+                    Ok(regex) => pattern_properties.push((pattern, regex, pattern_schema)),
+                    Err(error) => self.fail(
+                        "patternProperties",
+                        format!("invalid pattern '{pattern}' in schema, {error}"),
+                    ),
+                }
+            }
+        }
+        let additional_properties = schema.get("additionalProperties");
+
+        for (key, value) in received {
+            let mut is_evaluated = false;
+
+            if let Some(property_schema) = properties.and_then(|properties| properties.get(key)) {
+                let schema_path = format!(
+                    "{}/properties/{}",
+                    self.schema_path,
+                    escape_json_pointer(key)
+                );
+                self.validate_child(key.clone(), property_schema, &schema_path, value);
+                is_evaluated = true;
+            }
+
+            for (pattern, regex, pattern_schema) in &pattern_properties {
+                if regex.is_match(key) {
+                    let schema_path = format!(
+                        "{}/patternProperties/{}",
+                        self.schema_path,
+                        escape_json_pointer(pattern)
+                    );
+                    self.validate_child(key.clone(), pattern_schema, &schema_path, value);
+                    is_evaluated = true;
+                }
+            }
+
+            match additional_properties {
+                _ if is_evaluated => {}
+                None => {}
+                Some(Value::Bool(false)) => {
+                    self.context.push(key.clone());
+                    self.fail(
+                        "additionalProperties",
+                        "unexpected field, as additionalProperties is false",
+                    );
+                    self.context.pop();
+                }
+                Some(additional_schema) => {
+                    let schema_path = format!("{}/additionalProperties", self.schema_path);
+                    self.validate_child(key.clone(), additional_schema, &schema_path, value);
+                }
+            }
+        }
+    }
+
+    fn validate_applicators(&mut self, schema: &'s Map<String, Value>, received: &Value) {
+        if let Some(Value::Array(all_of)) = schema.get("allOf") {
+            for (index, sub_schema) in all_of.iter().enumerate() {
+                let schema_path = format!("{}/allOf/{index}", self.schema_path);
+                self.validate_sub_schema(sub_schema, &schema_path, received);
+            }
+        }
+
+        if let Some(Value::Array(any_of)) = schema.get("anyOf") {
+            let is_any_valid = any_of.iter().enumerate().any(|(index, sub_schema)| {
+                let schema_path = format!("{}/anyOf/{index}", self.schema_path);
+                self.is_valid(sub_schema, &schema_path, received)
+            });
+            if !is_any_valid {
+                self.fail(
+                    "anyOf",
+                    format!(
+                        "expected value to match any of the {} anyOf schemas,\n    but none did",
+                        any_of.len()
+                    ),
+                );
+            }
+        }
+
+        if let Some(Value::Array(one_of)) = schema.get("oneOf") {
+            let valid_count = one_of
+                .iter()
+                .enumerate()
+                .filter(|(index, sub_schema)| {
+                    let schema_path = format!("{}/oneOf/{index}", self.schema_path);
+                    self.is_valid(sub_schema, &schema_path, received)
+                })
+                .count();
+            if valid_count != 1 {
+                self.fail(
+                    "oneOf",
+                    format!("expected value to match exactly one of the {} oneOf schemas,\n    but it matched {valid_count}", one_of.len()),
+                );
+            }
+        }
+
+        if let Some(not_schema) = schema.get("not") {
+            let schema_path = format!("{}/not", self.schema_path);
+            if self.is_valid(not_schema, &schema_path, received) {
+                self.fail(
+                    "not",
+                    format!(
+                        "expected value to not match the schema,\n    received {}",
+                        ValueObject::from(received.clone())
+                    ),
+                );
+            }
+        }
+
+        if let Some(if_schema) = schema.get("if") {
+            let schema_path = format!("{}/if", self.schema_path);
+            let (branch, branch_schema) = if self.is_valid(if_schema, &schema_path, received) {
+                ("then", schema.get("then"))
+            } else {
+                ("else", schema.get("else"))
+            };
+
+            if let Some(branch_schema) = branch_schema {
+                let schema_path = format!("{}/{branch}", self.schema_path);
+                self.validate_sub_schema(branch_schema, &schema_path, received);
+            }
+        }
+    }
+}
+
+fn is_type(expected_type: &str, received: &Value) -> bool {
+    match expected_type {
+        "null" => received.is_null(),
+        "boolean" => received.is_boolean(),
+        "object" => received.is_object(),
+        "array" => received.is_array(),
+        "string" => received.is_string(),
+        "number" => received.is_number(),
+        "integer" => {
+            received.is_i64()
+                || received.is_u64()
+                || received.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => false,
+    }
+}
+
+/// Json Schema equality, where numbers are equal by value. i.e. `1` equals `1.0`.
+fn is_json_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => {
+            if let (Some(left), Some(right)) = (left.as_i64(), right.as_i64()) {
+                return left == right;
+            }
+            if let (Some(left), Some(right)) = (left.as_u64(), right.as_u64()) {
+                return left == right;
+            }
+
+            left.as_f64() == right.as_f64()
+        }
+        (Value::Array(left), Value::Array(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right)
+                    .all(|(left, right)| is_json_equal(left, right))
+        }
+        (Value::Object(left), Value::Object(right)) => {
+            left.len() == right.len()
+                && left.iter().all(|(key, left)| {
+                    right
+                        .get(key)
+                        .is_some_and(|right| is_json_equal(left, right))
+                })
+        }
+        (left, right) => left == right,
+    }
+}
+
+fn is_multiple_of(received: f64, multiple_of: f64) -> bool {
+    if multiple_of == 0.0 {
+        return false;
+    }
+
+    let quotient = received / multiple_of;
+    (quotient - quotient.round()).abs() <= f64::EPSILON * quotient.abs().max(1.0)
+}
+
+fn find_anchor<'s>(schema: &'s Value, anchor: &str) -> Option<&'s Value> {
+    match schema {
+        Value::Object(object) => {
+            if object.get("$anchor").and_then(Value::as_str) == Some(anchor) {
+                return Some(schema);
+            }
+
+            object.values().find_map(|value| find_anchor(value, anchor))
+        }
+        Value::Array(array) => array.iter().find_map(|value| find_anchor(value, anchor)),
+        _ => None,
+    }
+}
+
+/// Compiles every `pattern` and `patternProperties` regex found within the schema.
+pub fn compile_schema_patterns(schema: &Value) -> SchemaPatterns {
+    let mut patterns = SchemaPatterns::new();
+    collect_schema_patterns(schema, &mut patterns);
+    patterns
+}
+
+fn collect_schema_patterns(schema: &Value, patterns: &mut SchemaPatterns) {
+    match schema {
+        Value::Object(object) => {
+            if let Some(pattern) = object.get("pattern").and_then(Value::as_str) {
+                add_schema_pattern(pattern, patterns);
+            }
+            if let Some(Value::Object(pattern_properties)) = object.get("patternProperties") {
+                for pattern in pattern_properties.keys() {
+                    add_schema_pattern(pattern, patterns);
+                }
+            }
+
+            for (key, value) in object {
+                if !DATA_KEYWORDS.contains(&key.as_str()) {
+                    collect_schema_patterns(value, patterns);
+                }
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                collect_schema_patterns(value, patterns);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn add_schema_pattern(pattern: &str, patterns: &mut SchemaPatterns) {
+    if !patterns.contains_key(pattern) {
+        patterns.insert(pattern.to_string(), Regex::new(pattern));
+    }
+}
+
+fn escape_json_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
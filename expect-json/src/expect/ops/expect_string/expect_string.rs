@@ -4,9 +4,15 @@ use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use crate::internals::ErrorCollector;
+use crate::internals::utils::RegexPattern;
+use crate::internals::utils::WildcardPattern;
+use crate::internals::utils::WildcardSyntax;
+use serde_json::Value;
 
 #[expect_op(internal, name = "string")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectString {
     sub_ops: Vec<ExpectStringSubOp>,
 }
@@ -26,379 +32,1503 @@ impl ExpectString {
         self
     }
 
+    /// Expect a string with this many bytes, when encoded as UTF-8.
+    ///
+    /// See [`Self::char_len`] and [`Self::grapheme_len`] to count characters instead.
     pub fn len(mut self, len: usize) -> Self {
         self.sub_ops.push(ExpectStringSubOp::Len(len));
         self
     }
 
-    pub fn min_len(mut self, min_len: usize) -> Self {
-        self.sub_ops.push(ExpectStringSubOp::MinLen(min_len));
-        self
-    }
+    /// Expect a string with at least this many bytes, when encoded as UTF-8.
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::MinLen(min_len));
+        self
+    }
+
+    /// Expect a string with at most this many bytes, when encoded as UTF-8.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::MaxLen(max_len));
+        self
+    }
+
+    ///
+    /// Expect a string with this many Unicode scalar values, as counted by `str::chars`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         // Passes for "山田太郎", which is 12 bytes
+    ///         "name": expect_json::string().max_char_len(10),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn char_len(mut self, len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::CharLen(len));
+        self
+    }
+
+    /// Expect a string with at least this many Unicode scalar values.
+    pub fn min_char_len(mut self, min_len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::MinCharLen(min_len));
+        self
+    }
+
+    /// Expect a string with at most this many Unicode scalar values.
+    pub fn max_char_len(mut self, max_len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::MaxCharLen(max_len));
+        self
+    }
+
+    ///
+    /// Expect a string with this many extended grapheme clusters,
+    /// which are the characters a user would see.
+    ///
+    /// For example "👍🏽" is one grapheme, made of two chars, and eight bytes.
+    ///
+    pub fn grapheme_len(mut self, len: usize) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::GraphemeLen(len));
+        self
+    }
+
+    /// Expect a string with at least this many extended grapheme clusters.
+    pub fn min_grapheme_len(mut self, min_len: usize) -> Self {
+        self.sub_ops
+            .push(ExpectStringSubOp::MinGraphemeLen(min_len));
+        self
+    }
+
+    /// Expect a string with at most this many extended grapheme clusters.
+    pub fn max_grapheme_len(mut self, max_len: usize) -> Self {
+        self.sub_ops
+            .push(ExpectStringSubOp::MaxGraphemeLen(max_len));
+        self
+    }
+
+    ///
+    /// Expect a string containing a subset of the string given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": expect_json::string().contains("apples"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn contains<S>(mut self, expected_sub_string: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sub_ops
+            .push(ExpectStringSubOp::Contains(expected_sub_string.into()));
+        self
+    }
+
+    ///
+    /// Expect a string which does not contain the string given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": expect_json::string().not_contains("apples"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn not_contains<S>(mut self, expected_sub_string: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sub_ops
+            .push(ExpectStringSubOp::NotContains(expected_sub_string.into()));
+        self
+    }
+
+    ///
+    /// Expect a string matching the regex given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "email": expect_json::string().matches_regex(r#"\w+@(?:\w+\.)+\w+"#),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn matches_regex<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let pattern = into_valid_regex_pattern("matches_regex", pattern);
+        self.sub_ops.push(ExpectStringSubOp::MatchesRegex(pattern));
+        self
+    }
+
+    ///
+    /// Expect a string matching the regex given,
+    /// where the named capture groups match the expected object.
+    ///
+    /// The captures are compared as an object of the group names to the text they matched.
+    /// Only the groups listed in the expected object are compared, so others can be left out.
+    ///
+    /// Captures are strings, unless the expected value is a number,
+    /// or an op for numbers such as [`crate::expect::integer`].
+    /// Then numeric text is compared as a number.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/order")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "order_number": expect_json::string().matches_regex_captures(
+    ///             r"^ORD-(?<year>\d{4})-(?<id>\d+)$",
+    ///             json!({
+    ///                 "year": expect_json::integer().greater_than_equal(2020),
+    ///                 "id": expect_json::integer().greater_than(0),
+    ///             }),
+    ///         ),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn matches_regex_captures<S, V>(mut self, pattern: S, expected: V) -> Self
+    where
+        S: Into<String>,
+        V: Into<Value>,
+    {
+        let pattern = into_valid_regex_pattern("matches_regex_captures", pattern);
+        self.sub_ops.push(ExpectStringSubOp::MatchesRegexCaptures {
+            pattern,
+            expected: expected.into(),
+        });
+        self
+    }
+
+    ///
+    /// Expect a string where every match of the regex given has named capture groups
+    /// matching the expected object. At least one match is required.
+    ///
+    /// Captures are compared in the same way as [`Self::matches_regex_captures`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/order")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "tags": expect_json::string().matches_regex_all(
+    ///             r"#(?<tag>\w+)",
+    ///             json!({ "tag": expect_json::string().lowercase() }),
+    ///         ),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn matches_regex_all<S, V>(mut self, pattern: S, expected: V) -> Self
+    where
+        S: Into<String>,
+        V: Into<Value>,
+    {
+        let pattern = into_valid_regex_pattern("matches_regex_all", pattern);
+        self.sub_ops.push(ExpectStringSubOp::MatchesRegexAll {
+            pattern,
+            expected: expected.into(),
+        });
+        self
+    }
+
+    ///
+    /// Expect the whole string to match the glob pattern given.
+    ///
+    /// `*` matches any text, including none, and `?` matches any one character.
+    /// Use a backslash to match these literally, i.e. `\*`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "username": expect_json::string().glob("user-*-????"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn glob<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let pattern = into_valid_wildcard_pattern(WildcardSyntax::Glob, pattern);
+        self.sub_ops.push(ExpectStringSubOp::Glob(pattern));
+        self
+    }
+
+    ///
+    /// Expect the whole string to match the SQL `LIKE` pattern given.
+    ///
+    /// `%` matches any text, including none, and `_` matches any one character.
+    /// Use a backslash to match these literally, i.e. `\%`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "email": expect_json::string().like("%@%.com"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn like<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let pattern = into_valid_wildcard_pattern(WildcardSyntax::Like, pattern);
+        self.sub_ops.push(ExpectStringSubOp::Like(pattern));
+        self
+    }
+
+    /// Expect a string with exactly this many non-overlapping matches of the regex given.
+    pub fn matches_regex_count<S>(mut self, pattern: S, count: usize) -> Self
+    where
+        S: Into<String>,
+    {
+        let pattern = into_valid_regex_pattern("matches_regex_count", pattern);
+        self.sub_ops
+            .push(ExpectStringSubOp::MatchesRegexCount { pattern, count });
+        self
+    }
+
+    ///
+    /// Expect a string starting with the prefix given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "avatar_url": expect_json::string().starts_with("https://"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn starts_with<S>(mut self, prefix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sub_ops
+            .push(ExpectStringSubOp::StartsWith(prefix.into()));
+        self
+    }
+
+    /// Expect a string ending with the suffix given.
+    pub fn ends_with<S>(mut self, suffix: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sub_ops
+            .push(ExpectStringSubOp::EndsWith(suffix.into()));
+        self
+    }
+
+    /// Expect a string equal to the one given, ignoring differences in case.
+    pub fn eq_ignore_case<S>(mut self, expected: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sub_ops
+            .push(ExpectStringSubOp::EqIgnoreCase(expected.into()));
+        self
+    }
+
+    /// Expect a string without any leading or trailing whitespace.
+    pub fn trimmed(mut self) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::Trimmed);
+        self
+    }
+
+    /// Expect a string made up of only ASCII characters.
+    pub fn ascii_only(mut self) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::AsciiOnly);
+        self
+    }
+
+    /// Expect a string without any uppercase characters.
+    pub fn lowercase(mut self) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::Lowercase);
+        self
+    }
+
+    /// Expect a string without any lowercase characters.
+    pub fn uppercase(mut self) -> Self {
+        self.sub_ops.push(ExpectStringSubOp::Uppercase);
+        self
+    }
+
+    ///
+    /// Expect a string which is exactly one of the values given.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "status": expect_json::string().one_of(["pending", "active", "closed"]),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    pub fn one_of<I, S>(mut self, expected_values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let expected_values = expected_values.into_iter().map(Into::into).collect();
+        self.sub_ops.push(ExpectStringSubOp::OneOf(expected_values));
+        self
+    }
+}
+
+fn into_valid_regex_pattern<S>(method_name: &str, pattern: S) -> RegexPattern
+where
+    S: Into<String>,
+{
+    let pattern = pattern.into();
+    RegexPattern::new(pattern.clone()).unwrap_or_else(|error| {
+        panic!(
+            "string().{method_name}() expected a valid regex pattern. Received: '{pattern}', {error}"
+        )
+    })
+}
+
+fn into_valid_wildcard_pattern<S>(syntax: WildcardSyntax, pattern: S) -> String
+where
+    S: Into<String>,
+{
+    let pattern = pattern.into();
+    if let Err(error) = WildcardPattern::parse(syntax, &pattern) {
+        panic!(
+            "string().{syntax}() expected a valid {syntax} pattern. Received: '{pattern}', {error}"
+        );
+    }
+
+    pattern
+}
+
+impl ExpectOp for ExpectString {
+    fn on_string(&self, context: &mut Context, received: &str) -> ExpectOpResult<()> {
+        let mut errors = ErrorCollector::new(context);
+        for sub_op in &self.sub_ops {
+            errors.check(sub_op.on_string(self, context, received))?;
+        }
+
+        errors.into_result()
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::String]
+    }
+}
+
+#[cfg(test)]
+mod test_contains {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_be_equal_for_identical_strings() {
+        let left = json!("1, 2, 3");
+        let right = json!(expect::string().contains("1, 2, 3"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_be_equal_for_partial_matches_in_middle() {
+        let left = json!("0, 1, 2, 3, 4");
+        let right = json!(expect::string().contains("1, 2, 3"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_be_ok_for_empty_contains() {
+        let left = json!("0, 1, 2, 3, 4, 5");
+        let right = json!(expect::string().contains(""));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_error_for_totall_different_values() {
+        let left = json!("1, 2, 3");
+        let right = json!(expect::string().contains("a, b, c"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string at root does not contain expected value:
+    expected string to contain "a, b, c", but it was not found.
+    received "1, 2, 3""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_not_contains {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_be_ok_for_totally_different_values() {
+        let left = json!("1, 2, 3");
+        let right = json!(expect::string().not_contains("a, b, c"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_error_for_identical_strings() {
+        let left = json!("1, 2, 3");
+        let right = json!(expect::string().not_contains("1, 2, 3"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string at root contains value was expecting to not be there:
+    expected string to not contain "1, 2, 3", but it was found.
+    received "1, 2, 3""#
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_partial_matches_in_middle() {
+        let left = json!("0, 1, 2, 3, 4");
+        let right = json!(expect::string().not_contains("1, 2, 3"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string at root contains value was expecting to not be there:
+    expected string to not contain "1, 2, 3", but it was found.
+    received "0, 1, 2, 3, 4""#
+        );
+    }
+
+    #[test]
+    fn it_should_error_for_empty_not_contains() {
+        let left = json!("0, 1, 2, 3, 4, 5");
+        let right = json!(expect::string().not_contains(""));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string at root contains value was expecting to not be there:
+    expected string to not contain "", but it was found.
+    received "0, 1, 2, 3, 4, 5""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_empty {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_is_empty() {
+        let left = json!("");
+        let right = json!(expect::string().empty());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_not_empty() {
+        let left = json!("🦊");
+        let right = json!(expect::string().empty());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected empty string
+    received "🦊""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_not_empty {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_is_not_empty() {
+        let left = json!("🦊");
+        let right = json!(expect::string().not_empty());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_empty() {
+        let left = json!("");
+        let right = json!(expect::string().not_empty());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected non-empty string
+    received """#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_has_same_number_of_characters() {
+        let left = json!("123");
+        let right = json!(expect::string().len(3));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_too_short() {
+        let left = json!("12");
+        let right = json!(expect::string().len(3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to have 3 bytes, but it has 2,
+    received "12""#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_too_long() {
+        let left = json!("1234");
+        let right = json!(expect::string().len(3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to have 3 bytes, but it has 4,
+    received "1234""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_min_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_has_exactly_enough_characters() {
+        let left = json!("123");
+        let right = json!(expect::string().min_len(3));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_string_has_more_than_enough_characters() {
+        let left = json!("12345");
+        let right = json!(expect::string().min_len(3));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_too_short() {
+        let left = json!("12");
+        let right = json!(expect::string().min_len(3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to have at least 3 bytes, but it has 2,
+    received "12""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_max_len {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_has_exactly_enough_characters() {
+        let left = json!("123");
+        let right = json!(expect::string().max_len(3));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_string_has_less_than_enough_characters() {
+        let left = json!("12");
+        let right = json!(expect::string().max_len(5));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_is_too_long() {
+        let left = json!("🦊🦊🦊🦊🦊🦊");
+        let right = json!(expect::string().max_len(3));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to have at most 3 bytes, but it has 24,
+    received "🦊🦊🦊🦊🦊🦊""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_matches_regex {
+    use crate::expect;
+    use crate::expect::ops::ExpectString;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_matches_regex() {
+        let left = json!("abc123xyz");
+        let right = json!(expect::string().matches_regex(r"^[a-z]+[0-9]+[a-z]+$"));
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_string_does_not_match_regex() {
+        let left = json!("abcxyz");
+        let right = json!(expect::string().matches_regex(r"^[a-z]+[0-9]+[a-z]+$"));
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string error at root, regex did not match:
+    expected string to match regex pattern '^[a-z]+[0-9]+[a-z]+$',
+    received "abcxyz""#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_regex_is_invalid() {
+        let _ = expect::string().matches_regex(r"([a-z]+");
+    }
+
+    #[test]
+    fn it_should_fail_when_a_deserialised_regex_is_invalid() {
+        let left = json!("abc123xyz");
+        let expect_op: ExpectString =
+            serde_json::from_value(json!({ "sub_ops": [{ "MatchesRegex": "([a-z]+" }] })).unwrap();
+        let right = json!(expect_op);
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        // For robustness, we don't specify the error message coming from the regex crate.
+        assert!(
+            output.starts_with(r#"Json expect::string() error at root:"#),
+            "Unexpected error output: {output:#?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_matches_regex_captures {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_captures_match() {
+        let left = json!({ "order": "ORD-2025-0042" });
+        let right = json!({
+            "order": expect::string().matches_regex_captures(
+                r"^ORD-(?<year>\d{4})-(?<id>\d+)$",
+                json!({
+                    "year": expect::integer().in_range(2020..=2030),
+                    "id": "0042",
+                }),
+            ),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_a_capture_does_not_match() {
+        let left = json!({ "order": "ORD-2019-42" });
+        let right = json!({
+            "order": expect::string().matches_regex_captures(
+                r"^ORD-(?<year>\d{4})-(?<id>\d+)$",
+                json!({
+                    "year": 2025,
+                    "id": "42",
+                }),
+            ),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json integers at root.order<captures>.year are not equal:
+    expected 2025
+    received 2019
+    received string "ORD-2019-42", matched with regex pattern '^ORD-(?<year>\d{4})-(?<id>\d+)$'"#
+        );
+    }
+
+    #[test]
+    fn it_should_only_compare_the_groups_listed() {
+        let left = json!("ORD-2025-42");
+        let right = json!(expect::string().matches_regex_captures(
+            r"^ORD-(?<year>\d{4})-(?<id>\d+)$",
+            json!({ "year": expect::integer() }),
+        ));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_keep_captures_as_strings_when_strings_are_expected() {
+        let left = json!("ORD-2025-0042");
+        let right = json!(expect::string().matches_regex_captures(
+            r"^ORD-(?<year>\d{4})-(?<id>\d+)$",
+            json!({ "year": "2025", "id": expect::string().len(4) }),
+        ));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_the_regex_does_not_match() {
+        let left = json!("order-2019");
+        let right =
+            json!(expect::string().matches_regex_captures(r"^ORD-(?<year>\d{4})$", json!({})));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string error at root, regex did not match:
+    expected string to match regex pattern '^ORD-(?<year>\d{4})$',
+    received "order-2019""#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_regex_is_invalid() {
+        let _ = expect::string().matches_regex_captures(r"(?<year>", json!({}));
+    }
+}
+
+#[cfg(test)]
+mod test_matches_regex_all {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_all_matches_have_matching_captures() {
+        let left = json!("#rust #json #testing");
+        let right = json!(expect::string().matches_regex_all(
+            r"#(?<tag>\w+)",
+            json!({ "tag": expect::string().lowercase() }),
+        ));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_one_match_has_different_captures() {
+        let left = json!("#rust #Json");
+        let right = json!(expect::string().matches_regex_all(
+            r"#(?<tag>\w+)",
+            json!({ "tag": expect::string().lowercase() }),
+        ));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r##"Json expect::string() error at root<captures>[1].tag:
+    expected lowercase string, but found 'J' at byte 0,
+    received "Json"
+    received string "#rust #Json", matched with regex pattern '#(?<tag>\w+)'"##
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_nothing_matches() {
+        let left = json!("no tags here");
+        let right = json!(expect::string().matches_regex_all(r"#(?<tag>\w+)", json!({})));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json string error at root, regex did not match:
+    expected string to match regex pattern '#(?<tag>\w+)',
+    received "no tags here""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_matches_regex_count {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_matching_the_count() {
+        let left = json!("a-b-c");
+        let right = json!(expect::string().matches_regex_count("-", 2));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_matching_a_different_count() {
+        let left = json!("a-b-c-d");
+        let right = json!(expect::string().matches_regex_count("-", 2));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to match regex pattern '-' 2 times, but it matched 3 times,
+    received "a-b-c-d""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_starts_with {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_when_string_starts_with_prefix() {
+        let left = json!("https://example.com");
+        let right = json!(expect::string().starts_with("https://"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
+    }
+
+    #[test]
+    fn it_should_fail_when_string_does_not_start_with_prefix() {
+        let left = json!("http://example.com");
+        let right = json!(expect::string().starts_with("https://"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to start with "https://",
+    received "http://example.com""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_ends_with {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
 
-    pub fn max_len(mut self, max_len: usize) -> Self {
-        self.sub_ops.push(ExpectStringSubOp::MaxLen(max_len));
-        self
-    }
+    #[test]
+    fn it_should_pass_when_string_ends_with_suffix() {
+        let left = json!("photo.png");
+        let right = json!(expect::string().ends_with(".png"));
 
-    ///
-    /// Expect a string containing a subset of the string given.
-    ///
-    /// ```rust
-    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
-    /// #
-    /// # use axum::Router;
-    /// # use axum::extract::Json;
-    /// # use axum::routing::get;
-    /// # use axum_test::TestServer;
-    /// # use serde_json::json;
-    /// #
-    /// # let server = TestServer::new(Router::new());
-    /// #
-    /// use axum_test::expect_json;
-    ///
-    /// let server = TestServer::new(Router::new());
-    ///
-    /// server.get(&"/user")
-    ///     .await
-    ///     .assert_json(&json!({
-    ///         "name": expect_json::string().contains("apples"),
-    ///     }));
-    /// #
-    /// # Ok(()) }
-    /// ```
-    pub fn contains<S>(mut self, expected_sub_string: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.sub_ops
-            .push(ExpectStringSubOp::Contains(expected_sub_string.into()));
-        self
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
     }
 
-    ///
-    /// Expect a string matching the regex given.
-    ///
-    /// ```rust
-    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
-    /// #
-    /// # use axum::Router;
-    /// # use axum::extract::Json;
-    /// # use axum::routing::get;
-    /// # use axum_test::TestServer;
-    /// # use serde_json::json;
-    /// #
-    /// # let server = TestServer::new(Router::new());
-    /// #
-    /// use axum_test::expect_json;
-    ///
-    /// let server = TestServer::new(Router::new());
-    ///
-    /// server.get(&"/user")
-    ///     .await
-    ///     .assert_json(&json!({
-    ///         "email": expect_json::string().matches_regex(r#"\w+@(?:\w+\.)+\w+"#),
-    ///     }));
-    /// #
-    /// # Ok(()) }
-    /// ```
-    pub fn matches_regex<S>(mut self, pattern: S) -> Self
-    where
-        S: Into<String>,
-    {
-        self.sub_ops
-            .push(ExpectStringSubOp::MatchesRegex(pattern.into()));
-        self
+    #[test]
+    fn it_should_fail_when_string_does_not_end_with_suffix() {
+        let left = json!("photo.png.exe");
+        let right = json!(expect::string().ends_with(".png"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to end with ".png",
+    received "photo.png.exe""#
+        );
     }
 }
 
-impl ExpectOp for ExpectString {
-    fn on_string(&self, context: &mut Context, received: &str) -> ExpectOpResult<()> {
-        for sub_op in &self.sub_ops {
-            sub_op.on_string(self, context, received)?;
-        }
+#[cfg(test)]
+mod test_eq_ignore_case {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
 
-        Ok(())
+    #[test]
+    fn it_should_pass_for_strings_in_a_different_case() {
+        let left = json!("Hello World");
+        let right = json!(expect::string().eq_ignore_case("hello world"));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok());
     }
 
-    fn debug_supported_types(&self) -> &'static [JsonType] {
-        &[JsonType::String]
+    #[test]
+    fn it_should_fail_for_different_strings() {
+        let left = json!("Hello World");
+        let right = json!(expect::string().eq_ignore_case("hello there"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string equal to "hello there", ignoring case,
+    received "Hello World""#
+        );
     }
 }
 
 #[cfg(test)]
-mod test_contains {
+mod test_trimmed {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_be_equal_for_identical_strings() {
-        let left = json!("1, 2, 3");
-        let right = json!(expect::string().contains("1, 2, 3"));
+    fn it_should_pass_for_trimmed_strings() {
+        let left = json!("hello world");
+        let right = json!(expect::string().trimmed());
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok());
     }
 
     #[test]
-    fn it_should_be_equal_for_partial_matches_in_middle() {
-        let left = json!("0, 1, 2, 3, 4");
-        let right = json!(expect::string().contains("1, 2, 3"));
+    fn it_should_fail_for_leading_whitespace() {
+        let left = json!(" hello world");
+        let right = json!(expect::string().trimmed());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string without leading or trailing whitespace,
+    received " hello world""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_ascii_only {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_ascii_strings() {
+        let left = json!("hello world!");
+        let right = json!(expect::string().ascii_only());
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok());
     }
 
     #[test]
-    fn it_should_be_ok_for_empty_contains() {
-        let left = json!("0, 1, 2, 3, 4, 5");
-        let right = json!(expect::string().contains(""));
+    fn it_should_fail_for_non_ascii_strings() {
+        let left = json!("hello 🦊");
+        let right = json!(expect::string().ascii_only());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string with only ascii characters, but found '🦊' at byte 6,
+    received "hello 🦊""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_lowercase {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_lowercase_strings() {
+        let left = json!("hello-world_123");
+        let right = json!(expect::string().lowercase());
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok());
     }
 
     #[test]
-    fn it_should_error_for_totall_different_values() {
-        let left = json!("1, 2, 3");
-        let right = json!(expect::string().contains("a, b, c"));
+    fn it_should_fail_for_strings_with_uppercase() {
+        let left = json!("hello World");
+        let right = json!(expect::string().lowercase());
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
-            r#"Json string at root does not contain expected value:
-    expected string to contain "a, b, c", but it was not found.
-    received "1, 2, 3""#
+            r#"Json expect::string() error at root:
+    expected lowercase string, but found 'W' at byte 6,
+    received "hello World""#
         );
     }
 }
 
 #[cfg(test)]
-mod test_empty {
+mod test_uppercase {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_is_empty() {
-        let left = json!("");
-        let right = json!(expect::string().empty());
+    fn it_should_pass_for_uppercase_strings() {
+        let left = json!("HELLO_WORLD");
+        let right = json!(expect::string().uppercase());
 
         let output = expect_json_eq(&left, &right);
-        assert!(output.is_ok(), "assertion error: {output:#?}");
+        assert!(output.is_ok());
     }
 
     #[test]
-    fn it_should_fail_when_string_is_not_empty() {
-        let left = json!("🦊");
-        let right = json!(expect::string().empty());
+    fn it_should_fail_for_strings_with_lowercase() {
+        let left = json!("HELLO World");
+        let right = json!(expect::string().uppercase());
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected empty string
-    received "🦊""#
+    expected uppercase string, but found 'o' at byte 7,
+    received "HELLO World""#
         );
     }
 }
 
 #[cfg(test)]
-mod test_not_empty {
+mod test_one_of {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_is_not_empty() {
-        let left = json!("🦊");
-        let right = json!(expect::string().not_empty());
+    fn it_should_pass_for_a_string_in_the_list() {
+        let left = json!("active");
+        let right = json!(expect::string().one_of(["pending", "active", "closed"]));
 
         let output = expect_json_eq(&left, &right);
-        assert!(output.is_ok(), "assertion error: {output:#?}");
+        assert!(output.is_ok());
     }
 
     #[test]
-    fn it_should_fail_when_string_is_empty() {
-        let left = json!("");
-        let right = json!(expect::string().not_empty());
+    fn it_should_fail_for_a_string_not_in_the_list() {
+        let left = json!("deleted");
+        let right = json!(expect::string().one_of(["pending", "active", "closed"]));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected non-empty string
-    received """#
+    expected string to be one of "pending", "active", "closed",
+    received "deleted""#
         );
     }
 }
 
 #[cfg(test)]
-mod test_len {
+mod test_char_len {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_has_same_number_of_characters() {
-        let left = json!("123");
-        let right = json!(expect::string().len(3));
+    fn it_should_count_chars_rather_than_bytes() {
+        let left = json!("山田太郎");
+        let right = json!(expect::string().char_len(4).min_char_len(4).max_char_len(4));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_when_string_is_too_short() {
-        let left = json!("12");
-        let right = json!(expect::string().len(3));
+    fn it_should_fail_when_too_many_chars() {
+        let left = json!("山田太郎");
+        let right = json!(expect::string().max_char_len(3));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected string to have 3 characters, but it has 2,
-    received "12""#
+    expected string to have at most 3 chars, but it has 4,
+    received "山田太郎""#
         );
     }
 
     #[test]
-    fn it_should_fail_when_string_is_too_long() {
-        let left = json!("1234");
-        let right = json!(expect::string().len(3));
+    fn it_should_fail_when_too_few_chars() {
+        let left = json!("山田");
+        let right = json!(expect::string().min_char_len(3));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected string to have 3 characters, but it has 4,
-    received "1234""#
+    expected string to have at least 3 chars, but it has 2,
+    received "山田""#
         );
     }
 }
 
 #[cfg(test)]
-mod test_min_len {
+mod test_grapheme_len {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_has_exactly_enough_characters() {
-        let left = json!("123");
-        let right = json!(expect::string().min_len(3));
+    fn it_should_count_graphemes_rather_than_chars() {
+        let left = json!("👍🏽 cafe\u{301}");
+        let right = json!(expect::string().grapheme_len(6).max_grapheme_len(6));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_pass_when_string_has_more_than_enough_characters() {
-        let left = json!("12345");
-        let right = json!(expect::string().min_len(3));
+    fn it_should_count_emoji_sequences_as_one() {
+        // A family emoji joined with zero width joiners, and a flag.
+        let left = json!("👨‍👩‍👧🇯🇵");
+        let right = json!(expect::string().grapheme_len(2));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_when_string_is_too_short() {
-        let left = json!("12");
-        let right = json!(expect::string().min_len(3));
+    fn it_should_count_indic_conjuncts_as_one() {
+        // 'क्षि' is a conjunct of two consonants joined by a virama, with a vowel sign.
+        let left = json!("क्षि");
+        let right = json!(expect::string().grapheme_len(1));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_a_different_number_of_graphemes() {
+        let left = json!("👍🏽👍🏽");
+        let right = json!(expect::string().grapheme_len(4));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected string to have at least 3 characters, but it has 2,
-    received "12""#
+    expected string to have 4 graphemes, but it has 2,
+    received "👍🏽👍🏽""#
         );
     }
 }
 
 #[cfg(test)]
-mod test_max_len {
+mod test_glob {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_has_exactly_enough_characters() {
-        let left = json!("123");
-        let right = json!(expect::string().max_len(3));
+    fn it_should_pass_when_string_matches_glob() {
+        let left = json!("user-abc-1234");
+        let right = json!(expect::string().glob("user-*-????"));
 
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_pass_when_string_has_less_than_enough_characters() {
-        let left = json!("12");
-        let right = json!(expect::string().max_len(5));
+    fn it_should_report_where_the_string_diverged() {
+        let left = json!("user-abc_1234");
+        let right = json!(expect::string().glob("user-???-????"));
 
-        let output = expect_json_eq(&left, &right);
-        assert!(output.is_ok(), "assertion error: {output:#?}");
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to match glob pattern 'user-???-????',
+    pattern matched 'user-???' against "user-abc", then diverged at char 8 '_',
+    received "user-abc_1234""#
+        );
     }
 
     #[test]
-    fn it_should_fail_when_string_is_too_long() {
-        let left = json!("🦊🦊🦊🦊🦊🦊");
-        let right = json!(expect::string().max_len(3));
+    fn it_should_report_when_the_string_ends_early() {
+        let left = json!("user-abc-12");
+        let right = json!(expect::string().glob("user-*-????"));
 
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
             r#"Json expect::string() error at root:
-    expected string to have at most 3 characters, but it has 24,
-    received "🦊🦊🦊🦊🦊🦊""#
+    expected string to match glob pattern 'user-*-????',
+    pattern matched 'user-*-??' against the whole string, then expected '??',
+    received "user-abc-12""#
         );
     }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_when_pattern_is_invalid() {
+        let _ = expect::string().glob(r"user-\");
+    }
 }
 
 #[cfg(test)]
-mod test_matches_regex {
+mod test_like {
     use crate::expect;
     use crate::expect_json_eq;
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
     #[test]
-    fn it_should_pass_when_string_matches_regex() {
-        let left = json!("abc123xyz");
-        let right = json!(expect::string().matches_regex(r"^[a-z]+[0-9]+[a-z]+$"));
+    fn it_should_pass_when_string_matches_like() {
+        let left = json!("joe@example.com");
+        let right = json!(expect::string().like("%@%.com"));
+
         let output = expect_json_eq(&left, &right);
         assert!(output.is_ok(), "assertion error: {output:#?}");
     }
 
     #[test]
-    fn it_should_fail_when_string_does_not_match_regex() {
-        let left = json!("abcxyz");
-        let right = json!(expect::string().matches_regex(r"^[a-z]+[0-9]+[a-z]+$"));
+    fn it_should_treat_glob_wildcards_as_literals() {
+        let left = json!("joe@example.com");
+        let right = json!(expect::string().like("*@*.com"));
+
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
         assert_eq!(
             output,
-            r#"Json string error at root, regex did not match:
-    expected string to match regex pattern '^[a-z]+[0-9]+[a-z]+$',
-    received "abcxyz""#
+            r#"Json expect::string() error at root:
+    expected string to match like pattern '*@*.com',
+    pattern matched '' against "", then diverged at char 0 'j',
+    received "joe@example.com""#
         );
     }
 
     #[test]
-    fn it_should_fail_when_regex_is_invalid() {
-        let left = json!("abc123xyz");
-        let right = json!(expect::string().matches_regex(r"([a-z]+"));
+    fn it_should_fail_when_string_does_not_match_like() {
+        let left = json!("joe@example.org");
+        let right = json!(expect::string().like("%@%.com"));
+
         let output = expect_json_eq(&left, &right).unwrap_err().to_string();
-        // For robustness, we don't specify the error message coming from the regex crate.
-        assert!(
-            output.starts_with(r#"Json expect::string() error at root:"#),
-            "Unexpected error output: {output:#?}"
+        assert_eq!(
+            output,
+            r#"Json expect::string() error at root:
+    expected string to match like pattern '%@%.com',
+    pattern matched '%@%.' against "joe@example.", then diverged at char 12 'o',
+    received "joe@example.org""#
         );
     }
 }
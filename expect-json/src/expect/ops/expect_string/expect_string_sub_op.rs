@@ -1,11 +1,22 @@
+use crate::__private::SerializeExpectOp;
 use crate::JsonType;
 use crate::expect::ops::ExpectString;
 use crate::expect_core::Context;
+use crate::expect_core::ContextPathPart;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
+use crate::internals::ErrorCollector;
 use crate::internals::objects::StringObject;
+use crate::internals::utils::RegexPattern;
+use crate::internals::utils::WildcardPattern;
+use crate::internals::utils::WildcardSyntax;
+use regex::Captures;
+use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ExpectStringSubOp {
@@ -14,8 +25,66 @@ pub enum ExpectStringSubOp {
     Len(usize),
     MinLen(usize),
     MaxLen(usize),
+    CharLen(usize),
+    MinCharLen(usize),
+    MaxCharLen(usize),
+    GraphemeLen(usize),
+    MinGraphemeLen(usize),
+    MaxGraphemeLen(usize),
     Contains(String),
-    MatchesRegex(String),
+    NotContains(String),
+    MatchesRegex(RegexPattern),
+    Glob(String),
+    Like(String),
+    MatchesRegexCaptures {
+        pattern: RegexPattern,
+        expected: Value,
+    },
+    MatchesRegexAll {
+        pattern: RegexPattern,
+        expected: Value,
+    },
+    MatchesRegexCount {
+        pattern: RegexPattern,
+        count: usize,
+    },
+    StartsWith(String),
+    EndsWith(String),
+    EqIgnoreCase(String),
+    Trimmed,
+    AsciiOnly,
+    Lowercase,
+    Uppercase,
+    OneOf(Vec<String>),
+}
+
+/// What is counted when checking the length of a string.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum StringLengthUnit {
+    /// UTF-8 bytes.
+    Bytes,
+    /// Unicode scalar values, as returned by `str::chars`.
+    Chars,
+    /// Extended grapheme clusters, which are the characters a user would see.
+    Graphemes,
+}
+
+impl StringLengthUnit {
+    fn len_of(self, received: &str) -> usize {
+        match self {
+            Self::Bytes => received.len(),
+            Self::Chars => received.chars().count(),
+            Self::Graphemes => received.graphemes(true).count(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Chars => "chars",
+            Self::Graphemes => "graphemes",
+        }
+    }
 }
 
 impl ExpectStringSubOp {
@@ -28,15 +97,95 @@ impl ExpectStringSubOp {
         match self {
             Self::Empty => Self::on_string_empty(parent, context, received),
             Self::NotEmpty => Self::on_string_not_empty(parent, context, received),
-            Self::Len(len) => Self::on_string_len(*len, parent, context, received),
-            Self::MinLen(min_len) => Self::on_string_min_len(*min_len, parent, context, received),
-            Self::MaxLen(max_len) => Self::on_string_max_len(*max_len, parent, context, received),
+            Self::Len(len) => {
+                Self::on_string_len(*len, StringLengthUnit::Bytes, parent, context, received)
+            }
+            Self::MinLen(min_len) => Self::on_string_min_len(
+                *min_len,
+                StringLengthUnit::Bytes,
+                parent,
+                context,
+                received,
+            ),
+            Self::MaxLen(max_len) => Self::on_string_max_len(
+                *max_len,
+                StringLengthUnit::Bytes,
+                parent,
+                context,
+                received,
+            ),
+            Self::CharLen(len) => {
+                Self::on_string_len(*len, StringLengthUnit::Chars, parent, context, received)
+            }
+            Self::MinCharLen(min_len) => Self::on_string_min_len(
+                *min_len,
+                StringLengthUnit::Chars,
+                parent,
+                context,
+                received,
+            ),
+            Self::MaxCharLen(max_len) => Self::on_string_max_len(
+                *max_len,
+                StringLengthUnit::Chars,
+                parent,
+                context,
+                received,
+            ),
+            Self::GraphemeLen(len) => {
+                Self::on_string_len(*len, StringLengthUnit::Graphemes, parent, context, received)
+            }
+            Self::MinGraphemeLen(min_len) => Self::on_string_min_len(
+                *min_len,
+                StringLengthUnit::Graphemes,
+                parent,
+                context,
+                received,
+            ),
+            Self::MaxGraphemeLen(max_len) => Self::on_string_max_len(
+                *max_len,
+                StringLengthUnit::Graphemes,
+                parent,
+                context,
+                received,
+            ),
             Self::Contains(contains) => {
                 Self::on_string_contains(contains, parent, context, received)
             }
+            Self::NotContains(not_contains) => {
+                Self::on_string_not_contains(not_contains, parent, context, received)
+            }
             Self::MatchesRegex(pattern) => {
                 Self::on_string_matches_regex(pattern, parent, context, received)
             }
+            Self::Glob(pattern) => {
+                Self::on_string_wildcard(WildcardSyntax::Glob, pattern, parent, context, received)
+            }
+            Self::Like(pattern) => {
+                Self::on_string_wildcard(WildcardSyntax::Like, pattern, parent, context, received)
+            }
+            Self::MatchesRegexCaptures { pattern, expected } => {
+                Self::on_string_matches_regex_captures(pattern, expected, parent, context, received)
+            }
+            Self::MatchesRegexAll { pattern, expected } => {
+                Self::on_string_matches_regex_all(pattern, expected, parent, context, received)
+            }
+            Self::MatchesRegexCount { pattern, count } => {
+                Self::on_string_matches_regex_count(pattern, *count, parent, context, received)
+            }
+            Self::StartsWith(prefix) => {
+                Self::on_string_starts_with(prefix, parent, context, received)
+            }
+            Self::EndsWith(suffix) => Self::on_string_ends_with(suffix, parent, context, received),
+            Self::EqIgnoreCase(expected) => {
+                Self::on_string_eq_ignore_case(expected, parent, context, received)
+            }
+            Self::Trimmed => Self::on_string_trimmed(parent, context, received),
+            Self::AsciiOnly => Self::on_string_ascii_only(parent, context, received),
+            Self::Lowercase => Self::on_string_lowercase(parent, context, received),
+            Self::Uppercase => Self::on_string_uppercase(parent, context, received),
+            Self::OneOf(expected_values) => {
+                Self::on_string_one_of(expected_values, parent, context, received)
+            }
         }
     }
 
@@ -76,17 +225,18 @@ impl ExpectStringSubOp {
 
     fn on_string_len(
         len: usize,
+        unit: StringLengthUnit,
         parent: &ExpectString,
         context: &mut Context<'_>,
         received: &str,
     ) -> ExpectOpResult<()> {
-        if received.len() != len {
+        let received_len = unit.len_of(received);
+        if received_len != len {
             let error_message = format!(
-                r#"expected string to have {} characters, but it has {},
+                r#"expected string to have {len} {unit}, but it has {received_len},
     received {}"#,
-                len,
-                received.len(),
                 StringObject::from(received),
+                unit = unit.name(),
             );
             return Err(ExpectOpError::custom(parent, context, error_message));
         }
@@ -96,17 +246,18 @@ impl ExpectStringSubOp {
 
     fn on_string_min_len(
         min_len: usize,
+        unit: StringLengthUnit,
         parent: &ExpectString,
         context: &mut Context<'_>,
         received: &str,
     ) -> ExpectOpResult<()> {
-        if received.len() < min_len {
+        let received_len = unit.len_of(received);
+        if received_len < min_len {
             let error_message = format!(
-                r#"expected string to have at least {} characters, but it has {},
+                r#"expected string to have at least {min_len} {unit}, but it has {received_len},
     received {}"#,
-                min_len,
-                received.len(),
                 StringObject::from(received),
+                unit = unit.name(),
             );
             return Err(ExpectOpError::custom(parent, context, error_message));
         }
@@ -116,17 +267,18 @@ impl ExpectStringSubOp {
 
     fn on_string_max_len(
         max_len: usize,
+        unit: StringLengthUnit,
         parent: &ExpectString,
         context: &mut Context<'_>,
         received: &str,
     ) -> ExpectOpResult<()> {
-        if received.len() > max_len {
+        let received_len = unit.len_of(received);
+        if received_len > max_len {
             let error_message = format!(
-                r#"expected string to have at most {} characters, but it has {},
+                r#"expected string to have at most {max_len} {unit}, but it has {received_len},
     received {}"#,
-                max_len,
-                received.len(),
                 StringObject::from(received),
+                unit = unit.name(),
             );
             return Err(ExpectOpError::custom(parent, context, error_message));
         }
@@ -152,29 +304,391 @@ impl ExpectStringSubOp {
         Ok(())
     }
 
+    fn on_string_not_contains(
+        expected_sub_string: &str,
+        _parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if received.contains(expected_sub_string) {
+            return Err(ExpectOpError::ContainsFound {
+                context: context.to_static(),
+                json_type: JsonType::String,
+                expected: StringObject::from(expected_sub_string).into(),
+                received: StringObject::from(received).into(),
+            });
+        }
+
+        Ok(())
+    }
+
     fn on_string_matches_regex(
+        pattern: &RegexPattern,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        let regex = Self::compile_regex(pattern, parent, context)?;
+        if !regex.is_match(received) {
+            return Err(Self::regex_no_match(pattern, context, received));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_wildcard(
+        syntax: WildcardSyntax,
         pattern: &str,
-        _parent: &ExpectString,
+        parent: &ExpectString,
         context: &mut Context<'_>,
         received: &str,
     ) -> ExpectOpResult<()> {
-        let regex = regex::Regex::new(pattern).map_err(|e| {
+        let wildcard_pattern = WildcardPattern::parse(syntax, pattern).map_err(|e| {
             ExpectOpError::custom(
-                _parent,
+                parent,
                 context,
-                format!("invalid regex pattern '{}': {}", pattern, e),
+                format!("invalid {syntax} pattern '{pattern}': {e}"),
             )
         })?;
 
-        if !regex.is_match(received) {
-            return Err(ExpectOpError::RegexNoMatch {
-                context: context.to_static(),
-                json_type: JsonType::String,
+        let Some(mismatch) = wildcard_pattern.find_mismatch(received) else {
+            return Ok(());
+        };
+
+        let progress = match mismatch.maybe_diverged_at {
+            Some((char_index, c)) => format!(
+                "pattern matched '{}' against {}, then diverged at char {char_index} '{c}'",
+                mismatch.pattern_matched,
+                StringObject::from(mismatch.received_matched),
+            ),
+            None => format!(
+                "pattern matched '{}' against the whole string, then expected '{}'",
+                mismatch.pattern_matched, mismatch.pattern_remaining,
+            ),
+        };
+        let error_message = format!(
+            r#"expected string to match {syntax} pattern '{pattern}',
+    {progress},
+    received {}"#,
+            StringObject::from(received),
+        );
+
+        Err(ExpectOpError::custom(parent, context, error_message))
+    }
+
+    fn on_string_matches_regex_captures(
+        pattern: &RegexPattern,
+        expected: &Value,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        let regex = Self::compile_regex(pattern, parent, context)?;
+        let captures = regex
+            .captures(received)
+            .ok_or_else(|| Self::regex_no_match(pattern, context, received))?;
+
+        let number_names = to_number_capture_names(expected);
+        let captures_value = captures_to_json(regex, &captures, expected, &number_names);
+        let mut context: Context<'_> = context.clone();
+        context
+            .with_path(ContextPathPart::RegexCaptures)
+            .json_eq(&captures_value, expected)
+            .map_err(|error| ExpectOpError::RegexCaptures {
+                error: Box::new(error),
                 pattern: pattern.to_string(),
                 received: StringObject::from(received).into(),
-            });
+            })
+    }
+
+    fn on_string_matches_regex_all(
+        pattern: &RegexPattern,
+        expected: &Value,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        let regex = Self::compile_regex(pattern, parent, context)?;
+        let number_names = to_number_capture_names(expected);
+        let all_captures = regex
+            .captures_iter(received)
+            .map(|captures| captures_to_json(regex, &captures, expected, &number_names))
+            .collect::<Vec<_>>();
+        if all_captures.is_empty() {
+            return Err(Self::regex_no_match(pattern, context, received));
+        }
+
+        let mut context: Context<'_> = context.clone();
+        let mut errors = ErrorCollector::new(&context);
+        for (index, captures_value) in all_captures.iter().enumerate() {
+            let result = context
+                .with_path(ContextPathPart::RegexCaptures)
+                .with_path(index)
+                .json_eq(captures_value, expected)
+                .map_err(|error| ExpectOpError::RegexCaptures {
+                    error: Box::new(error),
+                    pattern: pattern.to_string(),
+                    received: StringObject::from(received).into(),
+                });
+            errors.check(result)?;
+        }
+
+        errors.into_result()
+    }
+
+    fn on_string_matches_regex_count(
+        pattern: &RegexPattern,
+        count: usize,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        let regex = Self::compile_regex(pattern, parent, context)?;
+        let received_count = regex.find_iter(received).count();
+        if received_count != count {
+            let error_message = format!(
+                r#"expected string to match regex pattern '{pattern}' {count} times, but it matched {received_count} times,
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
         }
 
         Ok(())
     }
+
+    /// Patterns are checked when the op is built,
+    /// so this only fails for ops deserialised from elsewhere, such as snapshots.
+    fn compile_regex<'p>(
+        pattern: &'p RegexPattern,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+    ) -> ExpectOpResult<&'p Regex> {
+        pattern.regex().map_err(|e| {
+            ExpectOpError::custom(
+                parent,
+                context,
+                format!("invalid regex pattern '{}': {}", pattern, e),
+            )
+        })
+    }
+
+    fn regex_no_match(
+        pattern: &RegexPattern,
+        context: &Context<'_>,
+        received: &str,
+    ) -> ExpectOpError {
+        ExpectOpError::RegexNoMatch {
+            context: context.to_static(),
+            json_type: JsonType::String,
+            pattern: pattern.to_string(),
+            received: StringObject::from(received).into(),
+        }
+    }
+
+    fn on_string_starts_with(
+        prefix: &str,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if !received.starts_with(prefix) {
+            let error_message = format!(
+                r#"expected string to start with {},
+    received {}"#,
+                StringObject::from(prefix),
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_ends_with(
+        suffix: &str,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if !received.ends_with(suffix) {
+            let error_message = format!(
+                r#"expected string to end with {},
+    received {}"#,
+                StringObject::from(suffix),
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_eq_ignore_case(
+        expected: &str,
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if received.to_lowercase() != expected.to_lowercase() {
+            let error_message = format!(
+                r#"expected string equal to {}, ignoring case,
+    received {}"#,
+                StringObject::from(expected),
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_trimmed(
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if received.trim() != received {
+            let error_message = format!(
+                r#"expected string without leading or trailing whitespace,
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_ascii_only(
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if let Some((index, c)) = received.char_indices().find(|(_, c)| !c.is_ascii()) {
+            let error_message = format!(
+                r#"expected string with only ascii characters, but found '{c}' at byte {index},
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_lowercase(
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if let Some((index, c)) = received.char_indices().find(|(_, c)| c.is_uppercase()) {
+            let error_message = format!(
+                r#"expected lowercase string, but found '{c}' at byte {index},
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_uppercase(
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if let Some((index, c)) = received.char_indices().find(|(_, c)| c.is_lowercase()) {
+            let error_message = format!(
+                r#"expected uppercase string, but found '{c}' at byte {index},
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+
+    fn on_string_one_of(
+        expected_values: &[String],
+        parent: &ExpectString,
+        context: &mut Context<'_>,
+        received: &str,
+    ) -> ExpectOpResult<()> {
+        if !expected_values.iter().any(|expected| expected == received) {
+            let expected_list = expected_values
+                .iter()
+                .map(|expected| StringObject::from(expected.as_str()).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let error_message = format!(
+                r#"expected string to be one of {expected_list},
+    received {}"#,
+                StringObject::from(received),
+            );
+            return Err(ExpectOpError::custom(parent, context, error_message));
+        }
+
+        Ok(())
+    }
+}
+
+/// Builds an object of the named groups matched, to the text they matched.
+///
+/// When an object is expected, only the groups it lists are included,
+/// so groups which are not listed are not reported as extra fields.
+/// Groups named in `number_names` are compared as numbers when their text is numeric.
+fn captures_to_json(
+    regex: &Regex,
+    captures: &Captures<'_>,
+    expected: &Value,
+    number_names: &BTreeSet<&str>,
+) -> Value {
+    let maybe_expected_object = expected.as_object();
+    let object = regex
+        .capture_names()
+        .flatten()
+        .filter(|name| maybe_expected_object.is_none_or(|object| object.contains_key(*name)))
+        .filter_map(|name| {
+            let matched = captures.name(name)?.as_str();
+            let value = match serde_json::from_str::<Value>(matched) {
+                Ok(number @ Value::Number(_)) if number_names.contains(name) => number,
+                _ => Value::String(matched.to_string()),
+            };
+
+            Some((name.to_string(), value))
+        })
+        .collect();
+
+    Value::Object(object)
+}
+
+/// Returns the names in the expected object where a number is expected,
+/// either as a number, or as an op which only accepts numbers such as `expect::integer()`.
+fn to_number_capture_names(expected: &Value) -> BTreeSet<&str> {
+    let Some(expected_object) = expected.as_object() else {
+        return BTreeSet::new();
+    };
+
+    expected_object
+        .iter()
+        .filter(|(_, expected_value)| is_number_expected(expected_value))
+        .map(|(name, _)| name.as_str())
+        .collect()
+}
+
+fn is_number_expected(expected: &Value) -> bool {
+    if expected.is_number() {
+        return true;
+    }
+
+    SerializeExpectOp::maybe_parse(expected).is_some_and(|expect_op| {
+        let types = expect_op.inner.debug_supported_types();
+        !types.is_empty()
+            && types
+                .iter()
+                .all(|json_type| matches!(json_type, JsonType::Integer | JsonType::Float))
+    })
 }
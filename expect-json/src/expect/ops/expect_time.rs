@@ -0,0 +1,316 @@
+use crate::JsonType;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::into_temporal_bound;
+use crate::expect::ops::utils::into_temporal_range;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use chrono::DateTime;
+use chrono::NaiveTime;
+use chrono::ParseResult;
+use std::time::Duration as StdDuration;
+
+///
+/// Expects a time of day on its own, such as `"14:30:00"` or `"14:30"`.
+///
+/// Fractions of a second are also accepted, such as `"14:30:00.250"`.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use std::time::Duration;
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/shop")
+///     .await
+///     .assert_json(&json!({
+///         "name": "Barrington's Bakery",
+///         "opens_at": expect_json::time().between("06:00", "10:00"),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+///
+#[expect_op(internal, name = "time")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectTime {
+    checks: TemporalChecks,
+}
+
+impl ExpectTime {
+    pub(crate) fn new() -> Self {
+        Self {
+            checks: TemporalChecks::default(),
+        }
+    }
+
+    ///
+    /// Expects the time to be within a past duration, up to now.
+    ///
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks.within_past(duration);
+        self
+    }
+
+    ///
+    /// Expects the time to be from now, and up to a future duration.
+    ///
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks.within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the time to be after the bound given.
+    ///
+    /// The bound can also be a reference to another field, using [`crate::expect::field`].
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/shop")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "value": expect_json::time().greater_than("09:00"),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn greater_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than", bound);
+        self.checks.push(TemporalComparison::GreaterThan(bound));
+        self
+    }
+
+    /// Expects the time to be at or after the bound given.
+    pub fn greater_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "greater_than_equal", bound);
+        self.checks
+            .push(TemporalComparison::GreaterThanEqual(bound));
+        self
+    }
+
+    /// Expects the time to be before the bound given.
+    pub fn less_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than", bound);
+        self.checks.push(TemporalComparison::LessThan(bound));
+        self
+    }
+
+    /// Expects the time to be at or before the bound given.
+    pub fn less_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self, "less_than_equal", bound);
+        self.checks.push(TemporalComparison::LessThanEqual(bound));
+        self
+    }
+
+    /// Expects the time to be between the two bounds given, inclusive.
+    ///
+    /// The range cannot cross midnight, so this panics if `min` is after `max`.
+    pub fn between<B1, B2>(mut self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<String>>,
+        B2: Into<ValueOrFieldRef<String>>,
+    {
+        let (min, max) = into_temporal_range(&self, "between", min, max);
+        self.checks.push(TemporalComparison::Between { min, max });
+        self
+    }
+}
+
+impl ExpectOp for ExpectTime {
+    fn on_string(&self, context: &mut Context, received: &str) -> ExpectOpResult<()> {
+        let time = parse_time(received).map_err(|error| {
+            let error_message = format!("failed to parse string '{received}' as time");
+            ExpectOpError::custom_error(self, context, error_message, error)
+        })?;
+
+        self.checks.check(self, context, received, time)
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::String]
+    }
+}
+
+impl ExpectTemporal for ExpectTime {
+    type Value = NaiveTime;
+
+    const FUNCTION_NAME: &'static str = "time";
+    const LABEL: &'static str = "time";
+    const TYPE_NAME: &'static str = "time";
+
+    fn bound_description(&self) -> String {
+        "a time".to_string()
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        parse_time(raw).ok().or_else(|| {
+            DateTime::parse_from_rfc3339(raw)
+                .ok()
+                .map(|date_time| date_time.time())
+        })
+    }
+}
+
+fn parse_time(raw: &str) -> ParseResult<NaiveTime> {
+    NaiveTime::parse_from_str(raw, "%H:%M:%S%.f")
+        .or_else(|error| NaiveTime::parse_from_str(raw, "%H:%M").map_err(|_| error))
+}
+
+#[cfg(test)]
+mod test_time {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_times_with_and_without_seconds() {
+        for received in ["14:30", "14:30:00", "14:30:00.250"] {
+            let output = expect_json_eq(&json!(received), &json!(expect::time()));
+            assert!(output.is_ok(), "assertion error: {output:#?}");
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_times() {
+        let left = json!("25:00:00");
+        let right = json!(expect::time());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::time() error at root:
+    failed to parse string '25:00:00' as time,
+    input is out of range"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_between {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_times_in_range() {
+        let left = json!({ "opens_at": "08:00", "closes_at": "17:30:00" });
+        let right = json!({
+            "opens_at": expect::time().between("06:00", "10:00"),
+            "closes_at": expect::time().greater_than(expect::field("../opens_at")),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_times_out_of_range() {
+        let left = json!("05:45");
+        let right = json!(expect::time().between("06:00", "10:00"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::time() error at root:
+    time is not in range,
+    expected 06:00 to 10:00
+    received 05:45
+    difference 15 minutes"#
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "time().between() expected the minimum to not be after the maximum. Received: '22:00' to '02:00'"
+    )]
+    fn it_should_panic_when_range_crosses_midnight() {
+        let _ = expect::time().between("22:00", "02:00");
+    }
+
+    #[test]
+    fn it_should_reject_field_ranges_where_min_is_after_max() {
+        let left = json!({ "start": "22:00", "end": "02:00", "at": "23:00" });
+        let right = json!({
+            "start": expect::time(),
+            "end": expect::time(),
+            "at": expect::time().between(expect::field("../start"), expect::field("../end")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::time() error at root.at:
+    time range is empty, the minimum is after the maximum,
+    expected 22:00 (from root.start) to 02:00 (from root.end)"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_less_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_report_the_difference_without_wrapping_around_midnight() {
+        let left = json!("23:00");
+        let right = json!(expect::time().less_than("01:00"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::time() error at root:
+    time is out of bounds,
+    expected less than 01:00
+    received 23:00
+    difference 22 hours"#
+        );
+    }
+}
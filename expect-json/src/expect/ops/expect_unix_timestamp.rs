@@ -0,0 +1,500 @@
+use crate::JsonInteger;
+use crate::JsonType;
+use crate::expect::ops::TemporalBound;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::check_temporal_bound;
+use crate::expect::ops::utils::check_temporal_range;
+use crate::expect::ops::utils::parse_date_time_bound;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::expect_core::expect_op;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Display;
+use std::time::Duration as StdDuration;
+
+///
+/// Expects an integer holding a Unix timestamp.
+///
+/// By _default_ this is in seconds. Use `millis`, `micros` or `nanos` for other units.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// use std::time::Duration;
+/// use axum_test::expect_json;
+///
+/// let server = TestServer::new(Router::new());
+///
+/// server.get(&"/latest-event")
+///     .await
+///     .assert_json(&json!({
+///         "name": "user_created",
+///         "created_at": expect_json::unix_timestamp()
+///             .millis()
+///             .within_past(Duration::from_secs(60)),
+///     }));
+/// #
+/// # Ok(()) }
+/// ```
+///
+#[expect_op(internal, name = "unix_timestamp")]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ExpectUnixTimestamp {
+    unit: UnixTimestampUnit,
+    checks: TemporalChecks,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+enum UnixTimestampUnit {
+    #[default]
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+}
+
+impl ExpectUnixTimestamp {
+    pub(crate) fn new() -> Self {
+        Self {
+            unit: UnixTimestampUnit::Seconds,
+            checks: TemporalChecks::default(),
+        }
+    }
+
+    /// The timestamp is in seconds. This is the default.
+    pub fn seconds(mut self) -> Self {
+        self.unit = UnixTimestampUnit::Seconds;
+        self
+    }
+
+    /// The timestamp is in milliseconds.
+    pub fn millis(mut self) -> Self {
+        self.unit = UnixTimestampUnit::Millis;
+        self
+    }
+
+    /// The timestamp is in microseconds.
+    pub fn micros(mut self) -> Self {
+        self.unit = UnixTimestampUnit::Micros;
+        self
+    }
+
+    /// The timestamp is in nanoseconds.
+    pub fn nanos(mut self) -> Self {
+        self.unit = UnixTimestampUnit::Nanos;
+        self
+    }
+
+    ///
+    /// Expects the timestamp to be within a past duration, up to now.
+    ///
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks.within_past(duration);
+        self
+    }
+
+    ///
+    /// Expects the timestamp to be from now, and up to a future duration.
+    ///
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks.within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the timestamp to be after the bound given.
+    ///
+    /// The bound can be a timestamp in the same unit, such as `1_740_000_000`,
+    /// an RFC 3339 string, a date such as `"2025-01-01"`, a chrono `DateTime`,
+    /// or a reference to another field using [`crate::expect::field`].
+    /// Fields referenced can hold timestamps in the same unit.
+    /// Timestamps are read in the unit set, whether that is set before or after the bound.
+    ///
+    /// Strings are always parsed as dates, so `"2025"` is not read as a timestamp.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/latest-event")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "created_at": expect_json::unix_timestamp().greater_than("2025-01-01"),
+    ///         "expires_at": expect_json::unix_timestamp().greater_than(1_740_000_000),
+    ///         "updated_at": expect_json::unix_timestamp()
+    ///             .greater_than_equal(expect_json::field("../created_at")),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn greater_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<TemporalBound>>,
+    {
+        let bound = check_temporal_bound(&self, "greater_than", bound.into());
+        self.checks.push(TemporalComparison::GreaterThan(bound));
+        self
+    }
+
+    /// Expects the timestamp to be at or after the bound given.
+    pub fn greater_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<TemporalBound>>,
+    {
+        let bound = check_temporal_bound(&self, "greater_than_equal", bound.into());
+        self.checks
+            .push(TemporalComparison::GreaterThanEqual(bound));
+        self
+    }
+
+    /// Expects the timestamp to be before the bound given.
+    pub fn less_than<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<TemporalBound>>,
+    {
+        let bound = check_temporal_bound(&self, "less_than", bound.into());
+        self.checks.push(TemporalComparison::LessThan(bound));
+        self
+    }
+
+    /// Expects the timestamp to be at or before the bound given.
+    pub fn less_than_equal<B>(mut self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<TemporalBound>>,
+    {
+        let bound = check_temporal_bound(&self, "less_than_equal", bound.into());
+        self.checks.push(TemporalComparison::LessThanEqual(bound));
+        self
+    }
+
+    /// Expects the timestamp to be between the two bounds given, inclusive.
+    pub fn between<B1, B2>(mut self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<TemporalBound>>,
+        B2: Into<ValueOrFieldRef<TemporalBound>>,
+    {
+        let (min, max) = check_temporal_range(&self, "between", min.into(), max.into());
+        self.checks.push(TemporalComparison::Between { min, max });
+        self
+    }
+
+    fn check_timestamp(
+        &self,
+        context: &mut Context<'_>,
+        received: Option<i64>,
+        received_raw: &dyn Display,
+    ) -> ExpectOpResult<()> {
+        let date_time = received
+            .and_then(|timestamp| self.unit.to_date_time(timestamp))
+            .ok_or_else(|| {
+                let error_message = format!(
+                    "unix timestamp {received_raw} is out of range, for {}",
+                    self.unit.name()
+                );
+                ExpectOpError::custom(self, context, error_message)
+            })?;
+
+        let received_readable = format!(
+            "{received_raw} ({})",
+            date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        );
+        self.checks
+            .check(self, context, &received_readable, date_time.fixed_offset())
+    }
+}
+
+impl UnixTimestampUnit {
+    fn to_date_time(self, timestamp: i64) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Seconds => DateTime::from_timestamp(timestamp, 0),
+            Self::Millis => DateTime::from_timestamp_millis(timestamp),
+            Self::Micros => DateTime::from_timestamp_micros(timestamp),
+            Self::Nanos => Some(DateTime::from_timestamp_nanos(timestamp)),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Seconds => "seconds",
+            Self::Millis => "milliseconds",
+            Self::Micros => "microseconds",
+            Self::Nanos => "nanoseconds",
+        }
+    }
+}
+
+impl ExpectOp for ExpectUnixTimestamp {
+    fn on_i64(&self, context: &mut Context, received: i64) -> ExpectOpResult<()> {
+        self.check_timestamp(context, Some(received), &received)
+    }
+
+    fn on_u64(&self, context: &mut Context, received: u64) -> ExpectOpResult<()> {
+        self.check_timestamp(context, i64::try_from(received).ok(), &received)
+    }
+
+    fn debug_supported_types(&self) -> &'static [JsonType] {
+        &[JsonType::Integer]
+    }
+}
+
+impl ExpectTemporal for ExpectUnixTimestamp {
+    type Value = DateTime<FixedOffset>;
+
+    const FUNCTION_NAME: &'static str = "unix_timestamp";
+    const LABEL: &'static str = "unix timestamp";
+    const TYPE_NAME: &'static str = "unix timestamp";
+    const HAS_TIMESTAMP_UNIT: bool = true;
+
+    fn bound_description(&self) -> String {
+        format!(
+            "an iso date time, or a unix timestamp in {}",
+            self.unit.name()
+        )
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        parse_date_time_bound(raw)
+    }
+
+    fn parse_timestamp_bound(&self, timestamp: JsonInteger) -> Option<Self::Value> {
+        let timestamp = match timestamp {
+            JsonInteger::Positive(timestamp) => i64::try_from(timestamp).ok()?,
+            JsonInteger::Negative(timestamp) => timestamp,
+        };
+
+        self.unit
+            .to_date_time(timestamp)
+            .map(|date_time| date_time.fixed_offset())
+    }
+
+    fn field_to_bound(&self, value: &Value) -> Option<TemporalBound> {
+        match value {
+            Value::Number(_) => JsonInteger::from_json_value(value).map(TemporalBound::Timestamp),
+            Value::String(raw) => Some(TemporalBound::Raw(raw.clone())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_unix_timestamp {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_accept_integers() {
+        let left = json!({ "seconds": 1_740_000_000, "millis": 1_740_000_000_123_u64 });
+        let right = json!({
+            "seconds": expect::unix_timestamp(),
+            "millis": expect::unix_timestamp().millis(),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_strings() {
+        let left = json!("1740000000");
+        let right = json!(expect::unix_timestamp());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() at root, received wrong type:
+    expected integer
+    received string "1740000000""#
+        );
+    }
+
+    #[test]
+    fn it_should_reject_timestamps_out_of_range() {
+        let left = json!(u64::MAX);
+        let right = json!(expect::unix_timestamp());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root:
+    unix timestamp 18446744073709551615 is out of range, for seconds"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_greater_than {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_render_received_as_a_date_when_failing() {
+        let left = json!(1_735_689_599_500_u64);
+        let right = json!(expect::unix_timestamp().millis().greater_than("2025-01-01"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root:
+    unix timestamp is out of bounds,
+    expected greater than 2025-01-01
+    received 1735689599500 (2024-12-31T23:59:59.500Z)
+    difference 500 milliseconds"#
+        );
+    }
+
+    #[test]
+    fn it_should_compare_against_timestamps_in_other_fields() {
+        let left = json!({ "created_at": 1_740_000_000, "updated_at": 1_739_999_000 });
+        let right = json!({
+            "created_at": expect::unix_timestamp(),
+            "updated_at": expect::unix_timestamp().greater_than_equal(expect::field("../created_at")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root.updated_at:
+    unix timestamp is out of bounds,
+    expected greater than equal 1740000000 (from root.created_at)
+    received 1739999000 (2025-02-19T21:03:20Z)
+    difference 16 minutes, 40 seconds"#
+        );
+    }
+
+    #[test]
+    fn it_should_compare_against_integer_timestamps() {
+        let left = json!({ "seconds": 1_740_000_001, "millis": 1_740_000_000_001_u64 });
+        let right = json!({
+            "seconds": expect::unix_timestamp().greater_than(1_740_000_000),
+            "millis": expect::unix_timestamp()
+                .millis()
+                .greater_than(1_740_000_000_000_u64),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_render_integer_bounds_when_failing() {
+        let left = json!(1_739_999_999);
+        let right = json!(expect::unix_timestamp().greater_than(1_740_000_000));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root:
+    unix timestamp is out of bounds,
+    expected greater than 1740000000
+    received 1739999999 (2025-02-19T21:19:59Z)
+    difference 1 second"#
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "unix_timestamp().greater_than() expected an iso date time, or a unix timestamp in seconds. Received: '2025'"
+    )]
+    fn it_should_not_parse_strings_as_timestamps() {
+        let _ = expect::unix_timestamp().greater_than("2025");
+    }
+
+    #[test]
+    fn it_should_read_integer_bounds_in_the_unit_set_afterwards() {
+        let left = json!(1_700_000_000_000_000_001_u64);
+        let right = json!(
+            expect::unix_timestamp()
+                .greater_than(1_700_000_000_000_000_000_u64)
+                .nanos()
+        );
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_integer_bounds_out_of_range_for_the_unit_when_matching() {
+        let left = json!(1_740_000_000);
+        let right = json!(expect::unix_timestamp().greater_than(1_700_000_000_000_000_000_u64));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root:
+    failed to parse bound '1700000000000000000' as an iso date time, or a unix timestamp in seconds"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_within_past {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn it_should_accept_recent_timestamps() {
+        let now = Utc::now().timestamp_millis() - 10_000;
+        let right = json!(
+            expect::unix_timestamp()
+                .millis()
+                .within_past(Duration::from_secs(60))
+        );
+
+        let output = expect_json_eq(&json!(now), &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_reject_timestamps_in_the_future() {
+        let right = json!(expect::unix_timestamp().within_past(Duration::from_secs(60)));
+
+        let output = expect_json_eq(&json!(4_102_444_800_u64), &right)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::unix_timestamp() error at root:
+    unix timestamp '4102444800 (2100-01-01T00:00:00Z)' is in the future of now, expected between '1 minute' ago and now"#
+        );
+    }
+}
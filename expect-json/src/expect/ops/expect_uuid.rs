@@ -1,10 +1,23 @@
 use crate::JsonType;
+use crate::expect::ops::ExpectUuidTimestamp;
+use crate::expect::ops::utils::ExpectTemporal;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::parse_date_time_bound;
 use crate::expect_core::Context;
 use crate::expect_core::ExpectOp;
 use crate::expect_core::ExpectOpError;
 use crate::expect_core::ExpectOpResult;
 use crate::expect_core::expect_op;
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::SecondsFormat;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use uuid::Uuid;
+use uuid::Variant;
 
 ///
 /// Expects a UUID string.
@@ -36,9 +49,102 @@ use uuid::Uuid;
 ///
 #[expect_op(internal, name = "uuid")]
 #[derive(Debug, Clone, Default, PartialEq)]
+#[serde(default)]
 pub struct ExpectUuid {
     expected_version: Option<u8>,
     is_not_nil_flag: bool,
+    is_not_max_flag: bool,
+    maybe_variant: Option<UuidVariant>,
+    maybe_case: Option<UuidCase>,
+    maybe_format: Option<UuidFormat>,
+    pub(crate) maybe_timestamp_checks: Option<TemporalChecks>,
+}
+
+///
+/// The variant of a UUID, used with [`ExpectUuid::variant`].
+///
+/// Details on the variants can be found in [RFC 9562](https://www.ietf.org/rfc/rfc9562.html#section-4.1).
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UuidVariant {
+    /// Reserved by the NCS for backward compatibility. The nil UUID uses this variant.
+    Ncs,
+    /// The variant used by the majority of UUIDs.
+    Rfc4122,
+    /// Reserved by Microsoft for backward compatibility.
+    Microsoft,
+    /// Reserved for future expansion. The max UUID uses this variant.
+    Future,
+}
+
+impl From<Variant> for UuidVariant {
+    fn from(variant: Variant) -> Self {
+        match variant {
+            Variant::NCS => Self::Ncs,
+            Variant::RFC4122 => Self::Rfc4122,
+            Variant::Microsoft => Self::Microsoft,
+            _ => Self::Future,
+        }
+    }
+}
+
+impl Display for UuidVariant {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Ncs => "NCS",
+            Self::Rfc4122 => "RFC 4122",
+            Self::Microsoft => "Microsoft",
+            Self::Future => "future",
+        };
+
+        write!(formatter, "{name}")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+enum UuidCase {
+    Lower,
+    Upper,
+}
+
+impl UuidCase {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Lower => "lowercase",
+            Self::Upper => "uppercase",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+enum UuidFormat {
+    Hyphenated,
+    Simple,
+    Urn,
+    Braced,
+}
+
+impl UuidFormat {
+    fn from_received(received: &str) -> Self {
+        if received.starts_with("urn:uuid:") {
+            Self::Urn
+        } else if received.starts_with('{') {
+            Self::Braced
+        } else if received.contains('-') {
+            Self::Hyphenated
+        } else {
+            Self::Simple
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Hyphenated => "hyphenated",
+            Self::Simple => "simple",
+            Self::Urn => "urn",
+            Self::Braced => "braced",
+        }
+    }
 }
 
 impl ExpectUuid {
@@ -46,6 +152,11 @@ impl ExpectUuid {
         Self {
             expected_version: None,
             is_not_nil_flag: false,
+            is_not_max_flag: false,
+            maybe_variant: None,
+            maybe_case: None,
+            maybe_format: None,
+            maybe_timestamp_checks: None,
         }
     }
 
@@ -55,6 +166,12 @@ impl ExpectUuid {
         self
     }
 
+    /// Expects this is not the 'max' UUID, which is "ffffffff-ffff-ffff-ffff-ffffffffffff".
+    pub fn not_max(mut self) -> Self {
+        self.is_not_max_flag = true;
+        self
+    }
+
     /// Expects this meets the given UUID version.
     ///
     /// Details on the different versions can be found on Wikipedia: <https://en.wikipedia.org/wiki/Universally_unique_identifier#Versions_of_the_OSF_DCE_variant>
@@ -62,6 +179,108 @@ impl ExpectUuid {
         self.expected_version = Some(version);
         self
     }
+
+    /// Expects this meets the given UUID variant.
+    pub fn variant(mut self, variant: UuidVariant) -> Self {
+        self.maybe_variant = Some(variant);
+        self
+    }
+
+    /// Expects the hex digits of the UUID to all be lowercase.
+    pub fn lowercase(mut self) -> Self {
+        self.maybe_case = Some(UuidCase::Lower);
+        self
+    }
+
+    /// Expects the hex digits of the UUID to all be uppercase.
+    pub fn uppercase(mut self) -> Self {
+        self.maybe_case = Some(UuidCase::Upper);
+        self
+    }
+
+    /// Expects the UUID to be written with hyphens, i.e. "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8".
+    pub fn hyphenated(mut self) -> Self {
+        self.maybe_format = Some(UuidFormat::Hyphenated);
+        self
+    }
+
+    /// Expects the UUID to be written without hyphens, i.e. "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8".
+    pub fn simple(mut self) -> Self {
+        self.maybe_format = Some(UuidFormat::Simple);
+        self
+    }
+
+    /// Expects the UUID to be written as a URN, i.e. "urn:uuid:a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8".
+    pub fn urn(mut self) -> Self {
+        self.maybe_format = Some(UuidFormat::Urn);
+        self
+    }
+
+    ///
+    /// Expects the UUID to hold a timestamp, which is versions 1, 6 and 7,
+    /// and returns a builder for checking that timestamp.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use std::time::Duration;
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.post(&"/user")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "name": "Alice",
+    ///         "id": expect_json::uuid()
+    ///             .version(7)
+    ///             .timestamp()
+    ///             .within_past(Duration::from_secs(60)),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn timestamp(mut self) -> ExpectUuidTimestamp {
+        self.maybe_timestamp_checks.get_or_insert_default();
+        ExpectUuidTimestamp::new(self)
+    }
+
+    fn check_timestamp(
+        &self,
+        context: &mut Context,
+        received: &str,
+        uuid: Uuid,
+        checks: &TemporalChecks,
+    ) -> ExpectOpResult<()> {
+        let date_time = uuid
+            .get_timestamp()
+            .and_then(|timestamp| {
+                let (seconds, nanos) = timestamp.to_unix();
+                DateTime::from_timestamp(i64::try_from(seconds).ok()?, nanos)
+            })
+            .ok_or_else(|| {
+                let error_message = format!(
+                    "expected uuid with a timestamp (version 1, 6 or 7), received version '{}', for uuid '{received}'",
+                    uuid.get_version_num()
+                );
+                ExpectOpError::custom(self, context, error_message)
+            })?;
+
+        let received_readable = format!(
+            "{received} ({})",
+            date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        );
+        checks.check(self, context, &received_readable, date_time.fixed_offset())
+    }
 }
 
 impl ExpectOp for ExpectUuid {
@@ -81,12 +300,59 @@ impl ExpectOp for ExpectUuid {
             }
         }
 
+        if let Some(expected_variant) = self.maybe_variant {
+            let received_variant = UuidVariant::from(uuid.get_variant());
+            if received_variant != expected_variant {
+                let error_message = format!(
+                    "expected uuid variant '{expected_variant}', received variant '{received_variant}', for uuid '{received}'"
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        if let Some(expected_format) = self.maybe_format {
+            let received_format = UuidFormat::from_received(received);
+            if received_format != expected_format {
+                let error_message = format!(
+                    "expected uuid in {} format, received {} format, for uuid '{received}'",
+                    expected_format.name(),
+                    received_format.name(),
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
+        if let Some(expected_case) = self.maybe_case {
+            let digits = received.strip_prefix("urn:uuid:").unwrap_or(received);
+            let is_wrong_case = match expected_case {
+                UuidCase::Lower => digits.chars().any(|c| c.is_ascii_uppercase()),
+                UuidCase::Upper => digits.chars().any(|c| c.is_ascii_lowercase()),
+            };
+            if is_wrong_case {
+                let error_message = format!(
+                    "expected uuid to be {}, received '{received}'",
+                    expected_case.name()
+                );
+                return Err(ExpectOpError::custom(self, context, error_message));
+            }
+        }
+
         if self.is_not_nil_flag && uuid.is_nil() {
             let error_message =
                 format!("expected uuid to be not nil, but it is, received '{received}'");
             return Err(ExpectOpError::custom(self, context, error_message));
         }
 
+        if self.is_not_max_flag && uuid.is_max() {
+            let error_message =
+                format!("expected uuid to be not max, but it is, received '{received}'");
+            return Err(ExpectOpError::custom(self, context, error_message));
+        }
+
+        if let Some(checks) = &self.maybe_timestamp_checks {
+            self.check_timestamp(context, received, uuid, checks)?;
+        }
+
         Ok(())
     }
 
@@ -95,6 +361,22 @@ impl ExpectOp for ExpectUuid {
     }
 }
 
+impl ExpectTemporal for ExpectUuid {
+    type Value = DateTime<FixedOffset>;
+
+    const FUNCTION_NAME: &'static str = "uuid().timestamp";
+    const LABEL: &'static str = "uuid timestamp";
+    const TYPE_NAME: &'static str = "uuid timestamp";
+
+    fn bound_description(&self) -> String {
+        "an iso date time".to_string()
+    }
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value> {
+        parse_date_time_bound(raw)
+    }
+}
+
 #[cfg(test)]
 mod test_uuid {
     use crate::expect;
@@ -193,3 +475,254 @@ mod test_version {
         );
     }
 }
+
+#[cfg(test)]
+mod test_not_max {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_a_non_max_uuid() {
+        let left = json!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8");
+        let right = json!(expect::uuid().not_max());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_for_the_max_uuid() {
+        let left = json!("ffffffff-ffff-ffff-ffff-ffffffffffff");
+        let right = json!(expect::uuid().not_max());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid to be not max, but it is, received 'ffffffff-ffff-ffff-ffff-ffffffffffff'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_variant {
+    use crate::expect;
+    use crate::expect::ops::UuidVariant;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_a_matching_variant() {
+        let left = json!("f3b4958c-52a1-11e7-802a-010203040506");
+        let right = json!(expect::uuid().variant(UuidVariant::Rfc4122));
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_for_a_different_variant() {
+        let left = json!("f3b4958c-52a1-11e7-c02a-010203040506");
+        let right = json!(expect::uuid().variant(UuidVariant::Rfc4122));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid variant 'RFC 4122', received variant 'Microsoft', for uuid 'f3b4958c-52a1-11e7-c02a-010203040506'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_case {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_lowercase_for_lowercase_uuids() {
+        let left = json!("urn:uuid:a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8");
+        let right = json!(expect::uuid().lowercase());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_lowercase_for_mixed_case_uuids() {
+        let left = json!("a1a2a3a4-B1B2-c1c2-d1d2-d3d4d5d6d7d8");
+        let right = json!(expect::uuid().lowercase());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid to be lowercase, received 'a1a2a3a4-B1B2-c1c2-d1d2-d3d4d5d6d7d8'"#
+        );
+    }
+
+    #[test]
+    fn it_should_pass_uppercase_for_uppercase_uuids() {
+        let left = json!("A1A2A3A4-B1B2-C1C2-D1D2-D3D4D5D6D7D8");
+        let right = json!(expect::uuid().uppercase());
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_uppercase_for_lowercase_uuids() {
+        let left = json!("a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8");
+        let right = json!(expect::uuid().uppercase());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid to be uppercase, received 'a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_format {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_matching_formats() {
+        let left = json!([
+            "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+            "a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8",
+            "urn:uuid:a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8",
+        ]);
+        let right = json!([
+            expect::uuid().hyphenated(),
+            expect::uuid().simple(),
+            expect::uuid().urn(),
+        ]);
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_hyphenated_for_simple_uuids() {
+        let left = json!("a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8");
+        let right = json!(expect::uuid().hyphenated());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid in hyphenated format, received simple format, for uuid 'a1a2a3a4b1b2c1c2d1d2d3d4d5d6d7d8'"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_urn_for_braced_uuids() {
+        let left = json!("{a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8}");
+        let right = json!(expect::uuid().urn());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid in urn format, received braced format, for uuid '{a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8}'"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_timestamp {
+    use crate::ExpectJsonOptions;
+    use crate::FixedClock;
+    use crate::expect;
+    use crate::expect_json_eq;
+    use crate::expect_json_eq_with;
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::time::Duration;
+    use uuid::Builder;
+
+    // A v7 uuid minted at 2025-01-01T12:00:00.123Z.
+    const V7_UUID: &str = "019421bc-aa7b-7000-8000-000000000000";
+
+    fn options_at(seconds: u32) -> ExpectJsonOptions {
+        let now = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, seconds).unwrap();
+        ExpectJsonOptions::new().clock(FixedClock::new(now))
+    }
+
+    #[test]
+    fn it_should_pass_for_a_v7_uuid_minted_now() {
+        let now_millis = Utc::now().timestamp_millis() as u64;
+        let uuid = Builder::from_unix_timestamp_millis(now_millis, &[0; 10]).into_uuid();
+        let left = json!(uuid.to_string());
+        let right = json!(
+            expect::uuid()
+                .timestamp()
+                .within_past(Duration::from_secs(60))
+        );
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_pass_when_within_past_of_the_clock() {
+        let left = json!(V7_UUID);
+        let right = json!(
+            expect::uuid()
+                .version(7)
+                .timestamp()
+                .within_past(Duration::from_secs(60))
+        );
+
+        let output = expect_json_eq_with(&left, &right, options_at(30));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_comparing_timestamps() {
+        let left = json!(V7_UUID);
+        let right = json!(expect::uuid().timestamp().greater_than("2025-01-02"));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    uuid timestamp is out of bounds,
+    expected greater than 2025-01-02
+    received 019421bc-aa7b-7000-8000-000000000000 (2025-01-01T12:00:00.123Z)
+    difference 11 hours, 59 minutes, 59 seconds"#
+        );
+    }
+
+    #[test]
+    fn it_should_fail_for_uuids_without_a_timestamp() {
+        let left = json!("a1a2a3a4-b1b2-4c12-91d2-d3d4d5d6d7d8");
+        let right = json!(expect::uuid().timestamp());
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::uuid() error at root:
+    expected uuid with a timestamp (version 1, 6 or 7), received version '4', for uuid 'a1a2a3a4-b1b2-4c12-91d2-d3d4d5d6d7d8'"#
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_should_panic_for_invalid_bounds() {
+        let _ = expect::uuid().timestamp().less_than("not a date");
+    }
+}
@@ -0,0 +1,131 @@
+use crate::expect::ops::ExpectUuid;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::TemporalChecks;
+use crate::expect::ops::utils::TemporalComparison;
+use crate::expect::ops::utils::into_temporal_bound;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::Value;
+use std::time::Duration as StdDuration;
+
+///
+/// Checks the timestamp held within a UUID, built using [`ExpectUuid::timestamp`].
+///
+/// This is used in place of the [`ExpectUuid`] it was built from.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectUuidTimestamp {
+    uuid: ExpectUuid,
+}
+
+impl ExpectUuidTimestamp {
+    pub(crate) fn new(uuid: ExpectUuid) -> Self {
+        Self { uuid }
+    }
+
+    /// Expects the timestamp to be within a past duration, up to now.
+    pub fn within_past(mut self, duration: StdDuration) -> Self {
+        self.checks_mut().within_past(duration);
+        self
+    }
+
+    /// Expects the timestamp to be from now, and up to a future duration.
+    pub fn within_future(mut self, duration: StdDuration) -> Self {
+        self.checks_mut().within_future(duration);
+        self
+    }
+
+    ///
+    /// Expects the timestamp to be after the bound given.
+    ///
+    /// The bound can be an RFC 3339 string, a date such as `"2025-01-01"`,
+    /// a chrono `DateTime`, or a reference to another field using [`crate::expect::field`].
+    ///
+    pub fn greater_than<B>(self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self.uuid, "greater_than", bound);
+        self.push(TemporalComparison::GreaterThan(bound))
+    }
+
+    /// Expects the timestamp to be at or after the bound given.
+    pub fn greater_than_equal<B>(self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self.uuid, "greater_than_equal", bound);
+        self.push(TemporalComparison::GreaterThanEqual(bound))
+    }
+
+    /// Expects the timestamp to be before the bound given.
+    pub fn less_than<B>(self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self.uuid, "less_than", bound);
+        self.push(TemporalComparison::LessThan(bound))
+    }
+
+    /// Expects the timestamp to be at or before the bound given.
+    pub fn less_than_equal<B>(self, bound: B) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let bound = into_temporal_bound(&self.uuid, "less_than_equal", bound);
+        self.push(TemporalComparison::LessThanEqual(bound))
+    }
+
+    /// Expects the timestamp to be between the two bounds given, inclusive.
+    pub fn between<B1, B2>(self, min: B1, max: B2) -> Self
+    where
+        B1: Into<ValueOrFieldRef<String>>,
+        B2: Into<ValueOrFieldRef<String>>,
+    {
+        let min = into_temporal_bound(&self.uuid, "between", min);
+        let max = into_temporal_bound(&self.uuid, "between", max);
+        self.push(TemporalComparison::Between { min, max })
+    }
+
+    /// Expects the timestamp to be within the tolerance given of an instant, either before or after it.
+    pub fn around<B>(self, instant: B, tolerance: StdDuration) -> Self
+    where
+        B: Into<ValueOrFieldRef<String>>,
+    {
+        let expected = into_temporal_bound(&self.uuid, "around", instant);
+        self.push(TemporalComparison::Around {
+            expected,
+            tolerance,
+        })
+    }
+
+    fn push(mut self, comparison: TemporalComparison) -> Self {
+        self.checks_mut().push(comparison);
+        self
+    }
+
+    fn checks_mut(&mut self) -> &mut TemporalChecks {
+        self.uuid.maybe_timestamp_checks.get_or_insert_default()
+    }
+}
+
+impl From<ExpectUuidTimestamp> for ExpectUuid {
+    fn from(timestamp: ExpectUuidTimestamp) -> Self {
+        timestamp.uuid
+    }
+}
+
+impl From<ExpectUuidTimestamp> for Value {
+    fn from(timestamp: ExpectUuidTimestamp) -> Self {
+        Value::from(timestamp.uuid)
+    }
+}
+
+impl Serialize for ExpectUuidTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.uuid.serialize(serializer)
+    }
+}
@@ -0,0 +1,679 @@
+use crate::ExpectJsonError;
+use crate::ExpectJsonResult;
+use crate::JsonInteger;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use crate::internals::json_segments_find;
+use crate::internals::objects::ValueTypeObject;
+use crate::internals::utils::parse_json_pointer;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+///
+/// A reference to another field, within the same received Json.
+///
+/// Paths starting with `/` are Json Pointers from the root, such as `"/created_at"`.
+/// Otherwise they are relative to the value being checked,
+/// where `..` moves up to the parent, such as `"../created_at"`.
+///
+/// You can build these using the [`crate::expect::field`] function.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldRef {
+    path: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maybe_aggregate: Option<FieldRefAggregate>,
+}
+
+/// How the values matched by a [`FieldRef`] are combined into one.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FieldRefAggregate {
+    Sum,
+}
+
+impl FieldRef {
+    pub(crate) fn new(path: String) -> Self {
+        Self {
+            path,
+            maybe_aggregate: None,
+        }
+    }
+
+    ///
+    /// Uses the sum of every number the path matches.
+    ///
+    /// A `*` segment in the path matches each item in an array,
+    /// or each value in an object, such as `"/items/*/price"`.
+    ///
+    /// ```rust
+    /// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+    /// #
+    /// # use axum::Router;
+    /// # use axum::extract::Json;
+    /// # use axum::routing::get;
+    /// # use axum_test::TestServer;
+    /// # use serde_json::json;
+    /// #
+    /// # let server = TestServer::new(Router::new());
+    /// #
+    /// use axum_test::expect_json;
+    ///
+    /// let server = TestServer::new(Router::new());
+    ///
+    /// server.get(&"/basket")
+    ///     .await
+    ///     .assert_json(&json!({
+    ///         "items": expect_json::array(),
+    ///         "total": expect_json::integer().equal_to(expect_json::field("/items/*/price").sum()),
+    ///     }));
+    /// #
+    /// # Ok(()) }
+    /// ```
+    ///
+    pub fn sum(mut self) -> Self {
+        self.maybe_aggregate = Some(FieldRefAggregate::Sum);
+        self
+    }
+
+    /// Finds the value referenced, returning it along with a context pointing at it.
+    ///
+    /// For aggregates, the context points at where the path first has a `*`.
+    pub(crate) fn find<'a>(
+        &self,
+        context: &'a Context<'_>,
+    ) -> ExpectJsonResult<(Context<'static>, Cow<'a, Value>)> {
+        let segments = self.to_segments(context)?;
+        let mut field_context: Context<'a> = context.to_root_context();
+
+        let Some(FieldRefAggregate::Sum) = self.maybe_aggregate else {
+            let value =
+                json_segments_find(&mut field_context, context.root(), segments, &self.path)?;
+            return Ok((field_context.to_static(), Cow::Borrowed(value)));
+        };
+
+        let wildcard_index = segments
+            .iter()
+            .position(|segment| segment == "*")
+            .unwrap_or(segments.len());
+        let (prefix, rest) = segments.split_at(wildcard_index);
+        let value = json_segments_find(
+            &mut field_context,
+            context.root(),
+            prefix.to_vec(),
+            &self.path,
+        )?;
+
+        let mut values = vec![];
+        self.find_all(&mut field_context.clone(), value, rest, &mut values)?;
+        let sum = self.sum_values(values)?;
+
+        Ok((field_context.to_static(), Cow::Owned(sum)))
+    }
+
+    /// Finds every value matching the segments, where `*` matches every item within.
+    fn find_all<'a>(
+        &self,
+        context: &mut Context<'a>,
+        current: &'a Value,
+        segments: &[String],
+        found: &mut Vec<(Context<'static>, &'a Value)>,
+    ) -> ExpectJsonResult<()> {
+        let Some((segment, rest)) = segments.split_first() else {
+            found.push((context.to_static(), current));
+            return Ok(());
+        };
+
+        if segment != "*" {
+            let mut item_context = context.clone();
+            let item = json_segments_find(
+                &mut item_context,
+                current,
+                vec![segment.clone()],
+                &self.path,
+            )?;
+            return self.find_all(&mut item_context, item, rest, found);
+        }
+
+        match current {
+            Value::Array(array) => {
+                for (index, item) in array.iter().enumerate() {
+                    context.push(index);
+                    let result = self.find_all(context, item, rest, found);
+                    context.pop();
+                    result?;
+                }
+            }
+            Value::Object(object) => {
+                for (key, item) in object {
+                    context.push(key.to_string());
+                    let result = self.find_all(context, item, rest, found);
+                    context.pop();
+                    result?;
+                }
+            }
+            _ => {
+                return Err(ExpectJsonError::JsonPointerNotFound {
+                    context: context.to_static(),
+                    json_type: current.into(),
+                    segment: segment.clone(),
+                    pointer: self.path.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Integers are summed as integers, unless there are floats or the sum overflows.
+    fn sum_values(&self, values: Vec<(Context<'static>, &Value)>) -> ExpectJsonResult<Value> {
+        let mut maybe_integer_sum = Some(0_i64);
+        let mut float_sum = 0.0;
+
+        for (value_context, value) in values {
+            let Value::Number(number) = value else {
+                return Err(ExpectJsonError::FieldRefSumNotNumber {
+                    context: value_context,
+                    path: self.path.clone(),
+                    received: value.clone().into(),
+                });
+            };
+
+            maybe_integer_sum = maybe_integer_sum
+                .zip(number.as_i64())
+                .and_then(|(sum, integer)| sum.checked_add(integer));
+            float_sum += number.as_f64().unwrap_or_default();
+        }
+
+        Ok(maybe_integer_sum.map_or_else(|| Value::from(float_sum), Value::from))
+    }
+
+    fn to_segments(&self, context: &Context<'_>) -> ExpectJsonResult<Vec<String>> {
+        if self.path.starts_with('/') {
+            return parse_json_pointer(&self.path).ok_or_else(|| {
+                ExpectJsonError::InvalidJsonPointer {
+                    pointer: self.path.clone(),
+                }
+            });
+        }
+
+        let mut segments = context.to_json_pointer_segments();
+        let relative_segments = self
+            .path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.replace("~1", "/").replace("~0", "~"));
+
+        for segment in relative_segments {
+            match segment.as_str() {
+                "." => {}
+                ".." => {
+                    segments
+                        .pop()
+                        .ok_or_else(|| ExpectJsonError::InvalidFieldRef {
+                            context: context.to_static(),
+                            path: self.path.clone(),
+                        })?;
+                }
+                _ => segments.push(segment),
+            }
+        }
+
+        Ok(segments)
+    }
+}
+
+impl Display for FieldRef {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self.maybe_aggregate {
+            Some(FieldRefAggregate::Sum) => write!(formatter, "sum of '{}'", self.path),
+            None => write!(formatter, "'{}'", self.path),
+        }
+    }
+}
+
+///
+/// A bound for a comparison, which is either a value given up front,
+/// or a [`FieldRef`] to read the value from the received Json.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ValueOrFieldRef<V> {
+    Value(V),
+    FieldRef(FieldRef),
+}
+
+impl<V> ValueOrFieldRef<V>
+where
+    V: Clone,
+{
+    /// Returns the value for this bound.
+    ///
+    /// For field references, the value found is converted with the function given.
+    /// The type name is used for the error when that fails.
+    pub(crate) fn resolve<O, F>(
+        &self,
+        expect_op: &O,
+        context: &Context<'_>,
+        type_name: &str,
+        from_value: F,
+    ) -> ExpectOpResult<ResolvedBound<V>>
+    where
+        O: ExpectOp,
+        F: FnOnce(&Value) -> Option<V>,
+    {
+        let field_ref = match self {
+            Self::Value(value) => {
+                return Ok(ResolvedBound {
+                    value: value.clone(),
+                    maybe_field_context: None,
+                });
+            }
+            Self::FieldRef(field_ref) => field_ref,
+        };
+
+        let (field_context, field_value) = field_ref.find(context)?;
+        let value = from_value(&field_value).ok_or_else(|| {
+            let error_message = format!(
+                "field {field_ref} at {field_context} is not {type_name},
+    received {}",
+                ValueTypeObject::from(field_value.into_owned())
+            );
+            ExpectOpError::custom(expect_op, context, error_message)
+        })?;
+
+        Ok(ResolvedBound {
+            value,
+            maybe_field_context: Some(field_context),
+        })
+    }
+}
+
+impl<V> From<FieldRef> for ValueOrFieldRef<V> {
+    fn from(field_ref: FieldRef) -> Self {
+        Self::FieldRef(field_ref)
+    }
+}
+
+impl From<&str> for ValueOrFieldRef<String> {
+    fn from(value: &str) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<String> for ValueOrFieldRef<String> {
+    fn from(value: String) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl<Tz> From<DateTime<Tz>> for ValueOrFieldRef<String>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn from(value: DateTime<Tz>) -> Self {
+        Self::Value(value.to_rfc3339())
+    }
+}
+
+impl From<NaiveDate> for ValueOrFieldRef<String> {
+    fn from(value: NaiveDate) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<NaiveTime> for ValueOrFieldRef<String> {
+    fn from(value: NaiveTime) -> Self {
+        Self::Value(value.to_string())
+    }
+}
+
+impl From<f64> for ValueOrFieldRef<f64> {
+    fn from(value: f64) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl From<JsonInteger> for ValueOrFieldRef<JsonInteger> {
+    fn from(value: JsonInteger) -> Self {
+        Self::Value(value)
+    }
+}
+
+macro_rules! impl_from_integer_for_value_or_field_ref {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for ValueOrFieldRef<JsonInteger> {
+                fn from(value: $integer) -> Self {
+                    Self::Value(JsonInteger::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer_for_value_or_field_ref!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize);
+
+///
+/// A bound for comparing dates and times, which is either
+/// a string to parse, such as `"2025-01-01"`, or a Unix timestamp.
+///
+/// Only [`crate::expect::unix_timestamp`] accepts timestamps.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TemporalBound {
+    Timestamp(JsonInteger),
+    Raw(String),
+}
+
+impl Display for TemporalBound {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Timestamp(timestamp) => write!(formatter, "{timestamp}"),
+            Self::Raw(raw) => write!(formatter, "{raw}"),
+        }
+    }
+}
+
+impl From<ValueOrFieldRef<String>> for ValueOrFieldRef<TemporalBound> {
+    fn from(bound: ValueOrFieldRef<String>) -> Self {
+        match bound {
+            ValueOrFieldRef::Value(raw) => Self::Value(TemporalBound::Raw(raw)),
+            ValueOrFieldRef::FieldRef(field_ref) => Self::FieldRef(field_ref),
+        }
+    }
+}
+
+impl From<&str> for ValueOrFieldRef<TemporalBound> {
+    fn from(value: &str) -> Self {
+        ValueOrFieldRef::<String>::from(value).into()
+    }
+}
+
+impl From<String> for ValueOrFieldRef<TemporalBound> {
+    fn from(value: String) -> Self {
+        ValueOrFieldRef::<String>::from(value).into()
+    }
+}
+
+impl<Tz> From<DateTime<Tz>> for ValueOrFieldRef<TemporalBound>
+where
+    Tz: TimeZone,
+    Tz::Offset: Display,
+{
+    fn from(value: DateTime<Tz>) -> Self {
+        ValueOrFieldRef::<String>::from(value).into()
+    }
+}
+
+impl From<NaiveDate> for ValueOrFieldRef<TemporalBound> {
+    fn from(value: NaiveDate) -> Self {
+        ValueOrFieldRef::<String>::from(value).into()
+    }
+}
+
+macro_rules! impl_from_integer_for_temporal_bound {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for ValueOrFieldRef<TemporalBound> {
+                fn from(value: $integer) -> Self {
+                    Self::Value(TemporalBound::Timestamp(JsonInteger::from(value)))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer_for_temporal_bound!(u32, i32, u64, i64);
+
+/// The value of a bound, and where it came from when it was a field reference.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ResolvedBound<V> {
+    pub value: V,
+    pub maybe_field_context: Option<Context<'static>>,
+}
+
+impl<V> ResolvedBound<V> {
+    pub(crate) fn map<F, W>(self, mapper: F) -> ResolvedBound<W>
+    where
+        F: FnOnce(V) -> W,
+    {
+        ResolvedBound {
+            value: mapper(self.value),
+            maybe_field_context: self.maybe_field_context,
+        }
+    }
+}
+
+impl<V> Display for ResolvedBound<V>
+where
+    V: Display,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.value)?;
+
+        if let Some(field_context) = &self.maybe_field_context {
+            write!(formatter, " (from {field_context})")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test_find {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_find_relative_fields() {
+        let left = json!({ "start": 1, "end": 5 });
+        let right = json!({
+            "start": expect::integer(),
+            "end": expect::integer().greater_than(expect::field("../start")),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_find_relative_fields_within_arrays() {
+        let left = json!({
+            "items": [
+                { "start": 1, "end": 5 },
+                { "start": 10, "end": 7 },
+            ],
+        });
+        let right = json!({
+            "items": expect::array().all(json!({
+                "start": expect::integer(),
+                "end": expect::integer().greater_than(expect::field("../start")),
+            })),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::integer() error at root.items[1].end:
+    integer is out of bounds,
+    expected greater than 10 (from root.items[1].start)
+    received 7
+    received full array [
+        {
+            "end": 5,
+            "start": 1
+        },
+        {
+            "end": 7,
+            "start": 10
+        }
+    ]"#
+        );
+    }
+
+    #[test]
+    fn it_should_find_absolute_fields() {
+        let left = json!({
+            "limit": 3,
+            "page": { "size": 3 },
+        });
+        let right = json!({
+            "limit": 3,
+            "page": {
+                "size": expect::integer().less_than_equal(expect::field("/limit")),
+            },
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_error_when_field_is_not_found() {
+        let left = json!({ "end": 5 });
+        let right = json!({
+            "end": expect::integer().greater_than(expect::field("../start")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json object at root has no value at 'start', for path '../start'"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_going_above_the_root() {
+        let left = json!(5);
+        let right = json!(expect::integer().greater_than(expect::field("../start")));
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json field reference '../start' at root is invalid, it goes above the root"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_field_is_the_wrong_type() {
+        let left = json!({ "start": "1", "end": 5 });
+        let right = json!({
+            "start": expect::string(),
+            "end": expect::integer().greater_than(expect::field("../start")),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::integer() error at root.end:
+    field '../start' at root.start is not an integer,
+    received string "1""#
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_sum {
+    use crate::expect;
+    use crate::expect_json_eq;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_sum_integers_across_arrays() {
+        let left = json!({
+            "items": [{ "price": 10 }, { "price": 20 }, { "price": 5 }],
+            "total": 35,
+        });
+        let right = json!({
+            "items": expect::array(),
+            "total": expect::integer().equal_to(expect::field("/items/*/price").sum()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_sum_floats_across_objects() {
+        let left = json!({
+            "prices": { "apple": 0.1, "pear": 0.2 },
+            "total": 0.3,
+        });
+        let right = json!({
+            "prices": expect::object(),
+            "total": expect::float().approx(expect::field("../prices/*").sum()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_sum_to_zero_for_empty_arrays() {
+        let left = json!({ "items": [], "total": 0 });
+        let right = json!({
+            "items": [],
+            "total": expect::integer().equal_to(expect::field("/items/*/price").sum()),
+        });
+
+        let output = expect_json_eq(&left, &right);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_error_when_sum_is_different() {
+        let left = json!({
+            "items": [{ "price": 10 }, { "price": 20 }],
+            "total": 25,
+        });
+        let right = json!({
+            "items": expect::array(),
+            "total": expect::integer().equal_to(expect::field("/items/*/price").sum()),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json expect::integer() error at root.total:
+    integer is out of bounds,
+    expected equal to 30 (from root.items)
+    received 25"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_summing_non_numbers() {
+        let left = json!({
+            "items": [{ "price": 10 }, { "price": "20" }],
+            "total": 30,
+        });
+        let right = json!({
+            "items": expect::array(),
+            "total": expect::integer().equal_to(expect::field("/items/*/price").sum()),
+        });
+
+        let output = expect_json_eq(&left, &right).unwrap_err().to_string();
+        assert_eq!(
+            output,
+            r#"Json field reference '/items/*/price' at root.items[1].price can only sum numbers,
+    received string "20""#
+        );
+    }
+}
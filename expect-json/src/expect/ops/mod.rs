@@ -25,7 +25,52 @@ pub use self::expect_email::*;
 mod expect_iso_date_time;
 pub use self::expect_iso_date_time::*;
 
+mod expect_date;
+pub use self::expect_date::*;
+
+mod expect_time;
+pub use self::expect_time::*;
+
+mod expect_date_time_format;
+pub use self::expect_date_time_format::*;
+
+mod expect_unix_timestamp;
+pub use self::expect_unix_timestamp::*;
+
 mod expect_uuid;
 pub use self::expect_uuid::*;
 
+mod expect_uuid_timestamp;
+pub use self::expect_uuid_timestamp::*;
+
+mod expect_any_of;
+pub use self::expect_any_of::*;
+
+mod expect_all_of;
+pub use self::expect_all_of::*;
+
+mod expect_not;
+pub use self::expect_not::*;
+
+mod expect_optional;
+pub use self::expect_optional::*;
+
+mod expect_absent;
+pub use self::expect_absent::*;
+
+mod expect_at;
+pub use self::expect_at::*;
+
+mod expect_schema;
+pub use self::expect_schema::*;
+
+mod expect_capture;
+pub use self::expect_capture::*;
+
+mod expect_same_as;
+pub use self::expect_same_as::*;
+
+mod field_ref;
+pub use self::field_ref::*;
+
 mod utils;
@@ -68,6 +68,19 @@ impl Display for DurationFormatter {
             } else {
                 write!(f, "{seconds} seconds")?;
             }
+            has_written = true;
+        }
+
+        // Durations under a second would otherwise print nothing.
+        if !has_written {
+            let milliseconds = self.0.num_milliseconds();
+            if milliseconds == 1 {
+                write!(f, "1 millisecond")?;
+            } else if milliseconds > 0 {
+                write!(f, "{milliseconds} milliseconds")?;
+            } else {
+                write!(f, "0 seconds")?;
+            }
         }
 
         Ok(())
@@ -133,6 +146,19 @@ mod test_fmt {
         assert_eq!(formatter.to_string(), "4 seconds");
     }
 
+    #[test]
+    fn it_should_format_milliseconds_under_a_second() {
+        let duration = Duration::milliseconds(250);
+        let formatter = DurationFormatter::new(duration);
+        assert_eq!(formatter.to_string(), "250 milliseconds");
+    }
+
+    #[test]
+    fn it_should_format_zero() {
+        let formatter = DurationFormatter::new(Duration::zero());
+        assert_eq!(formatter.to_string(), "0 seconds");
+    }
+
     #[test]
     fn it_should_format_hours() {
         let duration = Duration::minutes(60 * 2);
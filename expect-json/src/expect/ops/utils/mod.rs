@@ -3,3 +3,6 @@ pub use self::duration_formatter::*;
 
 mod serializable_bound;
 pub use self::serializable_bound::*;
+
+mod temporal;
+pub(crate) use self::temporal::*;
@@ -0,0 +1,556 @@
+use crate::JsonInteger;
+use crate::expect::ops::ResolvedBound;
+use crate::expect::ops::TemporalBound;
+use crate::expect::ops::ValueOrFieldRef;
+use crate::expect::ops::utils::DurationFormatter;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOp;
+use crate::expect_core::ExpectOpError;
+use crate::expect_core::ExpectOpResult;
+use chrono::DateTime;
+use chrono::Duration as ChronoDuration;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::time::Duration as StdDuration;
+
+/// A date, time, or date time, which can be compared and placed relative to now.
+pub(crate) trait Temporal: Copy + Ord {
+    fn from_now(now: DateTime<Utc>) -> Self;
+
+    /// How far this is after the earlier value. This is negative when it is before.
+    ///
+    /// This follows the same order as comparisons, for describing how far a bound was missed.
+    fn duration_since(self, earlier: Self) -> ChronoDuration;
+
+    /// The same as [`Self::duration_since`], except values which wrap around,
+    /// such as times of day, take the shortest way between the two.
+    fn nearest_duration_since(self, earlier: Self) -> ChronoDuration {
+        self.duration_since(earlier)
+    }
+}
+
+impl Temporal for DateTime<FixedOffset> {
+    fn from_now(now: DateTime<Utc>) -> Self {
+        now.fixed_offset()
+    }
+
+    fn duration_since(self, earlier: Self) -> ChronoDuration {
+        self.signed_duration_since(earlier)
+    }
+}
+
+impl Temporal for NaiveDate {
+    fn from_now(now: DateTime<Utc>) -> Self {
+        now.date_naive()
+    }
+
+    fn duration_since(self, earlier: Self) -> ChronoDuration {
+        self.signed_duration_since(earlier)
+    }
+}
+
+impl Temporal for NaiveTime {
+    fn from_now(now: DateTime<Utc>) -> Self {
+        now.time()
+    }
+
+    fn duration_since(self, earlier: Self) -> ChronoDuration {
+        self.signed_duration_since(earlier)
+    }
+
+    /// Times of day wrap around midnight, so this is the shortest way around the clock.
+    fn nearest_duration_since(self, earlier: Self) -> ChronoDuration {
+        let day = ChronoDuration::days(1);
+        let duration = self.signed_duration_since(earlier);
+
+        if duration > day / 2 {
+            duration - day
+        } else if duration <= -(day / 2) {
+            duration + day
+        } else {
+            duration
+        }
+    }
+}
+
+/// An op which checks a [`Temporal`] value, using [`TemporalChecks`].
+pub(crate) trait ExpectTemporal: ExpectOp + Sized {
+    type Value: Temporal;
+
+    /// The function for building the op, used in panics, such as `"iso_date_time"`.
+    const FUNCTION_NAME: &'static str;
+
+    /// Used at the start of relative window errors, such as `"ISO datetime"`.
+    const LABEL: &'static str;
+
+    /// Used in comparison errors, such as `"date time"`.
+    const TYPE_NAME: &'static str;
+
+    /// Set when timestamp bounds are read in a unit which can be changed after the bound is given,
+    /// such as with `.millis()`. These bounds are then checked when matching, rather than when given.
+    const HAS_TIMESTAMP_UNIT: bool = false;
+
+    /// Describes the bounds accepted, such as `"an iso date time"`.
+    fn bound_description(&self) -> String;
+
+    fn parse_bound(&self, raw: &str) -> Option<Self::Value>;
+
+    /// Parses a Unix timestamp given as a bound. Only some ops accept these.
+    fn parse_timestamp_bound(&self, _timestamp: JsonInteger) -> Option<Self::Value> {
+        None
+    }
+
+    /// Returns the bound held in a field that is referenced, for parsing.
+    fn field_to_bound(&self, value: &Value) -> Option<TemporalBound> {
+        value
+            .as_str()
+            .map(|raw| TemporalBound::Raw(raw.to_string()))
+    }
+}
+
+/// The range and relative window checks shared by the date and time ops.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct TemporalChecks {
+    maybe_past_duration: Option<StdDuration>,
+    maybe_future_duration: Option<StdDuration>,
+    comparisons: Vec<TemporalComparison>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum TemporalComparison {
+    GreaterThan(ValueOrFieldRef<TemporalBound>),
+    GreaterThanEqual(ValueOrFieldRef<TemporalBound>),
+    LessThan(ValueOrFieldRef<TemporalBound>),
+    LessThanEqual(ValueOrFieldRef<TemporalBound>),
+    Between {
+        min: ValueOrFieldRef<TemporalBound>,
+        max: ValueOrFieldRef<TemporalBound>,
+    },
+    EqInstant(ValueOrFieldRef<TemporalBound>),
+    Around {
+        expected: ValueOrFieldRef<TemporalBound>,
+        tolerance: StdDuration,
+    },
+}
+
+impl TemporalChecks {
+    pub(crate) fn within_past(&mut self, duration: StdDuration) {
+        self.maybe_past_duration = Some(duration);
+    }
+
+    pub(crate) fn within_future(&mut self, duration: StdDuration) {
+        self.maybe_future_duration = Some(duration);
+    }
+
+    pub(crate) fn push(&mut self, comparison: TemporalComparison) {
+        self.comparisons.push(comparison);
+    }
+
+    /// Fills in the relative window from durations held outside of the checks,
+    /// such as by ops serialised before these checks existed.
+    pub(crate) fn with_fallback_window(
+        &self,
+        maybe_past_duration: Option<StdDuration>,
+        maybe_future_duration: Option<StdDuration>,
+    ) -> Cow<'_, Self> {
+        if maybe_past_duration.is_none() && maybe_future_duration.is_none() {
+            return Cow::Borrowed(self);
+        }
+
+        Cow::Owned(Self {
+            maybe_past_duration: self.maybe_past_duration.or(maybe_past_duration),
+            maybe_future_duration: self.maybe_future_duration.or(maybe_future_duration),
+            comparisons: self.comparisons.clone(),
+        })
+    }
+
+    pub(crate) fn check<O>(
+        &self,
+        expect_op: &O,
+        context: &mut Context<'_>,
+        received_raw: &str,
+        received: O::Value,
+    ) -> ExpectOpResult<()>
+    where
+        O: ExpectTemporal,
+    {
+        for comparison in &self.comparisons {
+            comparison.check(expect_op, context, received_raw, received)?;
+        }
+
+        self.check_window(expect_op, context, received_raw, received)
+    }
+
+    fn check_window<O>(
+        &self,
+        expect_op: &O,
+        context: &mut Context<'_>,
+        received_raw: &str,
+        received: O::Value,
+    ) -> ExpectOpResult<()>
+    where
+        O: ExpectTemporal,
+    {
+        let label = O::LABEL;
+        let now = O::Value::from_now(context.now());
+        let since_received = now.nearest_duration_since(received);
+        let until_received = received.nearest_duration_since(now);
+
+        let error_message = match (self.maybe_past_duration, self.maybe_future_duration) {
+            (None, None) => None,
+            (Some(past_duration), None) => {
+                let duration = format_std_duration(past_duration);
+                if since_received > to_chrono_duration(past_duration) {
+                    Some(format!(
+                        "{label} '{received_raw}' is too far from the past, expected between '{duration}' ago and now"
+                    ))
+                } else if since_received < ChronoDuration::zero() {
+                    Some(format!(
+                        "{label} '{received_raw}' is in the future of now, expected between '{duration}' ago and now"
+                    ))
+                } else {
+                    None
+                }
+            }
+            (None, Some(future_duration)) => {
+                let duration = format_std_duration(future_duration);
+                if until_received > to_chrono_duration(future_duration) {
+                    Some(format!(
+                        "{label} '{received_raw}' is too far in the future, expected between now and '{duration}' in the future"
+                    ))
+                } else if until_received < ChronoDuration::zero() {
+                    Some(format!(
+                        "{label} '{received_raw}' is in the past of now, expected between now and '{duration}' in the future"
+                    ))
+                } else {
+                    None
+                }
+            }
+            (Some(past_duration), Some(future_duration)) => {
+                if since_received > to_chrono_duration(past_duration) {
+                    let duration = format_std_duration(past_duration);
+                    Some(format!(
+                        "{label} '{received_raw}' is too far from the past, expected between '{duration}' ago and now"
+                    ))
+                } else if until_received > to_chrono_duration(future_duration) {
+                    let duration = format_std_duration(future_duration);
+                    Some(format!(
+                        "{label} '{received_raw}' is too far in the future, expected between now and '{duration}' in the future"
+                    ))
+                } else {
+                    None
+                }
+            }
+        };
+
+        match error_message {
+            Some(error_message) => Err(ExpectOpError::custom(expect_op, context, error_message)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TemporalComparison {
+    fn check<O>(
+        &self,
+        expect_op: &O,
+        context: &mut Context<'_>,
+        received_raw: &str,
+        received: O::Value,
+    ) -> ExpectOpResult<()>
+    where
+        O: ExpectTemporal,
+    {
+        let type_name = O::TYPE_NAME;
+        let (bound, comparison, comparison_name): (_, fn(&_, &_) -> bool, _) = match self {
+            Self::GreaterThan(bound) => (bound, O::Value::gt, "greater than"),
+            Self::GreaterThanEqual(bound) => (bound, O::Value::ge, "greater than equal"),
+            Self::LessThan(bound) => (bound, O::Value::lt, "less than"),
+            Self::LessThanEqual(bound) => (bound, O::Value::le, "less than equal"),
+            Self::Between { min, max } => {
+                let (min_bound, min) = resolve_bound(expect_op, context, min)?;
+                let (max_bound, max) = resolve_bound(expect_op, context, max)?;
+
+                if min > max {
+                    let error_message = format!(
+                        "{type_name} range is empty, the minimum is after the maximum,
+    expected {min_bound} to {max_bound}"
+                    );
+                    return Err(ExpectOpError::custom(expect_op, context, error_message));
+                }
+
+                let maybe_difference = if received < min {
+                    Some(min.duration_since(received))
+                } else if received > max {
+                    Some(received.duration_since(max))
+                } else {
+                    None
+                };
+
+                if let Some(difference) = maybe_difference {
+                    let error_message = format!(
+                        "{type_name} is not in range,
+    expected {min_bound} to {max_bound}
+    received {received_raw}
+    difference {}",
+                        DurationFormatter::new(difference)
+                    );
+                    return Err(ExpectOpError::custom(expect_op, context, error_message));
+                }
+
+                return Ok(());
+            }
+            Self::EqInstant(expected) => {
+                let (expected_bound, expected) = resolve_bound(expect_op, context, expected)?;
+
+                if received != expected {
+                    let error_message = format!(
+                        "{type_name} is not the same instant,
+    expected {expected_bound}
+    received {received_raw}
+    difference {}",
+                        DurationFormatter::new(received.duration_since(expected).abs())
+                    );
+                    return Err(ExpectOpError::custom(expect_op, context, error_message));
+                }
+
+                return Ok(());
+            }
+            Self::Around {
+                expected,
+                tolerance,
+            } => {
+                let (expected_bound, expected) = resolve_bound(expect_op, context, expected)?;
+                let difference = received.nearest_duration_since(expected).abs();
+
+                if difference > to_chrono_duration(*tolerance) {
+                    let error_message = format!(
+                        "{type_name} is not around the instant expected,
+    expected {expected_bound}, within {}
+    received {received_raw}
+    difference {}",
+                        format_std_duration(*tolerance),
+                        DurationFormatter::new(difference)
+                    );
+                    return Err(ExpectOpError::custom(expect_op, context, error_message));
+                }
+
+                return Ok(());
+            }
+        };
+
+        let (bound, bound_value) = resolve_bound(expect_op, context, bound)?;
+        if !comparison(&received, &bound_value) {
+            let mut error_message = format!(
+                "{type_name} is out of bounds,
+    expected {comparison_name} {bound}
+    received {received_raw}"
+            );
+
+            // Equal values fail the strict comparisons, where a difference of zero says nothing.
+            let difference = received.duration_since(bound_value).abs();
+            if !difference.is_zero() {
+                error_message = format!(
+                    "{error_message}
+    difference {}",
+                    DurationFormatter::new(difference)
+                );
+            }
+
+            return Err(ExpectOpError::custom(expect_op, context, error_message));
+        }
+
+        Ok(())
+    }
+}
+
+/// Bounds given up front are checked when they are given, so mistakes are found early.
+pub(crate) fn into_temporal_bound<O, B>(
+    expect_op: &O,
+    method_name: &str,
+    bound: B,
+) -> ValueOrFieldRef<TemporalBound>
+where
+    O: ExpectTemporal,
+    B: Into<ValueOrFieldRef<String>>,
+{
+    check_temporal_bound(expect_op, method_name, bound.into().into())
+}
+
+/// The same as [`into_temporal_bound`], for both ends of a range.
+pub(crate) fn into_temporal_range<O, B1, B2>(
+    expect_op: &O,
+    method_name: &str,
+    min: B1,
+    max: B2,
+) -> (
+    ValueOrFieldRef<TemporalBound>,
+    ValueOrFieldRef<TemporalBound>,
+)
+where
+    O: ExpectTemporal,
+    B1: Into<ValueOrFieldRef<String>>,
+    B2: Into<ValueOrFieldRef<String>>,
+{
+    check_temporal_range(expect_op, method_name, min.into().into(), max.into().into())
+}
+
+/// Panics if the bound is a value which cannot be parsed by the op.
+pub(crate) fn check_temporal_bound<O>(
+    expect_op: &O,
+    method_name: &str,
+    bound: ValueOrFieldRef<TemporalBound>,
+) -> ValueOrFieldRef<TemporalBound>
+where
+    O: ExpectTemporal,
+{
+    if let ValueOrFieldRef::Value(value) = &bound
+        && is_checked_when_given::<O>(value)
+        && parse_temporal_bound(expect_op, value).is_none()
+    {
+        panic!(
+            "{}().{method_name}() expected {}. Received: '{value}'",
+            O::FUNCTION_NAME,
+            expect_op.bound_description()
+        );
+    }
+
+    bound
+}
+
+/// The same as [`check_temporal_bound`], for both ends of a range.
+///
+/// Ranges never wrap around, such as across midnight for times,
+/// so the minimum given must not be after the maximum.
+pub(crate) fn check_temporal_range<O>(
+    expect_op: &O,
+    method_name: &str,
+    min: ValueOrFieldRef<TemporalBound>,
+    max: ValueOrFieldRef<TemporalBound>,
+) -> (
+    ValueOrFieldRef<TemporalBound>,
+    ValueOrFieldRef<TemporalBound>,
+)
+where
+    O: ExpectTemporal,
+{
+    let min = check_temporal_bound(expect_op, method_name, min);
+    let max = check_temporal_bound(expect_op, method_name, max);
+    if let (ValueOrFieldRef::Value(min_value), ValueOrFieldRef::Value(max_value)) = (&min, &max)
+        && is_checked_when_given::<O>(min_value)
+        && is_checked_when_given::<O>(max_value)
+        && parse_temporal_bound(expect_op, min_value) > parse_temporal_bound(expect_op, max_value)
+    {
+        panic!(
+            "{}().{method_name}() expected the minimum to not be after the maximum. Received: '{min_value}' to '{max_value}'",
+            O::FUNCTION_NAME,
+        );
+    }
+
+    (min, max)
+}
+
+/// Timestamps in a unit which can still change are left to be checked when matching.
+fn is_checked_when_given<O>(bound: &TemporalBound) -> bool
+where
+    O: ExpectTemporal,
+{
+    !(O::HAS_TIMESTAMP_UNIT && matches!(bound, TemporalBound::Timestamp(_)))
+}
+
+fn parse_temporal_bound<O>(expect_op: &O, bound: &TemporalBound) -> Option<O::Value>
+where
+    O: ExpectTemporal,
+{
+    match bound {
+        TemporalBound::Timestamp(timestamp) => expect_op.parse_timestamp_bound(*timestamp),
+        TemporalBound::Raw(raw) => expect_op.parse_bound(raw),
+    }
+}
+
+/// Parses an RFC 3339 date time, or a date on its own as midnight UTC.
+pub(crate) fn parse_date_time_bound(raw: &str) -> Option<DateTime<FixedOffset>> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(raw) {
+        return Some(date_time);
+    }
+
+    let date = NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok()?;
+    Some(date.and_time(NaiveTime::MIN).and_utc().fixed_offset())
+}
+
+fn resolve_bound<O>(
+    expect_op: &O,
+    context: &Context<'_>,
+    bound: &ValueOrFieldRef<TemporalBound>,
+) -> ExpectOpResult<(ResolvedBound<TemporalBound>, O::Value)>
+where
+    O: ExpectTemporal,
+{
+    let bound_description = expect_op.bound_description();
+    let bound = bound.resolve(expect_op, context, &bound_description, |value| {
+        expect_op
+            .field_to_bound(value)
+            .filter(|bound| parse_temporal_bound(expect_op, bound).is_some())
+    })?;
+
+    let bound_value = parse_temporal_bound(expect_op, &bound.value).ok_or_else(|| {
+        let error_message = format!(
+            "failed to parse bound '{}' as {bound_description}",
+            bound.value
+        );
+        ExpectOpError::custom(expect_op, context, error_message)
+    })?;
+
+    Ok((bound, bound_value))
+}
+
+fn to_chrono_duration(duration: StdDuration) -> ChronoDuration {
+    ChronoDuration::from_std(duration).unwrap_or(ChronoDuration::MAX)
+}
+
+fn format_std_duration(duration: StdDuration) -> DurationFormatter {
+    DurationFormatter::new(to_chrono_duration(duration))
+}
+
+#[cfg(test)]
+mod test_duration_since {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_not_wrap_times_when_ordered() {
+        let before_midnight = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+        let after_midnight = NaiveTime::from_hms_opt(1, 0, 0).unwrap();
+
+        let output = before_midnight.duration_since(after_midnight);
+        assert_eq!(output, ChronoDuration::hours(22));
+    }
+
+    #[test]
+    fn it_should_wrap_times_around_midnight_when_nearest() {
+        let before_midnight = NaiveTime::from_hms_opt(23, 50, 0).unwrap();
+        let after_midnight = NaiveTime::from_hms_opt(0, 20, 0).unwrap();
+
+        let output = after_midnight.nearest_duration_since(before_midnight);
+        assert_eq!(output, ChronoDuration::minutes(30));
+
+        let output = before_midnight.nearest_duration_since(after_midnight);
+        assert_eq!(output, ChronoDuration::minutes(-30));
+    }
+
+    #[test]
+    fn it_should_not_wrap_times_within_half_a_day_when_nearest() {
+        let morning = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        let evening = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+
+        let output = evening.nearest_duration_since(morning);
+        assert_eq!(output, ChronoDuration::hours(8));
+    }
+}
@@ -1,16 +1,32 @@
+use crate::ExpectJsonCaptures;
+use crate::ExpectJsonError;
+use crate::ExpectJsonOptions;
 use crate::ExpectJsonResult;
+use crate::expect_core::CapturesCheckpoint;
+use crate::expect_core::ContextShared;
 use crate::expect_core::ContextWith;
+use crate::expect_core::ExpectOpError;
 use crate::expect_core::context::ContextPathPart;
 use crate::internals::json_eq;
+use crate::internals::utils::parse_json_pointer;
+use chrono::DateTime;
+use chrono::Utc;
 use serde_json::Value;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
+use std::sync::Arc;
 
-#[derive(Clone, Default, Debug, PartialEq)]
+/// The root used by contexts outside of an assertion, such as those held in errors.
+static NULL_ROOT: Value = Value::Null;
+
+#[derive(Clone, Debug)]
 pub struct Context<'c> {
     stack: Vec<ContextPathPart<'c>>,
     is_propagated_contains: bool,
+    is_collecting_all_errors: bool,
+    root: &'c Value,
+    shared: Arc<ContextShared>,
 }
 
 impl<'c> Context<'c> {
@@ -18,6 +34,96 @@ impl<'c> Context<'c> {
         Self::default()
     }
 
+    pub(crate) fn with_root(root: &'c Value, options: ExpectJsonOptions) -> Self {
+        Self {
+            is_collecting_all_errors: options.is_collecting_all_errors(),
+            root,
+            shared: Arc::new(ContextShared::new(options)),
+            ..Self::default()
+        }
+    }
+
+    /// The time now, for expectations relative to the current time.
+    ///
+    /// This is read from the clock in the options, the thread clock, or the system clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.options().now()
+    }
+
+    /// The whole of the received Json, that this context is within.
+    pub fn root(&self) -> &'c Value {
+        self.root
+    }
+
+    /// Returns a new context pointing at the root, for the same assertion.
+    pub(crate) fn to_root_context(&self) -> Context<'c> {
+        Context {
+            stack: vec![],
+            shared: self.shared.clone(),
+            ..*self
+        }
+    }
+
+    /// The path of this context, as unescaped Json Pointer segments.
+    pub(crate) fn to_json_pointer_segments(&self) -> Vec<String> {
+        self.stack
+            .iter()
+            .map(ContextPathPart::to_string_segment)
+            .collect()
+    }
+
+    /// The options given for the whole assertion.
+    pub fn options(&self) -> &ExpectJsonOptions {
+        self.shared.options()
+    }
+
+    /// Returns the value captured under the name, or captures the value given if there is none.
+    pub(crate) fn get_or_bind_capture(&self, name: &str, value: &Value) -> Option<Value> {
+        self.shared.get_or_bind_capture(name, value)
+    }
+
+    /// The same as [`Self::get_or_bind_capture`], for `same_as`, which must also be captured elsewhere.
+    pub(crate) fn get_or_bind_same_as(&self, name: &str, value: &Value) -> Option<Value> {
+        let path = self.stack.iter().map(ContextPathPart::to_static).collect();
+        self.shared.get_or_bind_same_as(name, value, path)
+    }
+
+    /// Errors for each `same_as` where nothing was captured under the same name.
+    pub(crate) fn check_same_as_captured(&self) -> ExpectJsonResult<()> {
+        let mut errors = self
+            .shared
+            .to_uncaptured_same_as()
+            .into_iter()
+            .map(|(name, stack)| {
+                let context = Context {
+                    stack,
+                    ..self.to_root_context().to_static()
+                };
+
+                ExpectJsonError::from(ExpectOpError::SameAsNotCaptured { context, name })
+            })
+            .collect::<Vec<_>>();
+
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(ExpectJsonError::multiple(errors)),
+        }
+    }
+
+    /// Saves what has been captured so far, for comparisons that are allowed to fail.
+    ///
+    /// Use this around trial matches, such as each branch of an `any_of`,
+    /// so values captured by a failed match are not kept.
+    pub(crate) fn captures_checkpoint(&self) -> CapturesCheckpoint {
+        self.shared.captures_checkpoint()
+    }
+
+    /// Returns everything captured so far, across the whole assertion.
+    pub(crate) fn to_captures(&self) -> ExpectJsonCaptures {
+        self.shared.to_captures()
+    }
+
     pub fn json_eq(&self, received: &'c Value, expected: &'c Value) -> ExpectJsonResult<()> {
         json_eq(&mut self.clone(), received, expected)
     }
@@ -49,6 +155,12 @@ impl<'c> Context<'c> {
         self.is_propagated_contains
     }
 
+    /// When true, comparisons keep going after a failure,
+    /// so every mismatch can be reported at once.
+    pub(crate) fn is_collecting_all_errors(&self) -> bool {
+        self.is_collecting_all_errors
+    }
+
     pub(crate) fn push<P>(&mut self, path: P)
     where
         P: Into<ContextPathPart<'c>>,
@@ -60,10 +172,73 @@ impl<'c> Context<'c> {
         self.stack.pop();
     }
 
+    /// Copies the root into the state shared with errors, for when they are displayed.
+    ///
+    /// This is only done once the assertion has failed, to avoid copying the root otherwise.
+    pub(crate) fn keep_root_for_errors(&self) {
+        self.shared.keep_root(self.root);
+    }
+
+    /// Returns a copy of this context using the root kept by [`Self::keep_root_for_errors`],
+    /// for comparing values when errors are displayed.
+    pub(crate) fn with_kept_root(&self) -> Context<'_> {
+        Context {
+            stack: self.stack.clone(),
+            root: self.shared.kept_root().unwrap_or(&NULL_ROOT),
+            shared: self.shared.clone(),
+            ..*self
+        }
+    }
+
+    /// Returns a copy of this context which does not borrow anything, for use in errors.
+    ///
+    /// The root is borrowed, so it is not kept. Clone the context instead
+    /// when it is used for further comparisons.
     pub(crate) fn to_static(&self) -> Context<'static> {
         let stack = self.stack.iter().map(ContextPathPart::to_static).collect();
 
-        Context { stack, ..*self }
+        Context {
+            stack,
+            root: &NULL_ROOT,
+            shared: self.shared.clone(),
+            ..*self
+        }
+    }
+
+    /// Returns true if this context is at the location of the Json Pointer given.
+    /// A `*` segment in the pointer matches any field or index.
+    pub(crate) fn matches_json_pointer(&self, pointer: &str) -> bool {
+        let Some(segments) = parse_json_pointer(pointer) else {
+            return false;
+        };
+
+        segments.len() == self.stack.len()
+            && segments
+                .iter()
+                .zip(&self.stack)
+                .all(|(segment, path)| segment == "*" || path.matches_json_pointer_segment(segment))
+    }
+}
+
+impl Default for Context<'_> {
+    fn default() -> Self {
+        Self {
+            stack: vec![],
+            is_propagated_contains: false,
+            is_collecting_all_errors: false,
+            root: &NULL_ROOT,
+            shared: Arc::default(),
+        }
+    }
+}
+
+/// The root is not part of where a context is, so it is not compared.
+impl PartialEq for Context<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.stack == other.stack
+            && self.is_propagated_contains == other.is_propagated_contains
+            && self.is_collecting_all_errors == other.is_collecting_all_errors
+            && self.shared == other.shared
     }
 }
 
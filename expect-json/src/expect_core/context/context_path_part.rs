@@ -1,4 +1,5 @@
 use crate::internals::utils::is_unquotable_js_identifier;
+use crate::internals::utils::parse_json_pointer_index;
 use std::borrow::Cow;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -8,6 +9,9 @@ use std::fmt::Result as FmtResult;
 pub enum ContextPathPart<'a> {
     String(Cow<'a, str>),
     Index(usize),
+
+    /// The named groups captured by a regex, which are compared as an object of strings.
+    RegexCaptures,
 }
 
 impl ContextPathPart<'_> {
@@ -19,6 +23,25 @@ impl ContextPathPart<'_> {
                 ContextPathPart::String(cow)
             }
             Self::Index(index) => ContextPathPart::Index(*index),
+            Self::RegexCaptures => ContextPathPart::RegexCaptures,
+        }
+    }
+
+    /// Returns this as an unescaped segment of a Json Pointer.
+    pub(crate) fn to_string_segment(&self) -> String {
+        match self {
+            Self::String(inner) => inner.to_string(),
+            Self::Index(index) => index.to_string(),
+            Self::RegexCaptures => "<captures>".to_string(),
+        }
+    }
+
+    /// Compares against an unescaped segment of a Json Pointer.
+    pub(crate) fn matches_json_pointer_segment(&self, segment: &str) -> bool {
+        match self {
+            Self::String(inner) => *inner == segment,
+            Self::Index(index) => parse_json_pointer_index(segment) == Some(*index),
+            Self::RegexCaptures => false,
         }
     }
 }
@@ -34,6 +57,7 @@ impl Display for ContextPathPart<'_> {
                 }
             }
             Self::Index(inner) => write!(formatter, "[{inner}]"),
+            Self::RegexCaptures => write!(formatter, "<captures>"),
         }
     }
 }
@@ -44,6 +68,12 @@ impl<'a> From<&'a String> for ContextPathPart<'a> {
     }
 }
 
+impl From<String> for ContextPathPart<'_> {
+    fn from(inner: String) -> Self {
+        Self::String(Cow::Owned(inner))
+    }
+}
+
 impl From<usize> for ContextPathPart<'_> {
     fn from(inner: usize) -> Self {
         Self::Index(inner)
@@ -0,0 +1,143 @@
+use crate::ExpectJsonCaptures;
+use crate::ExpectJsonOptions;
+use crate::expect_core::context::ContextPathPart;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+
+/// State shared by every context, across the whole of an assertion.
+#[derive(Debug, Default)]
+pub(crate) struct ContextShared {
+    options: ExpectJsonOptions,
+    captures: Mutex<Captures>,
+
+    /// A copy of the root, only made when the assertion fails,
+    /// so errors can still compare values when they are displayed.
+    kept_root: OnceLock<Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Captures {
+    values: Map<String, Value>,
+
+    /// Names bound by `same_as`, where no `capture` has been found yet.
+    /// Each holds the path of the first `same_as` using the name.
+    uncaptured_same_as: BTreeMap<String, Vec<ContextPathPart<'static>>>,
+}
+
+impl ContextShared {
+    pub(crate) fn new(options: ExpectJsonOptions) -> Self {
+        Self {
+            options,
+            captures: Mutex::default(),
+            kept_root: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn keep_root(&self, root: &Value) {
+        self.kept_root.get_or_init(|| root.clone());
+    }
+
+    pub(crate) fn kept_root(&self) -> Option<&Value> {
+        self.kept_root.get()
+    }
+
+    pub(crate) fn options(&self) -> &ExpectJsonOptions {
+        &self.options
+    }
+
+    /// Returns the value bound to the name, or binds the value given if there is none.
+    pub(crate) fn get_or_bind_capture(&self, name: &str, value: &Value) -> Option<Value> {
+        let mut captures = self.lock_captures();
+        captures.uncaptured_same_as.remove(name);
+
+        get_or_bind(&mut captures.values, name, value)
+    }
+
+    /// The same as [`Self::get_or_bind_capture`], except the name must also be
+    /// captured somewhere else for the assertion to pass.
+    pub(crate) fn get_or_bind_same_as(
+        &self,
+        name: &str,
+        value: &Value,
+        path: Vec<ContextPathPart<'static>>,
+    ) -> Option<Value> {
+        let mut captures = self.lock_captures();
+        let maybe_bound = get_or_bind(&mut captures.values, name, value);
+        if maybe_bound.is_none() {
+            captures.uncaptured_same_as.insert(name.to_string(), path);
+        }
+
+        maybe_bound
+    }
+
+    /// Returns the names used by `same_as` that were never captured,
+    /// along with where they were used.
+    pub(crate) fn to_uncaptured_same_as(&self) -> Vec<(String, Vec<ContextPathPart<'static>>)> {
+        self.lock_captures()
+            .uncaptured_same_as
+            .iter()
+            .map(|(name, path)| (name.clone(), path.clone()))
+            .collect()
+    }
+
+    pub(crate) fn captures_checkpoint(self: &Arc<Self>) -> CapturesCheckpoint {
+        CapturesCheckpoint {
+            shared: self.clone(),
+            captures: self.lock_captures().clone(),
+        }
+    }
+
+    pub(crate) fn to_captures(&self) -> ExpectJsonCaptures {
+        ExpectJsonCaptures::from(self.lock_captures().values.clone())
+    }
+
+    fn lock_captures(&self) -> MutexGuard<'_, Captures> {
+        self.captures.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn get_or_bind(values: &mut Map<String, Value>, name: &str, value: &Value) -> Option<Value> {
+    if let Some(bound) = values.get(name) {
+        return Some(bound.clone());
+    }
+
+    values.insert(name.to_string(), value.clone());
+    None
+}
+
+/// The values captured at a point in time, so a comparison that is allowed to fail
+/// can be undone. Otherwise a failed match could leave behind values it captured.
+#[derive(Debug)]
+pub(crate) struct CapturesCheckpoint {
+    shared: Arc<ContextShared>,
+    captures: Captures,
+}
+
+impl CapturesCheckpoint {
+    /// Discards everything captured since the checkpoint was made.
+    pub(crate) fn restore(self) {
+        *self.shared.lock_captures() = self.captures;
+    }
+
+    /// Keeps what was captured if the result passed, and discards it otherwise.
+    pub(crate) fn restore_on_err<T, E>(self, result: Result<T, E>) -> Result<T, E> {
+        if result.is_err() {
+            self.restore();
+        }
+
+        result
+    }
+}
+
+/// The captures are not part of where a context is, so only the options are compared.
+impl PartialEq for ContextShared {
+    fn eq(&self, other: &Self) -> bool {
+        self.options == other.options
+    }
+}
@@ -58,7 +58,9 @@ impl<'a, 'c> ContextWith<'a, 'c> {
         let previous_is_partial = self.previous_is_propagated_contains;
         let path_count = self.pushed_paths;
 
-        fun(context)?;
+        // The context is always restored, even on error,
+        // as the caller may carry on when collecting all errors.
+        let result = fun(context);
 
         if previous_is_partial {
             context.enable_propagated_contains();
@@ -70,6 +72,6 @@ impl<'a, 'c> ContextWith<'a, 'c> {
             context.pop();
         }
 
-        Ok(())
+        result
     }
 }
@@ -1,5 +1,8 @@
 mod context_path_part;
-use self::context_path_part::*;
+pub(crate) use self::context_path_part::*;
+
+mod context_shared;
+pub(crate) use self::context_shared::*;
 
 mod context_with;
 pub(crate) use self::context_with::*;
@@ -160,6 +160,14 @@ pub trait ExpectOp: ExpectOpExt + Debug + Send + 'static {
         ))
     }
 
+    /// This is optional to implement. Returns true if this can be the value of an object field,
+    /// where that field is allowed to be missing. Defaults to false.
+    ///
+    /// When the field is present, the received value is checked as normal.
+    fn allows_missing_key(&self) -> bool {
+        false
+    }
+
     /// This is optional to implement. This method returns a list of types this is targeting.
     ///
     /// This is used for debug messages for the user, when the type doesn't match up.
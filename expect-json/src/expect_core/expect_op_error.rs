@@ -6,9 +6,11 @@ use crate::internals::ExpectOpMeta;
 use crate::internals::objects::ArrayObject;
 use crate::internals::objects::FloatObject;
 use crate::internals::objects::IntegerObject;
+use crate::internals::objects::ObjectObject;
 use crate::internals::objects::ValueObject;
 use crate::internals::objects::ValueTypeObject;
 use std::error::Error as StdError;
+use std::fmt::Write;
 use thiserror::Error;
 
 pub type ExpectOpResult<V> = Result<V, ExpectOpError>;
@@ -110,6 +112,37 @@ pub enum ExpectOpError {
         received_full_array: ArrayObject,
     },
 
+    #[error(
+        "{error}
+    received object key \"{received_key}\""
+    )]
+    ObjectAllKeys {
+        #[source]
+        error: Box<ExpectJsonError>,
+        received_key: String,
+    },
+
+    #[error(
+        "{error}
+    received full object {received_full_object}"
+    )]
+    ObjectAllValues {
+        #[source]
+        error: Box<ExpectJsonError>,
+        received_full_object: ObjectObject,
+    },
+
+    #[error(
+        "{error}
+    received string {received}, matched with regex pattern '{pattern}'"
+    )]
+    RegexCaptures {
+        #[source]
+        error: Box<ExpectJsonError>,
+        pattern: String,
+        received: ValueObject,
+    },
+
     #[error(
         "Json expect::array() error at {context}, mismatch:
     expected array (up to order): {expected_array},
@@ -121,6 +154,27 @@ pub enum ExpectOpError {
         received_array: ArrayObject,
     },
 
+    #[error(
+        "Json expect::any_of() error at {context}, none of the {} expectations matched:{}",
+        errors.len(),
+        format_any_of_errors(errors),
+    )]
+    AnyOfNoMatch {
+        context: Context<'static>,
+        errors: Vec<ExpectJsonError>,
+    },
+
+    #[error(
+        "Json expect::not() error at {context}, received value matched:
+    expected not {expected}
+    received {received}"
+    )]
+    NotMatched {
+        context: Context<'static>,
+        expected: ValueObject,
+        received: ValueObject,
+    },
+
     #[error(
         "Json expect::integer() error at {context}, is zero:
     expected non-zero integer
@@ -161,6 +215,16 @@ pub enum ExpectOpError {
         received: FloatObject,
     },
 
+    #[error(
+        "Json expect::same_as() error at {context}:
+    no value was captured as \"{name}\",
+    expected an expect::capture() using the same name"
+    )]
+    SameAsNotCaptured {
+        context: Context<'static>,
+        name: String,
+    },
+
     #[error(
         "Json expect::{}() error at {context}:
     {message},
@@ -254,3 +318,16 @@ fn format_expected_operation_types(expected_operation: &ExpectOpMeta) -> String
 
     types.join(", ")
 }
+
+fn format_any_of_errors(errors: &[ExpectJsonError]) -> String {
+    let mut output = String::new();
+
+    for (i, error) in errors.iter().enumerate() {
+        let _ = write!(output, "\n\n    expectation {i} failed,");
+        for line in error.to_string().lines() {
+            let _ = write!(output, "\n        {line}");
+        }
+    }
+
+    output
+}
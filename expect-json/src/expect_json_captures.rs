@@ -0,0 +1,72 @@
+use serde::de::DeserializeOwned;
+use serde_json::Map;
+use serde_json::Value;
+
+///
+/// The values bound by [`crate::expect::capture`] and [`crate::expect::same_as`],
+/// returned after a successful comparison.
+///
+/// ```rust
+/// use expect_json::expect;
+/// use expect_json::expect_json_eq_captures;
+/// use serde_json::json;
+///
+/// let received = json!({
+///     "id": "0194d4d5-1a79-7a3c-9a07-4a8e3b5d7c21",
+///     "links": {
+///         "self": "0194d4d5-1a79-7a3c-9a07-4a8e3b5d7c21",
+///     },
+/// });
+///
+/// let captures = expect_json_eq_captures(&received, &json!({
+///     "id": expect::capture("user_id", expect::uuid()),
+///     "links": {
+///         "self": expect::same_as("user_id"),
+///     },
+/// })).unwrap();
+///
+/// let user_id: String = captures.get_as("user_id").unwrap();
+/// assert_eq!(user_id, "0194d4d5-1a79-7a3c-9a07-4a8e3b5d7c21");
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectJsonCaptures {
+    values: Map<String, Value>,
+}
+
+impl ExpectJsonCaptures {
+    /// Returns the value captured under the name given.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Returns the value captured under the name given, deserialised into the type given.
+    ///
+    /// Returns `None` if there is no value, or it cannot be deserialised.
+    pub fn get_as<T>(&self, name: &str) -> Option<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.values
+            .get(name)
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.values
+    }
+}
+
+impl From<Map<String, Value>> for ExpectJsonCaptures {
+    fn from(values: Map<String, Value>) -> Self {
+        Self { values }
+    }
+}
@@ -1,10 +1,90 @@
+use crate::ExpectJsonCaptures;
 use crate::ExpectJsonError;
+use crate::ExpectJsonOptions;
 use crate::ExpectJsonResult;
 use crate::expect_core::Context;
 use crate::internals::json_eq;
+use crate::internals::json_pointer_find;
 use serde::Serialize;
+use serde_json::Value;
 
+/// Compares the received and expected values, returning an error describing the first mismatch.
 pub fn expect_json_eq<R, E>(received_raw: &R, expected_raw: &E) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    expect_json_eq_captures(received_raw, expected_raw).map(|_| ())
+}
+
+/// The same as [`expect_json_eq`], except it does not stop at the first mismatch.
+///
+/// The whole of the received and expected Json are compared,
+/// and every difference is returned in one error. Use
+/// [`ExpectJsonError::into_errors`] to get each individual error.
+///
+/// To combine this with other options, use [`ExpectJsonOptions::collect_all_errors`].
+pub fn expect_json_eq_all<R, E>(received_raw: &R, expected_raw: &E) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    expect_json_eq_with(
+        received_raw,
+        expected_raw,
+        ExpectJsonOptions::new().collect_all_errors(),
+    )
+}
+
+/// The same as [`expect_json_eq`], using the options given to change how values are compared.
+///
+/// See [`ExpectJsonOptions`] for the options available.
+pub fn expect_json_eq_with<R, E>(
+    received_raw: &R,
+    expected_raw: &E,
+    options: ExpectJsonOptions,
+) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    expect_json_eq_captures_with(received_raw, expected_raw, options).map(|_| ())
+}
+
+/// The same as [`expect_json_eq`], and on success returns the values bound
+/// with [`crate::expect::capture`].
+///
+/// ```rust
+/// use expect_json::expect;
+/// use expect_json::expect_json_eq_captures;
+/// use serde_json::json;
+///
+/// let received = json!({ "id": 123, "name": "Joe" });
+///
+/// let captures = expect_json_eq_captures(&received, &json!({
+///     "id": expect::capture("user_id", expect::integer()),
+///     "name": "Joe",
+/// })).unwrap();
+///
+/// assert_eq!(captures.get_as::<i64>("user_id"), Some(123));
+/// ```
+pub fn expect_json_eq_captures<R, E>(
+    received_raw: &R,
+    expected_raw: &E,
+) -> ExpectJsonResult<ExpectJsonCaptures>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    expect_json_eq_captures_with(received_raw, expected_raw, ExpectJsonOptions::default())
+}
+
+/// The same as [`expect_json_eq_captures`], using the options given to change how values are compared.
+pub fn expect_json_eq_captures_with<R, E>(
+    received_raw: &R,
+    expected_raw: &E,
+    options: ExpectJsonOptions,
+) -> ExpectJsonResult<ExpectJsonCaptures>
 where
     R: Serialize,
     E: Serialize,
@@ -14,10 +94,85 @@ where
     let expected =
         serde_json::to_value(expected_raw).map_err(ExpectJsonError::FailedToSerialiseExpected)?;
 
-    let mut context = Context::new();
-    json_eq(&mut context, &received, &expected)?;
+    let mut context = Context::with_root(&received, options);
+    json_eq_from_root(&mut context, &received, &expected)
+}
 
-    Ok(())
+/// The same as [`expect_json_eq`], except only the part of received at the Json Pointer (RFC 6901)
+/// given is compared.
+///
+/// Paths in errors are still given from the root of received.
+///
+/// ```rust
+/// use expect_json::expect;
+/// use expect_json::expect_json_eq_at;
+/// use serde_json::json;
+///
+/// let received = json!({
+///     "data": {
+///         "items": [
+///             { "owner": { "name": "Joe", "id": 123 } },
+///         ],
+///     },
+/// });
+///
+/// expect_json_eq_at(&received, "/data/items/0/owner", &json!({
+///     "name": "Joe",
+///     "id": expect::integer(),
+/// })).unwrap();
+/// ```
+pub fn expect_json_eq_at<R, E>(
+    received_raw: &R,
+    pointer: &str,
+    expected_raw: &E,
+) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    expect_json_eq_at_with(
+        received_raw,
+        pointer,
+        expected_raw,
+        ExpectJsonOptions::default(),
+    )
+}
+
+/// The same as [`expect_json_eq_at`], using the options given to change how values are compared.
+///
+/// Json Pointers in the options, such as for [`ExpectJsonOptions::unordered_arrays_at`],
+/// are from the root of received.
+pub fn expect_json_eq_at_with<R, E>(
+    received_raw: &R,
+    pointer: &str,
+    expected_raw: &E,
+    options: ExpectJsonOptions,
+) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+    E: Serialize,
+{
+    let received =
+        serde_json::to_value(received_raw).map_err(ExpectJsonError::FailedToSerialiseReceived)?;
+    let expected =
+        serde_json::to_value(expected_raw).map_err(ExpectJsonError::FailedToSerialiseExpected)?;
+
+    let mut context = Context::with_root(&received, options);
+    let received_at = json_pointer_find(&mut context, &received, pointer)?;
+    json_eq_from_root(&mut context, received_at, &expected).map(|_| ())
+}
+
+/// Runs the comparison for a whole assertion, returning the values captured on success.
+pub(crate) fn json_eq_from_root<'a>(
+    context: &mut Context<'a>,
+    received: &'a Value,
+    expected: &'a Value,
+) -> ExpectJsonResult<ExpectJsonCaptures> {
+    json_eq(context, received, expected)
+        .and_then(|_| context.check_same_as_captured())
+        .inspect_err(|_| context.keep_root_for_errors())?;
+
+    Ok(context.to_captures())
 }
 
 #[cfg(test)]
@@ -72,3 +227,281 @@ mod test_expect_json_eq {
         assert!(error_dbg.starts_with("DifferentTypes"));
     }
 }
+
+#[cfg(test)]
+mod test_expect_json_eq_all {
+    use super::*;
+    use crate::expect;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_pass_for_equal_values() {
+        let received = json!({ "name": "Joe", "age": 30 });
+        let expected = json!({ "name": "Joe", "age": 30 });
+
+        let output = expect_json_eq_all(&received, &expected);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_return_single_errors_as_is() {
+        let received = json!({ "name": "Joe", "age": 30 });
+        let expected = json!({ "name": "Joe", "age": 31 });
+
+        let output = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json integers at root.age are not equal:
+    expected 31
+    received 30"#
+        );
+    }
+
+    #[test]
+    fn it_should_return_every_error_in_an_object() {
+        let received = json!({ "name": "Joe", "age": 30, "extra": true });
+        let expected = json!({ "name": "Jane", "age": 31, "missing": false });
+
+        let output = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json has 4 errors:
+
+Json object at root has extra field "extra":
+    - expected
+    + received
+      {
+    +     "extra": true,
+      }
+
+Json integers at root.age are not equal:
+    expected 31
+    received 30
+
+Json objects at root are not equal:
+    expected field 'missing',
+    but it was not found
+
+Json strings at root.name are not equal:
+    expected "Jane"
+    received "Joe""#
+        );
+    }
+
+    #[test]
+    fn it_should_compare_items_in_arrays_of_different_lengths() {
+        let received = json!({ "items": [{ "id": 1 }, { "id": 2 }] });
+        let expected = json!({ "items": [{ "id": 10 }, { "id": 2 }, { "id": 3 }] });
+
+        let errors = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .into_errors()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 2, "errors: {errors:#?}");
+        assert!(errors[0].starts_with("Json arrays at root.items are not equal"));
+        assert_eq!(
+            errors[1],
+            r#"Json integers at root.items[0].id are not equal:
+    expected 10
+    received 1"#
+        );
+    }
+
+    #[test]
+    fn it_should_return_every_error_within_nested_arrays() {
+        let received = json!({ "items": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] });
+        let expected = json!({ "items": [{ "id": 1 }, { "id": 20 }, { "id": 30 }] });
+
+        let errors = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .into_errors()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                r#"Json integers at root.items[1].id are not equal:
+    expected 20
+    received 2"#,
+                r#"Json integers at root.items[2].id are not equal:
+    expected 30
+    received 3"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_every_error_within_expect_object_contains() {
+        let received = json!({ "name": "Joe", "age": 30 });
+        let expected = json!(expect::object().contains(json!({
+            "name": "Jane",
+            "age": 31,
+            "email": expect::email(),
+        })));
+
+        let errors = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .into_errors()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                r#"Json integers at root.age are not equal:
+    expected 31
+    received 30"#,
+                r#"Json object at root is missing key for object:
+    expected field 'email',
+    but it was not found"#,
+                r#"Json strings at root.name are not equal:
+    expected "Jane"
+    received "Joe""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_every_error_within_expect_array_sub_ops() {
+        let received = json!([1, 2, 3]);
+        let expected = json!(
+            expect::array()
+                .len(4)
+                .all(expect::integer().greater_than(1))
+        );
+
+        let errors = expect_json_eq_all(&received, &expected)
+            .unwrap_err()
+            .into_errors();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn it_should_combine_collecting_all_errors_with_other_options() {
+        let received = json!({ "name": "Joe", "age": 30, "extra": true });
+        let expected = json!({ "name": "Jane", "age": 31 });
+        let options = ExpectJsonOptions::new()
+            .ignore_extra_fields()
+            .collect_all_errors();
+
+        let errors = expect_json_eq_with(&received, &expected, options)
+            .unwrap_err()
+            .into_errors()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                r#"Json integers at root.age are not equal:
+    expected 31
+    received 30"#,
+                r#"Json strings at root.name are not equal:
+    expected "Jane"
+    received "Joe""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_only_return_the_first_error_for_expect_json_eq() {
+        let received = json!({ "name": "Joe", "age": 30 });
+        let expected = json!({ "name": "Jane", "age": 31 });
+
+        let errors = expect_json_eq(&received, &expected)
+            .unwrap_err()
+            .into_errors();
+        assert_eq!(errors.len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod test_expect_json_eq_at {
+    use super::*;
+    use crate::expect;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_compare_the_value_at_the_pointer() {
+        let received = json!({ "data": { "items": [{ "owner": "Joe" }] } });
+
+        let output = expect_json_eq_at(&received, "/data/items/0/owner", &json!("Joe"));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_compare_the_root_for_an_empty_pointer() {
+        let received = json!({ "name": "Joe" });
+
+        let output = expect_json_eq_at(&received, "", &json!({ "name": expect::string() }));
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_give_errors_from_the_document_root() {
+        let received = json!({ "data": { "items": [{ "owner": { "name": "Joe" } }] } });
+
+        let output = expect_json_eq_at(&received, "/data/items/0/owner", &json!({ "name": "Sam" }))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json strings at root.data.items[0].owner.name are not equal:
+    expected "Sam"
+    received "Joe""#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_the_pointer_is_not_found() {
+        let received = json!({ "data": { "items": [] } });
+
+        let output = expect_json_eq_at(&received, "/data/items/0/owner", &json!("Joe"))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json array at root.data.items has no value at '0', for path '/data/items/0/owner'"#
+        );
+    }
+
+    #[test]
+    fn it_should_error_when_the_pointer_is_invalid() {
+        let received = json!({ "data": 123 });
+
+        let output = expect_json_eq_at(&received, "data", &json!(123))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json Pointer 'data' is invalid, it must be empty or start with '/'"#
+        );
+    }
+
+    #[test]
+    fn it_should_use_the_options_given() {
+        let received = json!({ "data": { "owner": { "name": "Joe", "age": 30, "id": 1 } } });
+        let options = ExpectJsonOptions::new()
+            .ignore_extra_fields()
+            .collect_all_errors();
+
+        let errors = expect_json_eq_at_with(
+            &received,
+            "/data/owner",
+            &json!({ "name": "Sam", "age": 31 }),
+            options,
+        )
+        .unwrap_err()
+        .into_errors();
+        assert_eq!(errors.len(), 2);
+    }
+}
@@ -4,10 +4,13 @@ use crate::expect_core::ExpectOpError;
 use crate::internals::objects::ArrayObject;
 use crate::internals::objects::ValueObject;
 use crate::internals::objects::ValueTypeObject;
+use crate::internals::pretty_formatter::PrettyDiff;
 use crate::internals::utils::is_unquotable_js_identifier;
 use serde_json::Error as SerdeJsonError;
 use serde_json::Value;
 use std::fmt::Write;
+use std::io::Error as IoError;
+use std::path::PathBuf;
 use thiserror::Error;
 
 pub type ExpectJsonResult<V> = Result<V, ExpectJsonError>;
@@ -20,6 +23,79 @@ pub enum ExpectJsonError {
     #[error("Failed to serialise other value to Json")]
     FailedToSerialiseReceived(#[source] SerdeJsonError),
 
+    #[error("Failed to read snapshot {}", path.display())]
+    FailedToReadSnapshot {
+        path: PathBuf,
+        #[source]
+        source: IoError,
+    },
+
+    #[error("Failed to write snapshot {}", path.display())]
+    FailedToWriteSnapshot {
+        path: PathBuf,
+        #[source]
+        source: IoError,
+    },
+
+    #[error("Failed to parse snapshot {} as Json", path.display())]
+    FailedToParseSnapshot {
+        path: PathBuf,
+        #[source]
+        source: SerdeJsonError,
+    },
+
+    #[error(
+        "Snapshot {} was not found, run with EXPECT_JSON_UPDATE_SNAPSHOTS=1 to create it",
+        path.display()
+    )]
+    SnapshotNotFound { path: PathBuf },
+
+    #[error("Snapshot {} has an invalid expectation at {context}", path.display())]
+    InvalidSnapshotExpectation {
+        path: PathBuf,
+        context: Context<'static>,
+        #[source]
+        source: SerdeJsonError,
+    },
+
+    #[error(
+        "Json does not match snapshot {}:
+
+{error}",
+        path.display()
+    )]
+    SnapshotMismatch {
+        path: PathBuf,
+        error: Box<ExpectJsonError>,
+    },
+
+    #[error("Json Pointer '{pointer}' is invalid, it must be empty or start with '/'")]
+    InvalidJsonPointer { pointer: String },
+
+    #[error("Json field reference '{path}' at {context} is invalid, it goes above the root")]
+    InvalidFieldRef {
+        context: Context<'static>,
+        path: String,
+    },
+
+    #[error("Json {json_type} at {context} has no value at '{segment}', for path '{pointer}'")]
+    JsonPointerNotFound {
+        context: Context<'static>,
+        json_type: JsonType,
+        segment: String,
+        pointer: String,
+    },
+
+    #[error(
+        "Json field reference '{path}' at {context} can only sum numbers,
+    received {received}"
+    )]
+    FieldRefSumNotNumber {
+        context: Context<'static>,
+        path: String,
+        received: ValueTypeObject,
+    },
+
     #[error(
         "Json {} at {context} are different types:
     expected {expected}
@@ -33,9 +109,8 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json {json_type}s at {context} are not equal:
-    expected {expected}
-    received {received}"
+        "Json {json_type}s at {context} are not equal:{}",
+        format_expected_received(context, expected, received)
     )]
     DifferentValues {
         context: Context<'static>,
@@ -106,9 +181,8 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal:
-    expected {expected_array}
-    received {received_array}"
+        "Json arrays at {context} are not equal:{}",
+        PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayMissingInMiddle {
         context: Context<'static>,
@@ -121,9 +195,8 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal:
-    expected {expected_array}
-    received {received_array}"
+        "Json arrays at {context} are not equal:{}",
+        PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayValuesAreDifferent {
         context: Context<'static>,
@@ -131,6 +204,22 @@ pub enum ExpectJsonError {
         expected_array: ArrayObject,
     },
 
+    #[error(
+        "Json arrays at {context} are not equal, ignoring order:
+    expected {expected_array}
+    received {received_array}
+
+     missing {missing_in_received}
+       extra {extra_in_received}"
+    )]
+    ArrayUnorderedValuesAreDifferent {
+        context: Context<'static>,
+        received_array: ArrayObject,
+        expected_array: ArrayObject,
+        missing_in_received: Box<ArrayObject>,
+        extra_in_received: Box<ArrayObject>,
+    },
+
     #[error(
         "Json arrays at {context} are not equal:
     expected array index at '{expected_index}',
@@ -142,11 +231,11 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal, missing {} {} at the end:
-    expected {expected_array}
-    received {received_array}
+        "Json arrays at {context} are not equal, missing {} {} at the end:{}
+
      missing {missing_in_received}"
      , missing_in_received.len(), pluralise_item_word(missing_in_received.len())
+     , PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayMissingAtEnd {
         context: Context<'static>,
@@ -156,11 +245,11 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal, missing {} {} from the start:
-    expected {expected_array}
-    received {received_array}
+        "Json arrays at {context} are not equal, missing {} {} from the start:{}
+
      missing {missing_in_received}"
      , missing_in_received.len(), pluralise_item_word(missing_in_received.len())
+     , PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayMissingAtStart {
         context: Context<'static>,
@@ -170,11 +259,11 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal, received {} extra {} at the end:
-    expected {expected_array}
-    received {received_array}
+        "Json arrays at {context} are not equal, received {} extra {} at the end:{}
+
        extra {extra_in_received}"
      , extra_in_received.len(), pluralise_item_word(extra_in_received.len())
+     , PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayExtraAtEnd {
         context: Context<'static>,
@@ -184,11 +273,11 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        "Json arrays at {context} are not equal, received {} extra {} at the start:
-    expected {expected_array}
-    received {received_array}
+        "Json arrays at {context} are not equal, received {} extra {} at the start:{}
+
        extra {extra_in_received}"
      , extra_in_received.len(), pluralise_item_word(extra_in_received.len())
+     , PrettyDiff::new(context, expected_array.clone(), received_array.clone())
     )]
     ArrayExtraAtStart {
         context: Context<'static>,
@@ -198,31 +287,29 @@ pub enum ExpectJsonError {
     },
 
     #[error(
-        r#"Json object at {context} has extra field "{received_extra_field}":
-    expected {expected_obj}
-    received {received_obj}"#
+        r#"Json object at {context} has extra field "{received_extra_field}":{}"#,
+        format_expected_received(context, expected_obj, received_obj)
     )]
     ObjectReceivedHasExtraKey {
         context: Context<'static>,
         received_extra_field: String,
-        received_obj: ValueObject,
-        expected_obj: ValueObject,
+        received_obj: Box<ValueObject>,
+        expected_obj: Box<ValueObject>,
     },
 
     #[error(
-        r#"Json object at {context} has many extra fields over expected:
-    expected {expected_obj}
-    received {received_obj}
+        r#"Json object at {context} has many extra fields over expected:{}
 
     extra fields in received:
 {}"#,
+        format_expected_received(context, expected_obj, received_obj),
         format_extra_fields(received_extra_fields)
     )]
     ObjectReceivedHasExtraKeys {
         context: Context<'static>,
         received_extra_fields: Vec<String>,
-        received_obj: ValueObject,
-        expected_obj: ValueObject,
+        received_obj: Box<ValueObject>,
+        expected_obj: Box<ValueObject>,
     },
 
     #[error("{source}")]
@@ -230,9 +317,39 @@ pub enum ExpectJsonError {
         #[from]
         source: ExpectOpError,
     },
+
+    #[error(
+        "Json has {} errors:
+
+{}",
+        errors.len(),
+        format_multiple_errors(errors)
+    )]
+    MultipleErrors { errors: Vec<ExpectJsonError> },
 }
 
 impl ExpectJsonError {
+    /// Builds an error holding all of the errors given.
+    /// Any errors which are themselves a collection of errors are flattened.
+    pub(crate) fn multiple(errors: Vec<Self>) -> Self {
+        let errors = errors.into_iter().flat_map(Self::into_errors).collect();
+        Self::MultipleErrors { errors }
+    }
+
+    /// Returns every individual error held within.
+    ///
+    /// For most errors this is a list of one. For errors
+    /// returned by [`crate::expect_json_eq_all`] this is every mismatch found.
+    pub fn into_errors(self) -> Vec<Self> {
+        match self {
+            Self::MultipleErrors { errors } => errors,
+            Self::ExpectOpError {
+                source: ExpectOpError::ExpectJsonError { error },
+            } => error.into_errors(),
+            error => vec![error],
+        }
+    }
+
     pub(crate) fn array_index_missing<'a>(
         context: &mut Context<'a>,
         source_error: Self,
@@ -306,6 +423,37 @@ fn value_or_number_type_name(left: &ValueTypeObject, right: &ValueTypeObject) ->
     }
 }
 
+/// Containers are shown as a diff, as they can be too big to spot the difference.
+fn format_expected_received(
+    context: &Context<'_>,
+    expected: &ValueObject,
+    received: &ValueObject,
+) -> String {
+    if expected.is_container() && received.is_container() {
+        return PrettyDiff::new(context, expected.clone(), received.clone()).to_string();
+    }
+
+    format!(
+        "
+    expected {expected}
+    received {received}"
+    )
+}
+
+fn format_multiple_errors(errors: &[ExpectJsonError]) -> String {
+    let mut output = String::new();
+
+    for (i, error) in errors.iter().enumerate() {
+        if i > 0 {
+            output.push_str("\n\n");
+        }
+
+        let _ = write!(output, "{error}");
+    }
+
+    output
+}
+
 fn format_extra_fields(received_extra_fields: &[String]) -> String {
     let mut output = String::new();
 
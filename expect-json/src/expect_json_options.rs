@@ -0,0 +1,164 @@
+use crate::Clock;
+use crate::SharedClock;
+use crate::expect::ops::FloatTolerance;
+use crate::expect_core::Context;
+use crate::thread_clock_now;
+use chrono::DateTime;
+use chrono::Utc;
+
+///
+/// Options for changing how Json is compared, across the whole of an assertion.
+///
+/// By default comparisons are strict. These are passed to [`crate::expect_json_eq_with`].
+///
+/// ```rust
+/// use expect_json::ExpectJsonOptions;
+/// use expect_json::expect::ops::FloatTolerance;
+/// use expect_json::expect_json_eq_with;
+/// use serde_json::json;
+///
+/// let options = ExpectJsonOptions::new()
+///     .ignore_extra_fields()
+///     .unordered_arrays_at(["/tags"])
+///     .float_tolerance(FloatTolerance::new().abs(0.001));
+///
+/// let received = json!({
+///     "name": "Joe",
+///     "tags": ["admin", "staff"],
+///     "score": 9.9996,
+///     "created_at": "2024-01-01T00:00:00Z",
+/// });
+///
+/// expect_json_eq_with(&received, &json!({
+///     "name": "Joe",
+///     "tags": ["staff", "admin"],
+///     "score": 10.0,
+/// }), options).unwrap();
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectJsonOptions {
+    is_ignoring_extra_fields: bool,
+    unordered_arrays: UnorderedArrays,
+    is_null_same_as_missing: bool,
+    is_collecting_all_errors: bool,
+    float_tolerance: Option<FloatTolerance>,
+    maybe_clock: Option<SharedClock>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+enum UnorderedArrays {
+    #[default]
+    Nowhere,
+    Everywhere,
+    AtPaths(Vec<String>),
+}
+
+impl ExpectJsonOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fields in received objects, which are not in the expected objects, are ignored.
+    pub fn ignore_extra_fields(mut self) -> Self {
+        self.is_ignoring_extra_fields = true;
+        self
+    }
+
+    /// Arrays are compared ignoring the order of their items, everywhere.
+    pub fn unordered_arrays(mut self) -> Self {
+        self.unordered_arrays = UnorderedArrays::Everywhere;
+        self
+    }
+
+    /// Arrays at the paths given are compared ignoring the order of their items.
+    ///
+    /// Paths are Json Pointers (RFC 6901), such as `"/users/0/roles"`.
+    /// A `*` segment matches any field or index, such as `"/users/*/roles"`.
+    pub fn unordered_arrays_at<I, S>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let new_paths = paths.into_iter().map(Into::into);
+        match &mut self.unordered_arrays {
+            UnorderedArrays::Everywhere => {}
+            UnorderedArrays::AtPaths(paths) => paths.extend(new_paths),
+            UnorderedArrays::Nowhere => {
+                self.unordered_arrays = UnorderedArrays::AtPaths(new_paths.collect());
+            }
+        }
+
+        self
+    }
+
+    /// An object field holding `null` is treated the same as the field being missing.
+    pub fn null_as_missing(mut self) -> Self {
+        self.is_null_same_as_missing = true;
+        self
+    }
+
+    /// The comparison does not stop at the first mismatch,
+    /// and every difference is returned in one error.
+    ///
+    /// Use [`crate::ExpectJsonError::into_errors`] to get each individual error.
+    pub fn collect_all_errors(mut self) -> Self {
+        self.is_collecting_all_errors = true;
+        self
+    }
+
+    /// Floats are compared using this tolerance, rather than needing to be exactly equal.
+    ///
+    /// This is also used by [`crate::expect::ops::ExpectFloat::approx`]
+    /// when no tolerance is given to it.
+    pub fn float_tolerance(mut self, tolerance: FloatTolerance) -> Self {
+        self.float_tolerance = Some(tolerance);
+        self
+    }
+
+    /// The clock given is used for expectations relative to now, such as `within_past`.
+    ///
+    /// This takes precedence over a clock set with [`crate::set_thread_clock`].
+    pub fn clock<C>(mut self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        self.maybe_clock = Some(SharedClock::new(clock));
+        self
+    }
+
+    pub fn is_ignoring_extra_fields(&self) -> bool {
+        self.is_ignoring_extra_fields
+    }
+
+    /// Returns true if the array at the location of the context should be compared unordered.
+    pub fn is_unordered_array_at(&self, context: &Context<'_>) -> bool {
+        match &self.unordered_arrays {
+            UnorderedArrays::Nowhere => false,
+            UnorderedArrays::Everywhere => true,
+            UnorderedArrays::AtPaths(paths) => {
+                paths.iter().any(|path| context.matches_json_pointer(path))
+            }
+        }
+    }
+
+    pub fn is_null_same_as_missing(&self) -> bool {
+        self.is_null_same_as_missing
+    }
+
+    pub fn is_collecting_all_errors(&self) -> bool {
+        self.is_collecting_all_errors
+    }
+
+    pub fn get_float_tolerance(&self) -> Option<FloatTolerance> {
+        self.float_tolerance
+    }
+
+    /// Returns the time now, from the clock given, the thread clock, or the system clock.
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        match &self.maybe_clock {
+            Some(clock) => clock.now(),
+            None => thread_clock_now(),
+        }
+    }
+}
@@ -0,0 +1,520 @@
+use crate::__private::SerializeExpectOp;
+use crate::ExpectJsonError;
+use crate::ExpectJsonOptions;
+use crate::ExpectJsonResult;
+use crate::expect_core::Context;
+use crate::expect_core::ExpectOpMarkerId;
+use crate::expect_json_eq::json_eq_from_root;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// When this environment variable is set, snapshots are created or rewritten with the values received.
+///
+/// Any expectations already in the snapshot are kept, and must still match the values received.
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "EXPECT_JSON_UPDATE_SNAPSHOTS";
+
+/// The key for writing expectations in snapshot files by hand.
+///
+/// The value is either the name of the expectation type, such as `{ "$expect": "ExpectUuid" }`,
+/// or the type along with its settings, such as
+/// `{ "$expect": { "type": "ExpectString", "sub_ops": [{ "MinLen": 3 }] } }`.
+const SNAPSHOT_EXPECT_KEY: &str = "$expect";
+
+/// Compares the received value against a golden file stored under `tests/snapshots/`.
+///
+/// The assertion fails if the snapshot does not exist. Set the `EXPECT_JSON_UPDATE_SNAPSHOTS`
+/// environment variable to write the received Json to `tests/snapshots/<name>.json`.
+/// The file can then be edited by hand to replace unstable values with expectations.
+///
+/// Setting `EXPECT_JSON_UPDATE_SNAPSHOTS` also rewrites existing snapshots
+/// with the values received, whilst keeping the expectations in them.
+/// The update fails, and nothing is written, if those expectations no longer match.
+///
+/// ```rust
+/// # async fn test() -> Result<(), Box<dyn ::std::error::Error>> {
+/// #
+/// # use axum::Router;
+/// # use axum::extract::Json;
+/// # use axum::routing::get;
+/// # use axum_test::TestServer;
+/// # use serde_json::json;
+/// #
+/// # let server = TestServer::new(Router::new());
+/// #
+/// let user = server.get(&"/user").await.json::<serde_json::Value>();
+///
+/// // tests/snapshots/user.json can hold:
+/// // {
+/// //     "id": { "$expect": "ExpectUuid" },
+/// //     "name": "Alice"
+/// // }
+/// expect_json::assert_snapshot!("user", user);
+/// #
+/// # Ok(()) }
+/// ```
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $received:expr $(,)?) => {{
+        let snapshot_path = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("snapshots")
+            .join(format!("{}.json", $name));
+
+        if let Err(error) = $crate::expect_json_snapshot_eq(&snapshot_path, &$received) {
+            panic!("{error}");
+        }
+    }};
+}
+
+/// Compares the received value against the snapshot file at the path given.
+///
+/// If the file does not exist, it is an error, unless snapshots are being updated.
+/// See [`assert_snapshot!`] for more details.
+pub fn expect_json_snapshot_eq<P, R>(snapshot_path: P, received_raw: &R) -> ExpectJsonResult<()>
+where
+    P: AsRef<Path>,
+    R: Serialize,
+{
+    let is_updating = std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR)
+        .is_some_and(|value| !value.is_empty() && value != "0");
+
+    snapshot_eq(snapshot_path.as_ref(), received_raw, is_updating)
+}
+
+fn snapshot_eq<R>(path: &Path, received_raw: &R, is_updating: bool) -> ExpectJsonResult<()>
+where
+    R: Serialize,
+{
+    let received =
+        serde_json::to_value(received_raw).map_err(ExpectJsonError::FailedToSerialiseReceived)?;
+
+    let Some(snapshot) = read_snapshot(path)? else {
+        if !is_updating {
+            return Err(ExpectJsonError::SnapshotNotFound {
+                path: path.to_owned(),
+            });
+        }
+
+        return write_snapshot(path, &received);
+    };
+
+    if is_updating {
+        // The expectations kept must still match, before they are written back.
+        let updated = merge_snapshot(&snapshot, received.clone());
+        compare_snapshot(path, &received, &updated)?;
+        return write_snapshot(path, &updated);
+    }
+
+    compare_snapshot(path, &received, &snapshot)
+}
+
+fn compare_snapshot(path: &Path, received: &Value, snapshot: &Value) -> ExpectJsonResult<()> {
+    let expected = parse_expectations(&mut Context::new(), path, snapshot)?;
+    let mut context = Context::with_root(received, ExpectJsonOptions::default());
+    json_eq_from_root(&mut context, received, &expected)
+        .map(|_| ())
+        .map_err(|error| ExpectJsonError::SnapshotMismatch {
+            path: path.to_owned(),
+            error: Box::new(error),
+        })
+}
+
+fn read_snapshot(path: &Path) -> ExpectJsonResult<Option<Value>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(ExpectJsonError::FailedToReadSnapshot {
+                path: path.to_owned(),
+                source,
+            });
+        }
+    };
+
+    serde_json::from_str(&contents).map(Some).map_err(|source| {
+        ExpectJsonError::FailedToParseSnapshot {
+            path: path.to_owned(),
+            source,
+        }
+    })
+}
+
+fn write_snapshot(path: &Path, snapshot: &Value) -> ExpectJsonResult<()> {
+    let mut contents = serde_json::to_string_pretty(snapshot)
+        .map_err(ExpectJsonError::FailedToSerialiseReceived)?;
+    contents.push('\n');
+
+    let write_result = match path.parent() {
+        Some(parent) => fs::create_dir_all(parent).and_then(|_| fs::write(path, contents)),
+        None => fs::write(path, contents),
+    };
+
+    write_result.map_err(|source| ExpectJsonError::FailedToWriteSnapshot {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn is_snapshot_expectation(value: &Value) -> bool {
+    SerializeExpectOp::has_magic_id(value) || as_hand_written_expectation(value).is_some()
+}
+
+fn as_hand_written_expectation(value: &Value) -> Option<&Value> {
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+
+    object.get(SNAPSHOT_EXPECT_KEY)
+}
+
+/// Converts the expectations written in a snapshot into the form used by [`json_eq_from_root`].
+///
+/// Expectations are validated here, so mistakes are reported
+/// against the snapshot file rather than failing during the comparison.
+fn parse_expectations<'a>(
+    context: &mut Context<'a>,
+    path: &Path,
+    snapshot: &'a Value,
+) -> ExpectJsonResult<Value> {
+    if let Some(hand_written) = as_hand_written_expectation(snapshot) {
+        let inner = match hand_written {
+            Value::String(type_name) => Value::Object(Map::from_iter([(
+                "type".to_string(),
+                Value::String(type_name.clone()),
+            )])),
+            inner => parse_expectations(context, path, inner)?,
+        };
+
+        let magic_id = serde_json::to_value(ExpectOpMarkerId::default())
+            .expect("Failed to serialise internal expect marker");
+        let expect_op = Value::Object(Map::from_iter([
+            ("magic_id".to_string(), magic_id),
+            ("inner".to_string(), inner),
+        ]));
+
+        return validate_expectation(context, path, expect_op);
+    }
+
+    match snapshot {
+        Value::Object(object) => {
+            let mut parsed_object = Map::new();
+            for (key, value) in object {
+                context.push(key);
+                let parsed_value = parse_expectations(context, path, value);
+                context.pop();
+
+                parsed_object.insert(key.clone(), parsed_value?);
+            }
+
+            let parsed = Value::Object(parsed_object);
+            if SerializeExpectOp::has_magic_id(&parsed) {
+                return validate_expectation(context, path, parsed);
+            }
+
+            Ok(parsed)
+        }
+        Value::Array(array) => {
+            let mut parsed_array = Vec::with_capacity(array.len());
+            for (index, value) in array.iter().enumerate() {
+                context.push(index);
+                let parsed_value = parse_expectations(context, path, value);
+                context.pop();
+
+                parsed_array.push(parsed_value?);
+            }
+
+            Ok(Value::Array(parsed_array))
+        }
+        value => Ok(value.clone()),
+    }
+}
+
+fn validate_expectation(
+    context: &Context<'_>,
+    path: &Path,
+    expect_op: Value,
+) -> ExpectJsonResult<Value> {
+    serde_json::from_value::<SerializeExpectOp>(expect_op.clone()).map_err(|source| {
+        ExpectJsonError::InvalidSnapshotExpectation {
+            path: path.to_owned(),
+            context: context.to_static(),
+            source,
+        }
+    })?;
+
+    Ok(expect_op)
+}
+
+/// Builds the new snapshot from the received value,
+/// keeping any expectations from the old snapshot.
+fn merge_snapshot(snapshot: &Value, received: Value) -> Value {
+    if is_snapshot_expectation(snapshot) {
+        return snapshot.clone();
+    }
+
+    match (snapshot, received) {
+        (Value::Object(snapshot_object), Value::Object(received_object)) => received_object
+            .into_iter()
+            .map(|(key, received_value)| {
+                let merged_value = match snapshot_object.get(&key) {
+                    Some(snapshot_value) => merge_snapshot(snapshot_value, received_value),
+                    None => received_value,
+                };
+
+                (key, merged_value)
+            })
+            .collect(),
+        (Value::Array(snapshot_array), Value::Array(received_array)) => received_array
+            .into_iter()
+            .enumerate()
+            .map(|(index, received_value)| match snapshot_array.get(index) {
+                Some(snapshot_value) => merge_snapshot(snapshot_value, received_value),
+                None => received_value,
+            })
+            .collect(),
+        (_, received) => received,
+    }
+}
+
+#[cfg(test)]
+mod test_snapshot_eq {
+    use super::*;
+    use crate::expect;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    fn new_snapshot_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join("expect-json-test-snapshots")
+            .join(format!("{name}-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        path
+    }
+
+    fn write_raw_snapshot(path: &Path, snapshot: &Value) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, serde_json::to_string_pretty(snapshot).unwrap()).unwrap();
+    }
+
+    fn read_raw_snapshot(path: &Path) -> Value {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    /// Copies the golden file used by the integration tests,
+    /// so failing comparisons can never rewrite or race on the original.
+    fn copy_user_profile_snapshot(name: &str) -> PathBuf {
+        let path = new_snapshot_path(name);
+        let golden_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("snapshots")
+            .join("user_profile.json");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::copy(golden_path, &path).unwrap();
+
+        path
+    }
+
+    fn new_user_profile() -> Value {
+        json!({
+            "id": "b6cb5ca3-3d8b-4b6a-9b4d-a1f5e8a8e63b",
+            "name": "Alice",
+            "email": "alice@example.com",
+            "created_at": "2025-01-01T12:00:00Z",
+            "roles": ["admin", "editor"],
+            "settings": {
+                "theme": "dark",
+                "timezone": "Europe/London",
+            },
+        })
+    }
+
+    #[test]
+    fn it_should_fail_when_the_snapshot_is_missing() {
+        let path = new_snapshot_path("missing");
+
+        let output = snapshot_eq(&path, &json!({ "name": "Joe" }), false)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            format!(
+                "Snapshot {} was not found, run with EXPECT_JSON_UPDATE_SNAPSHOTS=1 to create it",
+                path.display()
+            )
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn it_should_write_the_received_value_when_missing_and_updating() {
+        let path = new_snapshot_path("first-run");
+        let received = json!({ "name": "Joe", "age": 30 });
+
+        let output = snapshot_eq(&path, &received, true);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+        assert_eq!(read_raw_snapshot(&path), received);
+    }
+
+    #[test]
+    fn it_should_pass_when_received_matches_the_snapshot() {
+        let path = new_snapshot_path("matching");
+        let received = json!({ "name": "Joe", "age": 30 });
+
+        snapshot_eq(&path, &received, true).unwrap();
+        let output = snapshot_eq(&path, &received, false);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+    }
+
+    #[test]
+    fn it_should_fail_when_received_differs_from_the_snapshot() {
+        let path = new_snapshot_path("differs");
+        write_raw_snapshot(&path, &json!({ "age": 30 }));
+
+        let output = snapshot_eq(&path, &json!({ "age": 31 }), false)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            format!(
+                "Json does not match snapshot {}:
+
+Json integers at root.age are not equal:
+    expected 30
+    received 31",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_support_expectations_in_the_serialized_form() {
+        let path = new_snapshot_path("serialized-form");
+        write_raw_snapshot(
+            &path,
+            &json!({ "id": expect::uuid(), "name": expect::string().min_len(1) }),
+        );
+
+        let received = json!({ "id": "b6cb5ca3-3d8b-4b6a-9b4d-a1f5e8a8e63b", "name": "Joe" });
+        let output = snapshot_eq(&path, &received, false);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = snapshot_eq(&path, &json!({ "id": 123, "name": "Joe" }), false);
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn it_should_support_expectations_written_by_hand() {
+        let path = new_snapshot_path("hand-written");
+        write_raw_snapshot(
+            &path,
+            &json!({
+                "id": { "$expect": "ExpectUuid" },
+                "tags": {
+                    "$expect": {
+                        "type": "ExpectArray",
+                        "sub_ops": [{ "Contains": [{ "$expect": "ExpectString" }] }],
+                    },
+                },
+            }),
+        );
+
+        let received = json!({ "id": "b6cb5ca3-3d8b-4b6a-9b4d-a1f5e8a8e63b", "tags": ["a"] });
+        let output = snapshot_eq(&path, &received, false);
+        assert!(output.is_ok(), "assertion error: {output:#?}");
+
+        let output = snapshot_eq(&path, &json!({ "id": "🦊", "tags": ["a"] }), false);
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn it_should_error_on_invalid_expectations_with_their_location() {
+        let path = new_snapshot_path("invalid-expectation");
+        write_raw_snapshot(
+            &path,
+            &json!({ "users": [{ "id": { "$expect": "ExpectUnknown" } }] }),
+        );
+
+        let output = snapshot_eq(&path, &json!({}), false)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            format!(
+                "Snapshot {} has an invalid expectation at root.users[0].id",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_keep_expectations_when_updating() {
+        let path = new_snapshot_path("updating");
+        write_raw_snapshot(
+            &path,
+            &json!({
+                "id": { "$expect": "ExpectUuid" },
+                "created_at": expect::iso_date_time(),
+                "name": "Joe",
+                "removed": true,
+            }),
+        );
+
+        let received = json!({
+            "id": "b6cb5ca3-3d8b-4b6a-9b4d-a1f5e8a8e63b",
+            "created_at": "2025-01-01T00:00:00Z",
+            "name": "Jane",
+            "added": true,
+        });
+        snapshot_eq(&path, &received, true).unwrap();
+
+        assert_eq!(
+            read_raw_snapshot(&path),
+            json!({
+                "id": { "$expect": "ExpectUuid" },
+                "created_at": expect::iso_date_time(),
+                "name": "Jane",
+                "added": true,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_not_update_when_a_kept_expectation_no_longer_matches() {
+        let path = new_snapshot_path("updating-mismatch");
+        let snapshot = json!({ "id": { "$expect": "ExpectUuid" }, "name": "Joe" });
+        write_raw_snapshot(&path, &snapshot);
+
+        let output = snapshot_eq(&path, &json!({ "id": 123, "name": "Jane" }), true);
+        assert!(output.is_err());
+        assert_eq!(read_raw_snapshot(&path), snapshot);
+    }
+
+    #[test]
+    fn it_should_fail_when_an_expectation_in_the_snapshot_fails() {
+        let path = copy_user_profile_snapshot("user-profile-bad-id");
+        let mut user_profile = new_user_profile();
+        user_profile["id"] = json!("not-a-uuid");
+
+        let output = snapshot_eq(&path, &user_profile, false);
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn it_should_return_error_with_snapshot_path_on_mismatch() {
+        let path = copy_user_profile_snapshot("user-profile-bad-email");
+        let mut user_profile = new_user_profile();
+        user_profile["email"] = json!("not an email");
+
+        let output = snapshot_eq(&path, &user_profile, false)
+            .unwrap_err()
+            .to_string();
+        assert!(output.starts_with(&format!("Json does not match snapshot {}:", path.display())));
+    }
+}
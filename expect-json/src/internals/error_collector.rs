@@ -0,0 +1,89 @@
+use crate::ExpectJsonError;
+use crate::expect_core::Context;
+
+/// Gathers the errors from a series of checks.
+///
+/// By default the first error is returned straight away.
+/// When the context is collecting all errors, they are instead stored,
+/// and returned together at the end.
+#[derive(Debug)]
+pub struct ErrorCollector<E> {
+    is_collecting_all_errors: bool,
+    errors: Vec<E>,
+}
+
+impl<E> ErrorCollector<E>
+where
+    E: Into<ExpectJsonError> + From<ExpectJsonError>,
+{
+    pub fn new(context: &Context<'_>) -> Self {
+        Self {
+            is_collecting_all_errors: context.is_collecting_all_errors(),
+            errors: vec![],
+        }
+    }
+
+    pub fn check(&mut self, result: Result<(), E>) -> Result<(), E> {
+        if let Err(error) = result {
+            if !self.is_collecting_all_errors {
+                return Err(error);
+            }
+
+            self.errors.push(error);
+        }
+
+        Ok(())
+    }
+
+    pub fn into_result(mut self) -> Result<(), E> {
+        match self.errors.len() {
+            0 => Ok(()),
+            1 => Err(self.errors.remove(0)),
+            _ => {
+                let errors = self.errors.into_iter().map(Into::into).collect();
+                Err(ExpectJsonError::multiple(errors).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_check {
+    use super::*;
+    use crate::ExpectJsonOptions;
+    use crate::JsonType;
+    use crate::internals::objects::BooleanObject;
+    use serde_json::Value;
+
+    fn new_error() -> ExpectJsonError {
+        ExpectJsonError::DifferentValues {
+            context: Context::new(),
+            json_type: JsonType::Boolean,
+            received: BooleanObject::from(true).into(),
+            expected: BooleanObject::from(false).into(),
+        }
+    }
+
+    #[test]
+    fn it_should_return_the_first_error_by_default() {
+        let context = Context::new();
+        let mut collector = ErrorCollector::<ExpectJsonError>::new(&context);
+
+        let output = collector.check(Err(new_error()));
+        assert!(output.is_err());
+    }
+
+    #[test]
+    fn it_should_store_errors_when_collecting_all() {
+        let options = ExpectJsonOptions::new().collect_all_errors();
+        let context = Context::with_root(&Value::Null, options);
+        let mut collector = ErrorCollector::<ExpectJsonError>::new(&context);
+
+        assert!(collector.check(Err(new_error())).is_ok());
+        assert!(collector.check(Ok(())).is_ok());
+        assert!(collector.check(Err(new_error())).is_ok());
+
+        let errors = collector.into_result().unwrap_err().into_errors();
+        assert_eq!(errors.len(), 2);
+    }
+}
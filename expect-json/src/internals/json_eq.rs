@@ -9,7 +9,19 @@ pub fn json_eq<'a>(
     received: &'a Value,
     expected: &'a Value,
 ) -> ExpectJsonResult<()> {
-    if let Some(expected_op) = SerializeExpectOp::maybe_parse(expected) {
+    let maybe_expected_op = SerializeExpectOp::maybe_parse(expected);
+    json_eq_parsed(context, received, expected, maybe_expected_op.as_ref())
+}
+
+/// The same as [`json_eq`], where the expected value has already been parsed as an op,
+/// if it is one.
+pub fn json_eq_parsed<'a>(
+    context: &mut Context<'a>,
+    received: &'a Value,
+    expected: &'a Value,
+    maybe_expected_op: Option<&SerializeExpectOp>,
+) -> ExpectJsonResult<()> {
+    if let Some(expected_op) = maybe_expected_op {
         expected_op
             .inner
             .on_any(context, received)
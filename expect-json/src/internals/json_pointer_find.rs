@@ -0,0 +1,55 @@
+use crate::ExpectJsonError;
+use crate::ExpectJsonResult;
+use crate::JsonType;
+use crate::expect_core::Context;
+use crate::internals::utils::parse_json_pointer;
+use crate::internals::utils::parse_json_pointer_index;
+use serde_json::Value;
+
+/// Finds the value at the Json Pointer given, within the received value.
+///
+/// Each segment found is pushed onto the context,
+/// so it is left pointing at the returned value.
+pub fn json_pointer_find<'a>(
+    context: &mut Context<'a>,
+    received: &'a Value,
+    pointer: &str,
+) -> ExpectJsonResult<&'a Value> {
+    let segments =
+        parse_json_pointer(pointer).ok_or_else(|| ExpectJsonError::InvalidJsonPointer {
+            pointer: pointer.to_string(),
+        })?;
+
+    json_segments_find(context, received, segments, pointer)
+}
+
+/// Finds the value at the unescaped segments given, within the received value.
+///
+/// The path is what the segments were parsed from, and is only used for errors.
+pub fn json_segments_find<'a>(
+    context: &mut Context<'a>,
+    received: &'a Value,
+    segments: Vec<String>,
+    path: &str,
+) -> ExpectJsonResult<&'a Value> {
+    let mut current = received;
+    for segment in segments {
+        let found = match current {
+            Value::Object(object) => object
+                .get(&segment)
+                .inspect(|_| context.push(segment.clone())),
+            Value::Array(array) => parse_json_pointer_index(&segment)
+                .and_then(|index| array.get(index).inspect(|_| context.push(index))),
+            _ => None,
+        };
+
+        current = found.ok_or_else(|| ExpectJsonError::JsonPointerNotFound {
+            context: context.to_static(),
+            json_type: JsonType::from(current),
+            segment,
+            pointer: path.to_string(),
+        })?;
+    }
+
+    Ok(current)
+}
@@ -1,13 +1,63 @@
 use crate::ExpectJsonError;
 use crate::ExpectJsonResult;
 use crate::expect_core::Context;
+use crate::internals::ErrorCollector;
 use crate::internals::objects::ArrayObject;
+use crate::internals::utils::bipartite_match;
 use serde_json::Value;
 
 pub fn json_value_eq_array<'a>(
     context: &mut Context<'a>,
     received_array: &'a [Value],
     expected_array: &'a [Value],
+) -> ExpectJsonResult<()> {
+    if context.options().is_unordered_array_at(context) {
+        return json_value_eq_array_unordered(context, received_array, expected_array);
+    }
+
+    // When collecting all errors, the items both arrays have are still compared.
+    // Unless the difference is at the start, as then the items would be misaligned.
+    let mut errors = ErrorCollector::new(context);
+    let length_result = json_value_eq_array_len(context, received_array, expected_array);
+    let is_different_at_start = matches!(
+        length_result,
+        Err(ExpectJsonError::ArrayMissingAtStart { .. } | ExpectJsonError::ArrayExtraAtStart { .. })
+    );
+    errors.check(length_result)?;
+    if is_different_at_start {
+        return errors.into_result();
+    }
+
+    for (index, (expected_value, received_value)) in
+        expected_array.iter().zip(received_array).enumerate()
+    {
+        // The index is kept on the context when building the error,
+        // so it can tell if the source error came from deeper within.
+        context.push(index);
+        let result = context
+            .json_eq(received_value, expected_value)
+            .map_err(|source_error| {
+                ExpectJsonError::array_index_missing(
+                    context,
+                    source_error,
+                    received_array,
+                    expected_array,
+                )
+            });
+        context.pop();
+
+        errors.check(result)?;
+    }
+
+    errors.into_result()
+}
+
+/// Checks the arrays are the same length,
+/// with special cases to give better error messages.
+fn json_value_eq_array_len(
+    context: &Context<'_>,
+    received_array: &[Value],
+    expected_array: &[Value],
 ) -> ExpectJsonResult<()> {
     // The Expected array is longer,
     //
@@ -73,23 +123,72 @@ pub fn json_value_eq_array<'a>(
         });
     }
 
-    for (index, (expected_value, received_value)) in
-        expected_array.iter().zip(received_array).enumerate()
+    Ok(())
+}
+
+fn json_value_eq_array_unordered<'a>(
+    context: &mut Context<'a>,
+    received_array: &'a [Value],
+    expected_array: &'a [Value],
+) -> ExpectJsonResult<()> {
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    for (expected_index, expected_value) in expected_array.iter().enumerate() {
+        for (received_index, received_value) in received_array.iter().enumerate() {
+            // Every pairing is only a candidate, so nothing captured here is kept.
+            let checkpoint = context.captures_checkpoint();
+            let is_equal = context
+                .with_path(received_index)
+                .json_eq(received_value, expected_value)
+                .is_ok();
+            checkpoint.restore();
+
+            if is_equal {
+                edges.push((expected_index, received_index));
+            }
+        }
+    }
+
+    // Matches are indexed by the received index, and hold the expected index.
+    let size = expected_array.len().max(received_array.len());
+    let matches = bipartite_match(size, &edges);
+
+    let extra_in_received = received_array
+        .iter()
+        .zip(&matches)
+        .filter(|(_, expected_index)| expected_index.is_none())
+        .map(|(received_value, _)| received_value.clone())
+        .collect::<Vec<_>>();
+    let missing_in_received = expected_array
+        .iter()
+        .enumerate()
+        .filter(|(expected_index, _)| !matches.contains(&Some(*expected_index)))
+        .map(|(_, expected_value)| expected_value.clone())
+        .collect::<Vec<_>>();
+
+    if !extra_in_received.is_empty() || !missing_in_received.is_empty() {
+        return Err(ExpectJsonError::ArrayUnorderedValuesAreDifferent {
+            context: context.to_static(),
+            received_array: ArrayObject::from(received_array.to_owned()),
+            expected_array: ArrayObject::from(expected_array.to_owned()),
+            missing_in_received: Box::new(ArrayObject::from(missing_in_received)),
+            extra_in_received: Box::new(ArrayObject::from(extra_in_received)),
+        });
+    }
+
+    // Compare the chosen pairs again, to capture their values.
+    let mut errors = ErrorCollector::new(context);
+    for (received_index, (received_value, expected_index)) in
+        received_array.iter().zip(&matches).enumerate()
     {
-        context
-            .with_path(index)
-            .json_eq(received_value, expected_value)
-            .map_err(|source_error| {
-                ExpectJsonError::array_index_missing(
-                    context,
-                    source_error,
-                    received_array,
-                    expected_array,
-                )
-            })?;
+        if let Some(expected_index) = expected_index {
+            let result = context
+                .with_path(received_index)
+                .json_eq(received_value, &expected_array[*expected_index]);
+            errors.check(result)?;
+        }
     }
 
-    Ok(())
+    errors.into_result()
 }
 
 fn has_more_at_end<'a>(
@@ -9,7 +9,12 @@ pub fn json_value_eq_float(
     received_number: f64,
     expected_number: f64,
 ) -> ExpectJsonResult<()> {
-    if received_number != expected_number {
+    let is_equal = match context.options().get_float_tolerance() {
+        Some(tolerance) => tolerance.is_within(expected_number, received_number),
+        None => received_number == expected_number,
+    };
+
+    if !is_equal {
         let received = FloatObject::from(received_number);
         let expected = FloatObject::from(expected_number);
 
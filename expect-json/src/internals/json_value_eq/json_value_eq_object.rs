@@ -1,9 +1,17 @@
+use crate::__private::SerializeExpectOp;
 use crate::ExpectJsonError;
 use crate::ExpectJsonResult;
 use crate::JsonType;
 use crate::expect_core::Context;
+use crate::internals::ErrorCollector;
 use crate::internals::JsonObject;
+use crate::internals::json_eq_parsed;
 use crate::internals::objects::ObjectObject;
+use serde_json::Value;
+
+/// An expected field, with its value parsed as an op up front if it is one,
+/// so it is only parsed once.
+type ExpectedField<'a> = (&'a String, &'a Value, Option<SerializeExpectOp>);
 
 pub fn json_value_eq_object<'a>(
     context: &mut Context<'a>,
@@ -14,41 +22,73 @@ pub fn json_value_eq_object<'a>(
         return json_value_eq_object_contains(context, received, expected);
     }
 
-    let received_len = received.len();
-    let expected_len = expected.len();
+    let mut errors = ErrorCollector::new(context);
+    let is_null_same_as_missing = context.options().is_null_same_as_missing();
+    let expected_fields = to_expected_fields(expected);
+    let required_len = expected_fields
+        .iter()
+        .filter(|(_, expected_value, maybe_expected_op)| {
+            !is_missing_key_allowed_in(context, expected_value, maybe_expected_op.as_ref())
+        })
+        .count();
+    let has_optional_keys = required_len < expected.len();
 
-    // We have a special error case when there is only one extra field,
-    // for prettier error output.
-    if received_len == expected_len + 1 {
-        let maybe_extra_field = received.keys().find(|key| !expected.contains_key(*key));
+    // Extra fields are only checked up front when received is larger,
+    // unless we are collecting all errors, where everything is reported.
+    // Optional keys, and nulls treated as missing, make the lengths unreliable,
+    // so then they are always checked.
+    let is_checking_extra_fields = received.len() > expected.len()
+        || has_optional_keys
+        || is_null_same_as_missing
+        || context.is_collecting_all_errors();
+    if is_checking_extra_fields && !context.options().is_ignoring_extra_fields() {
+        let mut extra_fields = received
+            .iter()
+            .filter(|(_, value)| !(is_null_same_as_missing && value.is_null()))
+            .filter(|(key, _)| !expected.contains_key(*key))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
 
-        if let Some(extra_field) = maybe_extra_field {
-            return Err(ExpectJsonError::ObjectReceivedHasExtraKey {
-                context: context.to_static(),
-                received_extra_field: extra_field.to_string(),
-                received_obj: ObjectObject::from(received.clone()).into(),
-                expected_obj: ObjectObject::from(expected.clone()).into(),
-            });
-        }
-    }
+        if !extra_fields.is_empty() {
+            // When collecting all errors, the other differences are reported on their own.
+            // So the diff only shows the extra fields, rather than repeating those differences.
+            let (received_obj, expected_obj) = if context.is_collecting_all_errors() {
+                let received_extra = received
+                    .iter()
+                    .filter(|(key, _)| extra_fields.contains(key))
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<JsonObject>();
+                (received_extra, JsonObject::new())
+            } else {
+                (received.clone(), expected.clone())
+            };
 
-    // For when received many extra field over what is expected.
-    if received_len > expected_len {
-        let extra_fields = received
-            .keys()
-            .filter(|key| !expected.contains_key(*key))
-            .cloned()
-            .collect();
-
-        return Err(ExpectJsonError::ObjectReceivedHasExtraKeys {
-            context: context.to_static(),
-            received_extra_fields: extra_fields,
-            received_obj: ObjectObject::from(received.clone()).into(),
-            expected_obj: ObjectObject::from(expected.clone()).into(),
-        });
+            // We have a special error case when there is only one extra field,
+            // for prettier error output.
+            if extra_fields.len() == 1 {
+                errors.check(Err(ExpectJsonError::ObjectReceivedHasExtraKey {
+                    context: context.to_static(),
+                    received_extra_field: extra_fields.remove(0),
+                    received_obj: Box::new(ObjectObject::from(received_obj).into()),
+                    expected_obj: Box::new(ObjectObject::from(expected_obj).into()),
+                }))?;
+            } else {
+                errors.check(Err(ExpectJsonError::ObjectReceivedHasExtraKeys {
+                    context: context.to_static(),
+                    received_extra_fields: extra_fields,
+                    received_obj: Box::new(ObjectObject::from(received_obj).into()),
+                    expected_obj: Box::new(ObjectObject::from(expected_obj).into()),
+                }))?;
+            }
+        }
     }
 
-    if received.len() != expected.len() {
+    // When collecting all errors, the missing keys are reported individually below.
+    // Nulls treated as missing are also left to be reported individually.
+    if received.len() < required_len
+        && !context.is_collecting_all_errors()
+        && !is_null_same_as_missing
+    {
         return Err(ExpectJsonError::DifferentValues {
             context: context.to_static(),
             json_type: JsonType::Object,
@@ -57,21 +97,7 @@ pub fn json_value_eq_object<'a>(
         });
     }
 
-    for (expected_key, expected_value) in expected.iter() {
-        let received_value =
-            received
-                .get(expected_key)
-                .ok_or_else(|| ExpectJsonError::ObjectKeyMissing {
-                    context: context.to_static(),
-                    expected_key: expected_key.to_string(),
-                })?;
-
-        context
-            .with_path(expected_key)
-            .json_eq(received_value, expected_value)?;
-    }
-
-    Ok(())
+    json_value_eq_object_keys(context, errors, received, expected_fields)
 }
 
 pub fn json_value_eq_object_contains<'a>(
@@ -79,19 +105,66 @@ pub fn json_value_eq_object_contains<'a>(
     received: &'a JsonObject,
     expected: &'a JsonObject,
 ) -> ExpectJsonResult<()> {
-    for (expected_key, expected_value) in expected {
-        let received_value =
-            received
-                .get(expected_key)
-                .ok_or_else(|| ExpectJsonError::ObjectKeyMissing {
-                    context: context.to_static(),
-                    expected_key: expected_key.to_string(),
-                })?;
+    let errors = ErrorCollector::new(context);
+    json_value_eq_object_keys(context, errors, received, to_expected_fields(expected))
+}
+
+fn json_value_eq_object_keys<'a>(
+    context: &mut Context<'a>,
+    mut errors: ErrorCollector<ExpectJsonError>,
+    received: &'a JsonObject,
+    expected_fields: Vec<ExpectedField<'a>>,
+) -> ExpectJsonResult<()> {
+    let is_null_same_as_missing = context.options().is_null_same_as_missing();
+    for (expected_key, expected_value, maybe_expected_op) in expected_fields {
+        let received_value = received
+            .get(expected_key)
+            .filter(|received_value| !(is_null_same_as_missing && received_value.is_null()));
+        let Some(received_value) = received_value else {
+            if is_missing_key_allowed_in(context, expected_value, maybe_expected_op.as_ref()) {
+                continue;
+            }
+
+            errors.check(Err(ExpectJsonError::ObjectKeyMissing {
+                context: context.to_static(),
+                expected_key: expected_key.to_string(),
+            }))?;
+            continue;
+        };
 
-        context
-            .with_path(expected_key)
-            .json_eq(received_value, expected_value)?;
+        context.push(expected_key);
+        let result = json_eq_parsed(
+            context,
+            received_value,
+            expected_value,
+            maybe_expected_op.as_ref(),
+        );
+        context.pop();
+        errors.check(result)?;
     }
 
-    Ok(())
+    errors.into_result()
+}
+
+fn to_expected_fields(expected: &JsonObject) -> Vec<ExpectedField<'_>> {
+    expected
+        .iter()
+        .map(|(key, value)| (key, value, SerializeExpectOp::maybe_parse(value)))
+        .collect()
+}
+
+/// The same as [`is_missing_key_allowed`], for a value already parsed,
+/// and also takes the options in the context into account.
+fn is_missing_key_allowed_in(
+    context: &Context<'_>,
+    expected_value: &Value,
+    maybe_expected_op: Option<&SerializeExpectOp>,
+) -> bool {
+    maybe_expected_op.is_some_and(|expect_op| expect_op.inner.allows_missing_key())
+        || (context.options().is_null_same_as_missing() && expected_value.is_null())
+}
+
+pub(crate) fn is_missing_key_allowed(expected_value: &Value) -> bool {
+    SerializeExpectOp::maybe_parse(expected_value)
+        .is_some_and(|expect_op| expect_op.inner.allows_missing_key())
 }
@@ -2,6 +2,9 @@ pub mod objects;
 pub mod pretty_formatter;
 pub mod utils;
 
+mod error_collector;
+pub use self::error_collector::*;
+
 mod expect_op_meta;
 pub use self::expect_op_meta::*;
 
@@ -13,3 +16,6 @@ use self::json_object::*;
 mod json_eq;
 
 pub use self::json_eq::*;
+
+mod json_pointer_find;
+pub use self::json_pointer_find::*;
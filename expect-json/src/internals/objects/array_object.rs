@@ -29,6 +29,12 @@ where
     }
 }
 
+impl From<ArrayObject> for Value {
+    fn from(array: ArrayObject) -> Self {
+        Value::Array(array.0.into_iter().map(Value::from).collect())
+    }
+}
+
 impl Display for ArrayObject {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         let mut pretty_formatter = PrettyFormatter::new(formatter);
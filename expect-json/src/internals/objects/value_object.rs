@@ -28,6 +28,10 @@ impl ValueObject {
     pub fn is_number(&self) -> bool {
         matches!(self, Self::Integer(_) | Self::Float(_))
     }
+
+    pub fn is_container(&self) -> bool {
+        matches!(self, Self::Array(_) | Self::Object(_))
+    }
 }
 
 impl From<Value> for ValueObject {
@@ -106,6 +110,21 @@ impl From<ObjectObject> for ValueObject {
     }
 }
 
+impl From<ValueObject> for Value {
+    fn from(value: ValueObject) -> Self {
+        match value {
+            ValueObject::Null(_) => Value::Null,
+            ValueObject::String(inner) => Value::String(inner.0),
+            ValueObject::Float(inner) => Value::from(inner.0),
+            ValueObject::Integer(IntegerObject::Positive(inner)) => Value::from(inner),
+            ValueObject::Integer(IntegerObject::Negative(inner)) => Value::from(inner),
+            ValueObject::Boolean(inner) => Value::Bool(inner.0),
+            ValueObject::Array(inner) => inner.into(),
+            ValueObject::Object(inner) => Value::Object(inner.0),
+        }
+    }
+}
+
 impl Display for ValueObject {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         let mut pretty_formatter = PrettyFormatter::new(formatter);
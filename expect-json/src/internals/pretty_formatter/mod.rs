@@ -1,5 +1,8 @@
 mod pretty_display;
 pub use self::pretty_display::*;
 
+mod pretty_diff;
+pub use self::pretty_diff::*;
+
 mod pretty_formatter;
 pub use self::pretty_formatter::*;
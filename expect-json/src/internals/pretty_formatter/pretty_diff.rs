@@ -0,0 +1,643 @@
+use crate::__private::SerializeExpectOp;
+use crate::expect_core::Context;
+use crate::internals::JsonObject;
+use crate::internals::json_eq;
+use crate::internals::json_value_eq::is_missing_key_allowed;
+use crate::internals::pretty_formatter::PrettyDisplay;
+use crate::internals::pretty_formatter::PrettyFormatter;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+const UNCHANGED_MARKER: char = ' ';
+const EXPECTED_MARKER: char = '-';
+const RECEIVED_MARKER: char = '+';
+
+/// Displays the expected and received values as one merged tree.
+///
+/// Lines only in expected are marked with '-', and lines only in received with '+'.
+/// Only the branches that differ are shown in full, with one sibling either side as context.
+/// All other unchanged siblings are collapsed into a single line.
+///
+/// Values are compared using the context of the error, so options,
+/// captures and field references are taken into account.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrettyDiff {
+    context: Context<'static>,
+    expected: Value,
+    received: Value,
+}
+
+impl PrettyDiff {
+    pub fn new<E, R>(context: &Context<'_>, expected: E, received: R) -> Self
+    where
+        E: Into<Value>,
+        R: Into<Value>,
+    {
+        Self {
+            context: context.to_static(),
+            expected: expected.into(),
+            received: received.into(),
+        }
+    }
+}
+
+impl Display for PrettyDiff {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        let mut pretty_formatter = PrettyFormatter::new(formatter);
+
+        pretty_formatter.set_diff_marker(EXPECTED_MARKER);
+        pretty_formatter.write_indentation()?;
+        write!(pretty_formatter, "expected")?;
+
+        pretty_formatter.set_diff_marker(RECEIVED_MARKER);
+        pretty_formatter.write_indentation()?;
+        write!(pretty_formatter, "received")?;
+
+        let mut context = self.context.with_kept_root();
+        write_diff(
+            &mut pretty_formatter,
+            &mut context,
+            None,
+            &self.expected,
+            &self.received,
+            false,
+        )
+    }
+}
+
+#[derive(Debug)]
+enum DiffEntry<'a> {
+    Unchanged(&'a Value),
+    Expected(&'a Value),
+    Received(&'a Value),
+    Changed {
+        expected: &'a Value,
+        received: &'a Value,
+    },
+}
+
+impl DiffEntry<'_> {
+    fn is_changed(&self) -> bool {
+        !matches!(self, Self::Unchanged(_))
+    }
+}
+
+fn write_diff<'a>(
+    formatter: &mut PrettyFormatter<'_, '_>,
+    context: &mut Context<'a>,
+    key: Option<&str>,
+    expected: &'a Value,
+    received: &'a Value,
+    has_trailing_comma: bool,
+) -> FmtResult {
+    match (expected, received) {
+        (Value::Object(expected_object), Value::Object(received_object))
+            if !SerializeExpectOp::has_object_magic_id(expected_object) =>
+        {
+            let entries = diff_object_entries(context, expected_object, received_object);
+            write_diff_container(formatter, context, key, ('{', '}'), "fields", &entries)?;
+        }
+        (Value::Array(expected_array), Value::Array(received_array)) => {
+            let entries = diff_array_entries(context, expected_array, received_array)
+                .into_iter()
+                .map(|entry| (None, entry))
+                .collect::<Vec<_>>();
+            write_diff_container(formatter, context, key, ('[', ']'), "items", &entries)?;
+        }
+        _ => {
+            write_diff_line(formatter, EXPECTED_MARKER, key, expected)?;
+            if has_trailing_comma {
+                write!(formatter, ",")?;
+            }
+
+            write_diff_line(formatter, RECEIVED_MARKER, key, received)?;
+        }
+    }
+
+    if has_trailing_comma {
+        write!(formatter, ",")?;
+    }
+
+    Ok(())
+}
+
+fn write_diff_container<'a>(
+    formatter: &mut PrettyFormatter<'_, '_>,
+    context: &mut Context<'a>,
+    key: Option<&str>,
+    (open, close): (char, char),
+    entries_name: &str,
+    entries: &[(Option<&str>, DiffEntry<'a>)],
+) -> FmtResult {
+    formatter.set_diff_marker(UNCHANGED_MARKER);
+    formatter.write_indentation()?;
+    write_key(formatter, key)?;
+    write!(formatter, "{open}")?;
+
+    formatter.increment_indentation();
+
+    let is_visible = visible_entries(entries);
+    let mut i = 0;
+    while i < entries.len() {
+        if !is_visible[i] {
+            let collapsed_len = is_visible[i..]
+                .iter()
+                .take_while(|is_visible| !**is_visible)
+                .count();
+
+            formatter.set_diff_marker(UNCHANGED_MARKER);
+            formatter.write_indentation()?;
+            write!(formatter, "... {collapsed_len} unchanged {entries_name}")?;
+
+            i += collapsed_len;
+            continue;
+        }
+
+        // Changed entries only happen in arrays when compared index by index,
+        // so the entry index is also the index within the array.
+        let (key, entry) = &entries[i];
+        match key {
+            Some(key) => context.push(key.to_string()),
+            None => context.push(i),
+        }
+        let result = write_diff_entry(formatter, context, *key, entry);
+        context.pop();
+
+        result?;
+        i += 1;
+    }
+
+    formatter.decrement_indentation();
+
+    formatter.set_diff_marker(UNCHANGED_MARKER);
+    formatter.write_indentation()?;
+    write!(formatter, "{close}")
+}
+
+fn write_diff_entry<'a>(
+    formatter: &mut PrettyFormatter<'_, '_>,
+    context: &mut Context<'a>,
+    key: Option<&str>,
+    entry: &DiffEntry<'a>,
+) -> FmtResult {
+    match *entry {
+        DiffEntry::Unchanged(value) => {
+            formatter.set_diff_marker(UNCHANGED_MARKER);
+            formatter.write_indentation()?;
+            write_key(formatter, key)?;
+            write_collapsed_value(formatter, value)?;
+            write!(formatter, ",")
+        }
+        DiffEntry::Expected(value) => {
+            write_diff_line(formatter, EXPECTED_MARKER, key, value)?;
+            write!(formatter, ",")
+        }
+        DiffEntry::Received(value) => {
+            write_diff_line(formatter, RECEIVED_MARKER, key, value)?;
+            write!(formatter, ",")
+        }
+        DiffEntry::Changed { expected, received } => {
+            write_diff(formatter, context, key, expected, received, true)
+        }
+    }
+}
+
+fn write_diff_line(
+    formatter: &mut PrettyFormatter<'_, '_>,
+    diff_marker: char,
+    key: Option<&str>,
+    value: &Value,
+) -> FmtResult {
+    formatter.set_diff_marker(diff_marker);
+    formatter.write_indentation()?;
+    write_key(formatter, key)?;
+    value.pretty_fmt(formatter)
+}
+
+fn write_key(formatter: &mut PrettyFormatter<'_, '_>, key: Option<&str>) -> FmtResult {
+    if let Some(key) = key {
+        // Written as a Json string, so quotes and backslashes within the key are escaped.
+        write!(formatter, "{}: ", Value::from(key))?;
+    }
+
+    Ok(())
+}
+
+/// Unchanged containers are only shown as context, so their contents are left out.
+fn write_collapsed_value(formatter: &mut PrettyFormatter<'_, '_>, value: &Value) -> FmtResult {
+    match value {
+        Value::Array(array) if !array.is_empty() => write!(formatter, "[...]"),
+        Value::Object(object)
+            if !object.is_empty() && !SerializeExpectOp::has_object_magic_id(object) =>
+        {
+            write!(formatter, "{{...}}")
+        }
+        _ => value.pretty_fmt(formatter),
+    }
+}
+
+/// Changed entries are always visible, along with their direct neighbours.
+/// Lone unchanged entries are also shown, as collapsing them would save nothing.
+fn visible_entries(entries: &[(Option<&str>, DiffEntry<'_>)]) -> Vec<bool> {
+    let is_changed = |i: usize| entries.get(i).is_some_and(|(_, entry)| entry.is_changed());
+
+    let mut is_visible = (0..entries.len())
+        .map(|i| is_changed(i) || is_changed(i + 1) || (i > 0 && is_changed(i - 1)))
+        .collect::<Vec<_>>();
+
+    for i in 0..is_visible.len() {
+        let is_previous_visible = i == 0 || is_visible[i - 1];
+        let is_next_visible = is_visible.get(i + 1).copied().unwrap_or(true);
+        if is_previous_visible && is_next_visible {
+            is_visible[i] = true;
+        }
+    }
+
+    is_visible
+}
+
+fn diff_object_entries<'a>(
+    context: &mut Context<'a>,
+    expected: &'a JsonObject,
+    received: &'a JsonObject,
+) -> Vec<(Option<&'a str>, DiffEntry<'a>)> {
+    let keys = expected
+        .keys()
+        .chain(received.keys())
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let entry = match (expected.get(key), received.get(key)) {
+                (Some(expected), Some(received)) => {
+                    context.push(key.to_string());
+                    let entry = diff_values(context, expected, received);
+                    context.pop();
+
+                    entry
+                }
+                // Keys allowed to be missing are not a difference, so are left out.
+                (Some(expected), None) if is_missing_key_allowed(expected) => return None,
+                (Some(expected), None) => DiffEntry::Expected(expected),
+                (None, Some(received)) => DiffEntry::Received(received),
+                (None, None) => unreachable!("key is from either expected or received"),
+            };
+
+            Some((Some(key), entry))
+        })
+        .collect()
+}
+
+fn diff_array_entries<'a>(
+    context: &mut Context<'a>,
+    expected: &'a [Value],
+    received: &'a [Value],
+) -> Vec<DiffEntry<'a>> {
+    // When the lengths match, we compare index by index.
+    // This allows changed items to be diffed deeper.
+    if expected.len() == received.len() {
+        return expected
+            .iter()
+            .zip(received)
+            .enumerate()
+            .map(|(index, (expected, received))| {
+                context.push(index);
+                let entry = diff_values(context, expected, received);
+                context.pop();
+
+                entry
+            })
+            .collect();
+    }
+
+    // Otherwise the items are aligned using the longest common subsequence.
+    let expected_len = expected.len();
+    let received_len = received.len();
+    let is_equal = expected
+        .iter()
+        .map(|expected| {
+            received
+                .iter()
+                .enumerate()
+                .map(|(index, received)| {
+                    context.push(index);
+                    let is_equal = is_value_equal(context, expected, received);
+                    context.pop();
+
+                    is_equal
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut lcs_lens = vec![vec![0_usize; received_len + 1]; expected_len + 1];
+    for i in (0..expected_len).rev() {
+        for j in (0..received_len).rev() {
+            lcs_lens[i][j] = if is_equal[i][j] {
+                lcs_lens[i + 1][j + 1] + 1
+            } else {
+                lcs_lens[i + 1][j].max(lcs_lens[i][j + 1])
+            };
+        }
+    }
+
+    let mut entries = Vec::with_capacity(expected_len.max(received_len));
+    let (mut i, mut j) = (0, 0);
+    while i < expected_len || j < received_len {
+        if i < expected_len && j < received_len && is_equal[i][j] {
+            entries.push(DiffEntry::Unchanged(&received[j]));
+            i += 1;
+            j += 1;
+        } else if i < expected_len
+            && (j == received_len || lcs_lens[i + 1][j] >= lcs_lens[i][j + 1])
+        {
+            entries.push(DiffEntry::Expected(&expected[i]));
+            i += 1;
+        } else {
+            entries.push(DiffEntry::Received(&received[j]));
+            j += 1;
+        }
+    }
+
+    entries
+}
+
+fn diff_values<'a>(
+    context: &mut Context<'a>,
+    expected: &'a Value,
+    received: &'a Value,
+) -> DiffEntry<'a> {
+    if is_value_equal(context, expected, received) {
+        DiffEntry::Unchanged(received)
+    } else {
+        DiffEntry::Changed { expected, received }
+    }
+}
+
+/// Values are compared in the same way as normal, so expect operations are taken into account.
+/// Nothing captured whilst comparing is kept, as the assertion has already finished.
+fn is_value_equal<'a>(context: &mut Context<'a>, expected: &'a Value, received: &'a Value) -> bool {
+    let checkpoint = context.captures_checkpoint();
+    let is_equal = json_eq(context, received, expected).is_ok();
+    checkpoint.restore();
+
+    is_equal
+}
+
+#[cfg(test)]
+mod test_fmt {
+    use super::*;
+    use crate::expect;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_show_changed_fields_in_objects() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "name": "Joe", "age": 30 }),
+            json!({ "name": "Joe", "age": 31 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+    -     "age": 30,
+    +     "age": 31,
+          "name": "Joe",
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_show_missing_and_extra_fields() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "name": "Joe", "email": "joe@example.com" }),
+            json!({ "name": "Joe", "age": 30 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+    +     "age": 30,
+    -     "email": "joe@example.com",
+          "name": "Joe",
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_escape_keys() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ r#"say "hi""#: 1, r"a\b": true }),
+            json!({ r#"say "hi""#: 2, r"a\b": true }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+          "a\\b": true,
+    -     "say \"hi\"": 1,
+    +     "say \"hi\"": 2,
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_collapse_unchanged_siblings() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "a": 1, "b": 2, "c": 3, "d": 4, "e": 5 }),
+            json!({ "a": 1, "b": 2, "c": 3, "d": 4, "e": 6 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+          ... 3 unchanged fields
+          "d": 4,
+    -     "e": 5,
+    +     "e": 6,
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_diff_nested_containers() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "user": { "name": "Joe", "tags": ["a", "b"] }, "id": 1 }),
+            json!({ "user": { "name": "Joe", "tags": ["a", "c"] }, "id": 1 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+          "id": 1,
+          "user": {
+              "name": "Joe",
+              "tags": [
+                  "a",
+    -             "b",
+    +             "c",
+              ],
+          },
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_collapse_unchanged_containers_used_as_context() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "a": { "x": 1 }, "b": 2 }),
+            json!({ "a": { "x": 1 }, "b": 3 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+          "a": {...},
+    -     "b": 2,
+    +     "b": 3,
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_show_whole_values_when_added_or_removed() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({}),
+            json!({ "extra": { "inner": [1, 2] } }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+    +     "extra": {
+    +         "inner": [1, 2]
+    +     },
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_align_arrays_of_different_lengths() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!([1, 2, 3, 4, 5, 6, 7]),
+            json!([1, 2, 3, 5, 6, 7, 8, 9]),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      [
+          ... 2 unchanged items
+          3,
+    -     4,
+          5,
+          6,
+          7,
+    +     8,
+    +     9,
+      ]"#
+        );
+    }
+
+    #[test]
+    fn it_should_treat_passing_expect_ops_as_unchanged() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "id": expect::integer(), "name": "Joe" }),
+            json!({ "id": 123, "name": "Jane" }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+          "id": 123,
+    -     "name": "Joe",
+    +     "name": "Jane",
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_show_failing_expect_ops_by_name() {
+        let diff = PrettyDiff::new(
+            &Context::new(),
+            json!({ "id": expect::string() }),
+            json!({ "id": 123 }),
+        );
+
+        assert_eq!(
+            diff.to_string(),
+            r#"
+    - expected
+    + received
+      {
+    -     "id": expect::string(),
+    +     "id": 123,
+      }"#
+        );
+    }
+
+    #[test]
+    fn it_should_compare_field_references_against_the_received_root() {
+        let left = json!({ "items": [{ "start": 1, "end": 5 }, 3, 4] });
+        let right = json!({ "items": [
+            { "start": 1, "end": expect::integer().greater_than(expect::field("../start")) },
+            2,
+        ] });
+
+        let output = crate::expect_json_eq(&left, &right)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            output,
+            r#"Json arrays at root.items are not equal:
+    - expected
+    + received
+      [
+          {...},
+    -     2,
+    +     3,
+    +     4,
+      ]"#
+        );
+    }
+}
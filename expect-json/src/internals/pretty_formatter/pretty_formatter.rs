@@ -12,6 +12,7 @@ const INDENTATION_SIZE: usize = 4;
 
 pub struct PrettyFormatter<'a, 'b> {
     indentation: usize,
+    diff_marker: Option<char>,
     formatter: &'a mut Formatter<'b>,
 }
 
@@ -19,10 +20,17 @@ impl<'a, 'b> PrettyFormatter<'a, 'b> {
     pub fn new(formatter: &'a mut Formatter<'b>) -> Self {
         Self {
             indentation: INDENTATION_SIZE,
+            diff_marker: None,
             formatter,
         }
     }
 
+    /// Sets a marker written at the start of every new line, such as '+' or '-'.
+    /// This is for writing diffs.
+    pub fn set_diff_marker(&mut self, diff_marker: char) {
+        self.diff_marker = Some(diff_marker);
+    }
+
     pub fn write_fmt(&mut self, arguments: Arguments<'_>) -> FmtResult {
         self.formatter.write_fmt(arguments)
     }
@@ -108,7 +116,13 @@ impl<'a, 'b> PrettyFormatter<'a, 'b> {
     pub fn write_indentation(&mut self) -> FmtResult {
         writeln!(self.formatter)?;
 
-        for _ in 0..self.indentation {
+        let mut indentation = self.indentation;
+        if let Some(diff_marker) = self.diff_marker {
+            write!(self.formatter, "{:INDENTATION_SIZE$}{diff_marker} ", "")?;
+            indentation -= INDENTATION_SIZE;
+        }
+
+        for _ in 0..indentation {
             write!(self.formatter, " ")?;
         }
 
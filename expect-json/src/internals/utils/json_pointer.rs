@@ -0,0 +1,72 @@
+/// Splits a Json Pointer (RFC 6901) into its unescaped segments.
+///
+/// Returns `None` if the pointer is invalid.
+pub fn parse_json_pointer(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(vec![]);
+    }
+
+    let segments = pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect();
+
+    Some(segments)
+}
+
+/// Parses a Json Pointer segment as an array index.
+///
+/// Leading zeros are not allowed, as per RFC 6901.
+pub fn parse_json_pointer_index(segment: &str) -> Option<usize> {
+    let is_digits = !segment.is_empty() && segment.bytes().all(|c| c.is_ascii_digit());
+    if !is_digits || (segment.len() > 1 && segment.starts_with('0')) {
+        return None;
+    }
+
+    segment.parse().ok()
+}
+
+#[cfg(test)]
+mod test_parse_json_pointer {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_parse_empty_pointer_as_the_root() {
+        assert_eq!(parse_json_pointer(""), Some(vec![]));
+    }
+
+    #[test]
+    fn it_should_unescape_segments() {
+        let output = parse_json_pointer("/a~1b/m~0n/0");
+        assert_eq!(
+            output,
+            Some(vec!["a/b".to_string(), "m~n".to_string(), "0".to_string()])
+        );
+    }
+
+    #[test]
+    fn it_should_reject_pointers_without_a_leading_slash() {
+        assert_eq!(parse_json_pointer("a/b"), None);
+    }
+}
+
+#[cfg(test)]
+mod test_parse_json_pointer_index {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_parse_indexes() {
+        assert_eq!(parse_json_pointer_index("0"), Some(0));
+        assert_eq!(parse_json_pointer_index("12"), Some(12));
+    }
+
+    #[test]
+    fn it_should_reject_leading_zeros_and_non_digits() {
+        assert_eq!(parse_json_pointer_index("01"), None);
+        assert_eq!(parse_json_pointer_index("-"), None);
+        assert_eq!(parse_json_pointer_index("+1"), None);
+    }
+}
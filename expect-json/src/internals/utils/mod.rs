@@ -2,3 +2,9 @@ mod js_identifiers;
 pub use self::js_identifiers::*;
 mod bipartite_match;
 pub use self::bipartite_match::*;
+mod json_pointer;
+pub use self::json_pointer::*;
+mod regex_pattern;
+pub use self::regex_pattern::*;
+mod wildcard_pattern;
+pub use self::wildcard_pattern::*;
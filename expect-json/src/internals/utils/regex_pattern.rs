@@ -0,0 +1,52 @@
+use regex::Error as RegexError;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::OnceLock;
+
+/// A regex pattern, which is compiled once when first used.
+///
+/// This serialises as the pattern on its own,
+/// so patterns deserialised from elsewhere are compiled when they are first used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RegexPattern {
+    pattern: String,
+
+    #[serde(skip)]
+    compiled: OnceLock<Result<Regex, RegexError>>,
+}
+
+impl RegexPattern {
+    /// Compiles the pattern given, returning an error if it is invalid.
+    pub fn new(pattern: String) -> Result<Self, RegexError> {
+        let regex = Regex::new(&pattern)?;
+
+        Ok(Self {
+            pattern,
+            compiled: OnceLock::from(Ok(regex)),
+        })
+    }
+
+    pub fn regex(&self) -> Result<&Regex, &RegexError> {
+        self.compiled
+            .get_or_init(|| Regex::new(&self.pattern))
+            .as_ref()
+    }
+}
+
+/// Patterns are equal when they are written the same.
+impl PartialEq for RegexPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Display for RegexPattern {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        write!(formatter, "{}", self.pattern)
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// The flavour of wildcards used by a [`WildcardPattern`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum WildcardSyntax {
+    /// `*` matches any text, and `?` matches any single character.
+    Glob,
+    /// SQL `LIKE` patterns, where `%` matches any text, and `_` matches any single character.
+    Like,
+}
+
+impl WildcardSyntax {
+    fn any_sequence(self) -> char {
+        match self {
+            Self::Glob => '*',
+            Self::Like => '%',
+        }
+    }
+
+    fn any_char(self) -> char {
+        match self {
+            Self::Glob => '?',
+            Self::Like => '_',
+        }
+    }
+}
+
+impl Display for WildcardSyntax {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Glob => write!(formatter, "glob"),
+            Self::Like => write!(formatter, "like"),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum WildcardToken {
+    Literal(char),
+    AnyChar,
+    AnySequence,
+}
+
+///
+/// A pattern matched against the whole of a string,
+/// using wildcards rather than a regex. Wildcards can be escaped with a backslash.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct WildcardPattern<'a> {
+    source: &'a str,
+    /// Each token, with the byte offset in the source where it ends.
+    tokens: Vec<(WildcardToken, usize)>,
+}
+
+/// Describes how far a pattern got through a string, before it failed to match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WildcardMismatch<'a> {
+    pub pattern_matched: &'a str,
+    pub pattern_remaining: &'a str,
+    pub received_matched: &'a str,
+    /// The char index and char where the string diverged from the pattern,
+    /// or `None` if the string ended before the pattern did.
+    pub maybe_diverged_at: Option<(usize, char)>,
+}
+
+impl<'a> WildcardPattern<'a> {
+    pub fn parse(syntax: WildcardSyntax, source: &'a str) -> Result<Self, String> {
+        let mut tokens = vec![];
+        let mut chars = source.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let token = match c {
+                '\\' => match chars.next() {
+                    Some((_, escaped)) => WildcardToken::Literal(escaped),
+                    None => {
+                        return Err(format!(
+                            "trailing '\\' at byte {index}, with nothing to escape"
+                        ));
+                    }
+                },
+                c if c == syntax.any_sequence() => WildcardToken::AnySequence,
+                c if c == syntax.any_char() => WildcardToken::AnyChar,
+                c => WildcardToken::Literal(c),
+            };
+
+            let end = chars
+                .peek()
+                .map_or(source.len(), |(next_index, _)| *next_index);
+            tokens.push((token, end));
+        }
+
+        Ok(Self { source, tokens })
+    }
+
+    /// Returns `None` if the whole string matches.
+    ///
+    /// Otherwise this describes the furthest the pattern got through the string,
+    /// preferring the latest point in the string if that progress was held for a while.
+    pub fn find_mismatch<'r>(&self, received: &'r str) -> Option<WildcardMismatch<'r>>
+    where
+        'a: 'r,
+    {
+        let mut positions = self.closure(vec![0]);
+        let mut furthest = (0, 0);
+
+        for (byte_index, c) in received.char_indices() {
+            furthest = furthest_progress(furthest, &positions, byte_index);
+
+            let next_positions = positions
+                .iter()
+                .filter_map(|&position| match self.tokens.get(position)?.0 {
+                    WildcardToken::Literal(literal) if literal == c => Some(position + 1),
+                    WildcardToken::Literal(_) => None,
+                    WildcardToken::AnyChar => Some(position + 1),
+                    WildcardToken::AnySequence => Some(position),
+                })
+                .collect::<Vec<_>>();
+
+            positions = self.closure(next_positions);
+            if positions.is_empty() {
+                return Some(self.to_mismatch(furthest, received));
+            }
+        }
+
+        if positions.contains(&self.tokens.len()) {
+            return None;
+        }
+
+        furthest = furthest_progress(furthest, &positions, received.len());
+        Some(self.to_mismatch(furthest, received))
+    }
+
+    /// Adds the positions reachable by matching `AnySequence` against nothing.
+    fn closure(&self, mut positions: Vec<usize>) -> Vec<usize> {
+        let mut index = 0;
+        while let Some(&position) = positions.get(index) {
+            let is_any_sequence = matches!(
+                self.tokens.get(position),
+                Some((WildcardToken::AnySequence, _))
+            );
+            if is_any_sequence && !positions.contains(&(position + 1)) {
+                positions.push(position + 1);
+            }
+            index += 1;
+        }
+
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
+    fn to_mismatch<'r>(
+        &self,
+        (furthest_position, received_end): (usize, usize),
+        received: &'r str,
+    ) -> WildcardMismatch<'r>
+    where
+        'a: 'r,
+    {
+        let pattern_end = match furthest_position {
+            0 => 0,
+            position => self.tokens[position - 1].1,
+        };
+        let received_matched = &received[..received_end];
+        let maybe_diverged_at = received[received_end..]
+            .chars()
+            .next()
+            .map(|c| (received_matched.chars().count(), c));
+
+        WildcardMismatch {
+            pattern_matched: &self.source[..pattern_end],
+            pattern_remaining: &self.source[pattern_end..],
+            received_matched,
+            maybe_diverged_at,
+        }
+    }
+}
+
+/// Returns the furthest position in the pattern, and the byte index in the string it was reached at.
+fn furthest_progress(
+    (furthest_position, furthest_byte_index): (usize, usize),
+    positions: &[usize],
+    byte_index: usize,
+) -> (usize, usize) {
+    match positions.iter().copied().max() {
+        Some(position) if position >= furthest_position => (position, byte_index),
+        _ => (furthest_position, furthest_byte_index),
+    }
+}
+
+#[cfg(test)]
+mod test_find_mismatch {
+    use super::*;
+
+    fn glob(pattern: &str, received: &str) -> Option<(String, String, Option<(usize, char)>)> {
+        WildcardPattern::parse(WildcardSyntax::Glob, pattern)
+            .unwrap()
+            .find_mismatch(received)
+            .map(|mismatch| {
+                (
+                    mismatch.pattern_matched.to_string(),
+                    mismatch.received_matched.to_string(),
+                    mismatch.maybe_diverged_at,
+                )
+            })
+    }
+
+    #[test]
+    fn it_should_match_literals_and_wildcards() {
+        assert_eq!(glob("user-*-????", "user-abc-1234"), None);
+        assert_eq!(glob("user-*-????", "user--1234"), None);
+        assert_eq!(glob("*", ""), None);
+        assert_eq!(glob("", ""), None);
+    }
+
+    #[test]
+    fn it_should_match_escaped_wildcards_literally() {
+        assert_eq!(glob(r"what\?", "what?"), None);
+        assert_eq!(
+            glob(r"what\?", "whats"),
+            Some(("what".to_string(), "what".to_string(), Some((4, 's'))))
+        );
+    }
+
+    #[test]
+    fn it_should_report_where_the_string_diverged() {
+        assert_eq!(
+            glob("user-*-????", "admin-abc-1234"),
+            Some(("".to_string(), "".to_string(), Some((0, 'a'))))
+        );
+        assert_eq!(
+            glob("user-*.json", "user-abc.jsx"),
+            Some((
+                "user-*.js".to_string(),
+                "user-abc.js".to_string(),
+                Some((11, 'x'))
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_furthest_point_reached_after_a_wildcard() {
+        assert_eq!(
+            glob("*.json", "data.txt"),
+            Some(("*.".to_string(), "data.".to_string(), Some((5, 't'))))
+        );
+        assert_eq!(
+            glob("*.json", "data"),
+            Some(("*".to_string(), "data".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn it_should_report_when_the_string_ends_early() {
+        assert_eq!(
+            glob("user-*-????", "user-abc-12"),
+            Some(("user-*-??".to_string(), "user-abc-12".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn it_should_use_sql_wildcards_for_like() {
+        let pattern = WildcardPattern::parse(WildcardSyntax::Like, "%@%.com").unwrap();
+
+        assert_eq!(pattern.find_mismatch("joe@example.com"), None);
+        assert!(pattern.find_mismatch("joe@example.org").is_some());
+        assert!(pattern.find_mismatch("joe*example.com").is_some());
+    }
+
+    #[test]
+    fn it_should_reject_a_trailing_escape() {
+        let output = WildcardPattern::parse(WildcardSyntax::Glob, r"abc\");
+
+        assert_eq!(
+            output,
+            Err(r"trailing '\' at byte 3, with nothing to escape".to_string())
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Value;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -14,6 +15,23 @@ pub enum JsonInteger {
 }
 
 impl JsonInteger {
+    /// Returns the integer held in the Json value, if it is an integer.
+    pub(crate) fn from_json_value(value: &Value) -> Option<Self> {
+        value
+            .as_u64()
+            .map(Self::Positive)
+            .or_else(|| value.as_i64().map(Self::Negative))
+    }
+
+    /// Compares the numbers, regardless of which variant holds them.
+    pub(crate) fn eq_value<O>(self, other: O) -> bool
+    where
+        O: Into<JsonInteger>,
+    {
+        let other_json = other.into();
+        self.ge(other_json) && self.le(other_json)
+    }
+
     pub(crate) fn gt<O>(self, other: O) -> bool
     where
         O: Into<JsonInteger>,
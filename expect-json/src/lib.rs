@@ -1,18 +1,32 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::module_inception)]
+// Errors hold the context they happened at, which is large, and only built on failure.
+#![allow(clippy::result_large_err)]
 
 pub(crate) mod internals;
 
 pub mod expect;
 pub mod expect_core;
 
+mod clock;
+pub use self::clock::*;
+
+mod expect_json_captures;
+pub use self::expect_json_captures::*;
+
 mod expect_json_error;
 pub use self::expect_json_error::*;
 
 mod expect_json_eq;
 pub use self::expect_json_eq::*;
 
+mod expect_json_options;
+pub use self::expect_json_options::*;
+
+mod expect_json_snapshot;
+pub use self::expect_json_snapshot::*;
+
 mod json_integer;
 pub use self::json_integer::*;
 
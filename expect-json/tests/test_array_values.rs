@@ -95,8 +95,14 @@ fn it_should_not_be_equal_when_receiving_one_extra_value_at_the_end() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, received 1 extra item at the end:
-    expected [1, 2]
-    received [1, 2, 3]
+    - expected
+    + received
+      [
+          1,
+          2,
+    +     3,
+      ]
+
        extra [3]",
     );
 }
@@ -110,8 +116,16 @@ fn it_should_not_be_equal_when_receiving_many_extra_values_at_the_end() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, received 3 extra items at the end:
-    expected [1, 2]
-    received [1, 2, 3, 4, 5]
+    - expected
+    + received
+      [
+          1,
+          2,
+    +     3,
+    +     4,
+    +     5,
+      ]
+
        extra [3, 4, 5]",
     );
 }
@@ -125,8 +139,14 @@ fn it_should_not_be_equal_when_receiving_extra_values_at_the_start() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, received 1 extra item at the start:
-    expected [1, 2]
-    received [0, 1, 2]
+    - expected
+    + received
+      [
+    +     0,
+          1,
+          2,
+      ]
+
        extra [0]",
     );
 }
@@ -140,8 +160,14 @@ fn it_should_not_be_equal_when_receiving_extra_values_in_the_middle() {
     assert_eq!(
         output,
         "Json arrays at root are not equal:
-    expected [1, 2, 3]
-    received [1, 2, 2, 3]",
+    - expected
+    + received
+      [
+          1,
+          2,
+    +     2,
+          3,
+      ]",
     );
 }
 
@@ -154,8 +180,14 @@ fn it_should_not_be_equal_when_expecting_extra_values_in_the_middle() {
     assert_eq!(
         output,
         "Json arrays at root are not equal:
-    expected [1, 2, 2, 3]
-    received [1, 2, 3]",
+    - expected
+    + received
+      [
+          1,
+          2,
+    -     2,
+          3,
+      ]",
     );
 }
 
@@ -168,8 +200,15 @@ fn it_should_not_be_equal_when_differences_in_outer_and_inner_array_lengths() {
     assert_eq!(
         output,
         "Json arrays at root are not equal:
-    expected [1, [2, 2], 3, 4]
-    received [1, [2], 3]",
+    - expected
+    + received
+      [
+          1,
+    -     [2, 2],
+    +     [2],
+          3,
+    -     4,
+      ]",
     );
 }
 
@@ -198,8 +237,14 @@ fn it_should_not_be_equal_when_missing_expected_value_at_the_end() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, missing 1 item at the end:
-    expected [1, 2, 3, 4]
-    received [1, 2, 3]
+    - expected
+    + received
+      [
+          ... 2 unchanged items
+          3,
+    -     4,
+      ]
+
      missing [4]",
     );
 }
@@ -213,8 +258,16 @@ fn it_should_not_be_equal_when_missing_many_expected_values_at_the_end() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, missing 3 items at the end:
-    expected [1, 2, 3, 4, 5, 6]
-    received [1, 2, 3]
+    - expected
+    + received
+      [
+          ... 2 unchanged items
+          3,
+    -     4,
+    -     5,
+    -     6,
+      ]
+
      missing [4, 5, 6]",
     );
 }
@@ -228,8 +281,14 @@ fn it_should_not_be_equal_when_missing_expected_value_at_the_start() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, missing 1 item from the start:
-    expected [1, 2, 3, 4]
-    received [2, 3, 4]
+    - expected
+    + received
+      [
+    -     1,
+          2,
+          ... 2 unchanged items
+      ]
+
      missing [1]",
     );
 }
@@ -243,8 +302,16 @@ fn it_should_not_be_equal_when_missing_many_expected_values_at_the_start() {
     assert_eq!(
         output,
         "Json arrays at root are not equal, missing 3 items from the start:
-    expected [-1, 0, 1, 2, 3, 4]
-    received [2, 3, 4]
+    - expected
+    + received
+      [
+    -     -1,
+    -     0,
+    -     1,
+          2,
+          ... 2 unchanged items
+      ]
+
      missing [-1, 0, 1]",
     );
 }
@@ -66,10 +66,11 @@ fn it_should_error_if_expected_has_extra_field() {
     assert_eq!(
         output,
         r#"Json objects at root are not equal:
-    expected {
-        "extra": "🦊"
-    }
-    received {}"#,
+    - expected
+    + received
+      {
+    -     "extra": "🦊",
+      }"#,
     );
 }
 
@@ -96,10 +97,11 @@ fn it_should_have_appropriate_error_message_on_fields_with_spaces() {
     assert_eq!(
         output,
         r#"Json objects at root are not equal:
-    expected {
-        "something extra with spaces": "🦊"
-    }
-    received {}"#,
+    - expected
+    + received
+      {
+    -     "something extra with spaces": "🦊",
+      }"#,
     );
 }
 
@@ -112,10 +114,11 @@ fn it_should_error_if_received_has_extra_field() {
     assert_eq!(
         output,
         r#"Json object at root has extra field "extra":
-    expected {}
-    received {
-        "extra": "🦊"
-    }"#,
+    - expected
+    + received
+      {
+    +     "extra": "🦊",
+      }"#,
     );
 }
 
@@ -186,21 +189,22 @@ fn it_should_pretty_print_big_objects_when_it_has_one_extra_field() {
     assert_eq!(
         output,
         r#"Json object at root has extra field "obj_of_obj":
-    expected {}
-    received {
-        "obj_of_obj": {
-            "inner": {
-                "falsy": false,
-                "float": 123,
-                "floats": [1.1, 2.2, 3.3],
-                "int": 123,
-                "integers": [1, 2, 3],
-                "nullable": null,
-                "string": "abc123",
-                "truthy": true
-            }
-        }
-    }"#,
+    - expected
+    + received
+      {
+    +     "obj_of_obj": {
+    +         "inner": {
+    +             "falsy": false,
+    +             "float": 123,
+    +             "floats": [1.1, 2.2, 3.3],
+    +             "int": 123,
+    +             "integers": [1, 2, 3],
+    +             "nullable": null,
+    +             "string": "abc123",
+    +             "truthy": true
+    +         }
+    +     },
+      }"#,
     );
 }
 
@@ -236,73 +240,74 @@ fn it_should_pretty_print_big_objects_when_it_has_many_extra_fields() {
     assert_eq!(
         output,
         r#"Json object at root has many extra fields over expected:
-    expected {}
-    received {
-        "array_of_array_of_object": [
-            [
-                {
-                    "falsy": false,
-                    "float": 123,
-                    "floats": [1.1, 2.2, 3.3],
-                    "int": 123,
-                    "integers": [1, 2, 3],
-                    "nullable": null,
-                    "string": "abc123",
-                    "truthy": true
-                }
-            ],
-            [
-                {
-                    "falsy": false,
-                    "float": 123,
-                    "floats": [1.1, 2.2, 3.3],
-                    "int": 123,
-                    "integers": [1, 2, 3],
-                    "nullable": null,
-                    "string": "abc123",
-                    "truthy": true
-                }
-            ]
-        ],
-        "array_of_object": [
-            {
-                "falsy": false,
-                "float": 123,
-                "floats": [1.1, 2.2, 3.3],
-                "int": 123,
-                "integers": [1, 2, 3],
-                "nullable": null,
-                "string": "abc123",
-                "truthy": true
-            }
-        ],
-        "obj_array_of_obj": {
-            "inner": [
-                {
-                    "falsy": false,
-                    "float": 123,
-                    "floats": [1.1, 2.2, 3.3],
-                    "int": 123,
-                    "integers": [1, 2, 3],
-                    "nullable": null,
-                    "string": "abc123",
-                    "truthy": true
-                }
-            ]
-        },
-        "obj_of_obj": {
-            "inner": {
-                "falsy": false,
-                "float": 123,
-                "floats": [1.1, 2.2, 3.3],
-                "int": 123,
-                "integers": [1, 2, 3],
-                "nullable": null,
-                "string": "abc123",
-                "truthy": true
-            }
-        }
-    }
+    - expected
+    + received
+      {
+    +     "array_of_array_of_object": [
+    +         [
+    +             {
+    +                 "falsy": false,
+    +                 "float": 123,
+    +                 "floats": [1.1, 2.2, 3.3],
+    +                 "int": 123,
+    +                 "integers": [1, 2, 3],
+    +                 "nullable": null,
+    +                 "string": "abc123",
+    +                 "truthy": true
+    +             }
+    +         ],
+    +         [
+    +             {
+    +                 "falsy": false,
+    +                 "float": 123,
+    +                 "floats": [1.1, 2.2, 3.3],
+    +                 "int": 123,
+    +                 "integers": [1, 2, 3],
+    +                 "nullable": null,
+    +                 "string": "abc123",
+    +                 "truthy": true
+    +             }
+    +         ]
+    +     ],
+    +     "array_of_object": [
+    +         {
+    +             "falsy": false,
+    +             "float": 123,
+    +             "floats": [1.1, 2.2, 3.3],
+    +             "int": 123,
+    +             "integers": [1, 2, 3],
+    +             "nullable": null,
+    +             "string": "abc123",
+    +             "truthy": true
+    +         }
+    +     ],
+    +     "obj_array_of_obj": {
+    +         "inner": [
+    +             {
+    +                 "falsy": false,
+    +                 "float": 123,
+    +                 "floats": [1.1, 2.2, 3.3],
+    +                 "int": 123,
+    +                 "integers": [1, 2, 3],
+    +                 "nullable": null,
+    +                 "string": "abc123",
+    +                 "truthy": true
+    +             }
+    +         ]
+    +     },
+    +     "obj_of_obj": {
+    +         "inner": {
+    +             "falsy": false,
+    +             "float": 123,
+    +             "floats": [1.1, 2.2, 3.3],
+    +             "int": 123,
+    +             "integers": [1, 2, 3],
+    +             "nullable": null,
+    +             "string": "abc123",
+    +             "truthy": true
+    +         }
+    +     },
+      }
 
     extra fields in received:
         array_of_array_of_object,
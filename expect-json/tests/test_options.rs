@@ -0,0 +1,263 @@
+use chrono::TimeZone;
+use chrono::Utc;
+use expect_json::expect::ops::FloatTolerance;
+use expect_json::*;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn it_should_be_strict_with_default_options() {
+    let output = expect_json_eq_with(
+        &json!({ "name": "Joe", "age": 30 }),
+        &json!({ "name": "Joe" }),
+        ExpectJsonOptions::new(),
+    );
+    assert!(output.is_err());
+}
+
+#[test]
+fn it_should_ignore_extra_fields_in_nested_objects() {
+    let received = json!({
+        "name": "Joe",
+        "age": 30,
+        "address": {
+            "city": "London",
+            "postcode": "N1",
+        },
+    });
+    let expected = json!({
+        "name": "Joe",
+        "address": {
+            "city": "London",
+        },
+    });
+
+    let options = ExpectJsonOptions::new().ignore_extra_fields();
+    let output = expect_json_eq_with(&received, &expected, options);
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+}
+
+#[test]
+fn it_should_still_fail_on_missing_fields_when_ignoring_extra_fields() {
+    let received = json!({ "age": 30, "email": "joe@example.com" });
+    let expected = json!({ "name": "Joe" });
+
+    let options = ExpectJsonOptions::new().ignore_extra_fields();
+    let output = expect_json_eq_with(&received, &expected, options)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        output,
+        r#"Json objects at root are not equal:
+    expected field 'name',
+    but it was not found"#
+    );
+}
+
+#[test]
+fn it_should_compare_arrays_unordered_everywhere() {
+    let received = json!({
+        "tags": ["a", "b", "c"],
+        "users": [{ "roles": ["admin", "staff"] }],
+    });
+    let expected = json!({
+        "tags": ["c", "a", "b"],
+        "users": [{ "roles": ["staff", "admin"] }],
+    });
+
+    let options = ExpectJsonOptions::new().unordered_arrays();
+    let output = expect_json_eq_with(&received, &expected, options);
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+}
+
+#[test]
+fn it_should_compare_arrays_unordered_only_at_paths_given() {
+    let received = json!({
+        "tags": ["a", "b"],
+        "users": [{ "roles": ["admin", "staff"] }],
+    });
+    let expected = json!({
+        "tags": ["b", "a"],
+        "users": [{ "roles": ["staff", "admin"] }],
+    });
+
+    let options = ExpectJsonOptions::new().unordered_arrays_at(["/users/*/roles"]);
+    let output = expect_json_eq_with(&received, &expected, options)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        output,
+        r#"Json strings at root.tags[0] are not equal:
+    expected "b"
+        full array ["b", "a"]
+    received "a"
+        full array ["a", "b"]"#
+    );
+
+    let options = ExpectJsonOptions::new().unordered_arrays_at(["/tags", "/users/*/roles"]);
+    let output = expect_json_eq_with(&received, &expected, options);
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+}
+
+#[test]
+fn it_should_report_missing_and_extra_items_for_unordered_arrays() {
+    let received = json!([1, 2, 3, 4]);
+    let expected = json!([4, 5, 1, 2]);
+
+    let options = ExpectJsonOptions::new().unordered_arrays();
+    let output = expect_json_eq_with(&received, &expected, options)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        output,
+        r#"Json arrays at root are not equal, ignoring order:
+    expected [4, 5, 1, 2]
+    received [1, 2, 3, 4]
+
+     missing [5]
+       extra [3]"#
+    );
+}
+
+#[test]
+fn it_should_compare_unordered_arrays_with_expect_ops() {
+    let received = json!(["joe@example.com", 123]);
+    let expected = json!([expect::integer(), expect::email()]);
+
+    let options = ExpectJsonOptions::new().unordered_arrays();
+    let output = expect_json_eq_with(&received, &expected, options);
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+}
+
+#[test]
+fn it_should_treat_null_the_same_as_missing() {
+    let options = ExpectJsonOptions::new().null_as_missing();
+
+    let output = expect_json_eq_with(
+        &json!({ "name": "Joe", "email": null }),
+        &json!({ "name": "Joe" }),
+        options.clone(),
+    );
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+
+    let output = expect_json_eq_with(
+        &json!({ "name": "Joe" }),
+        &json!({ "name": "Joe", "email": null }),
+        options,
+    );
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+}
+
+#[test]
+fn it_should_fail_when_null_is_expected_to_be_a_value_with_null_as_missing() {
+    let options = ExpectJsonOptions::new().null_as_missing();
+
+    let output = expect_json_eq_with(
+        &json!({ "name": "Joe", "email": null }),
+        &json!({ "name": "Joe", "email": "joe@example.com" }),
+        options,
+    )
+    .unwrap_err()
+    .to_string();
+    assert_eq!(
+        output,
+        r#"Json objects at root are not equal:
+    expected field 'email',
+    but it was not found"#
+    );
+}
+
+#[test]
+fn it_should_compare_floats_with_default_tolerance() {
+    let options = ExpectJsonOptions::new().float_tolerance(FloatTolerance::new().abs(0.01));
+
+    let output = expect_json_eq_with(
+        &json!({ "price": 9.999 }),
+        &json!({ "price": 10.0 }),
+        options.clone(),
+    );
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+
+    let output = expect_json_eq_with(&json!({ "price": 9.5 }), &json!({ "price": 10.0 }), options)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        output,
+        r#"Json floats at root.price are not equal:
+    expected 10.0
+    received 9.5"#
+    );
+}
+
+#[test]
+fn it_should_use_default_float_tolerance_for_approx_without_tolerance() {
+    let options = ExpectJsonOptions::new().float_tolerance(FloatTolerance::new().abs(0.01));
+
+    let output = expect_json_eq_with(
+        &json!(9.999),
+        &json!(expect::float().approx(10.0)),
+        options.clone(),
+    );
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+
+    let output = expect_json_eq_with(
+        &json!(9.999),
+        &json!(expect::float().approx(10.0).abs(0.0001)),
+        options,
+    );
+    assert!(output.is_err());
+}
+
+#[test]
+fn it_should_use_the_clock_given_for_relative_times() {
+    let clock = FixedClock::new(Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap());
+    let options = ExpectJsonOptions::new().clock(clock);
+    let expected = json!({
+        "created_at": expect::iso_date_time().within_past(Duration::from_secs(60)),
+        "birthday": expect::date().within_past(Duration::from_secs(60 * 60 * 24)),
+    });
+
+    let output = expect_json_eq_with(
+        &json!({ "created_at": "2025-01-01T11:59:30Z", "birthday": "2024-12-31" }),
+        &expected,
+        options.clone(),
+    );
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+
+    let output = expect_json_eq_with(
+        &json!({ "created_at": "2025-01-01T11:58:00Z", "birthday": "2024-12-31" }),
+        &expected,
+        options,
+    )
+    .unwrap_err()
+    .to_string();
+    assert_eq!(
+        output,
+        r#"Json expect::iso_date_time() error at root.created_at:
+    ISO datetime '2025-01-01T11:58:00Z' is too far from the past, expected between '1 minute' ago and now"#
+    );
+}
+
+#[test]
+fn it_should_use_the_thread_clock_when_no_clock_is_given() {
+    let _clock_guard = set_thread_clock(FixedClock::new(
+        Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+    ));
+    let expected = json!(expect::unix_timestamp().within_future(Duration::from_secs(60)));
+
+    let output = expect_json_eq(&json!(1_735_732_830), &expected);
+    assert!(output.is_ok(), "assertion error: {output:#?}");
+
+    let options = ExpectJsonOptions::new().clock(FixedClock::new(
+        Utc.with_ymd_and_hms(2025, 1, 1, 13, 0, 0).unwrap(),
+    ));
+    let output = expect_json_eq_with(&json!(1_735_732_830), &expected, options)
+        .unwrap_err()
+        .to_string();
+    assert_eq!(
+        output,
+        r#"Json expect::unix_timestamp() error at root:
+    unix timestamp '1735732830 (2025-01-01T12:00:30Z)' is in the past of now, expected between now and '1 minute' in the future"#
+    );
+}
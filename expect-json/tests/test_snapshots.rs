@@ -0,0 +1,22 @@
+use expect_json::*;
+use serde_json::Value;
+use serde_json::json;
+
+fn new_user_profile() -> Value {
+    json!({
+        "id": "b6cb5ca3-3d8b-4b6a-9b4d-a1f5e8a8e63b",
+        "name": "Alice",
+        "email": "alice@example.com",
+        "created_at": "2025-01-01T12:00:00Z",
+        "roles": ["admin", "editor"],
+        "settings": {
+            "theme": "dark",
+            "timezone": "Europe/London",
+        },
+    })
+}
+
+#[test]
+fn it_should_match_snapshot_with_hand_written_expectations() {
+    assert_snapshot!("user_profile", new_user_profile());
+}